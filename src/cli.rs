@@ -1,4 +1,8 @@
-use clap::{Arg, Command};
+use crate::codegen::factory::GeneratorFactory;
+use crate::output::{HumanReporter, JsonLinesReporter, Reporter, SilentReporter};
+use crate::performance::SamplingStrategy;
+use clap::{Arg, ArgAction, Command};
+use std::sync::Arc;
 
 /// Command line arguments structure for the j2s tool
 ///
@@ -13,10 +17,22 @@ pub struct CliArgs {
     pub output: Option<String>,
     /// Input file path specified as positional argument
     pub json_file: Option<String>,
-    /// Target output format specified via --format flag
+    /// Target output format(s) specified via --format flag (comma-separated when several)
     pub format: Option<String>,
     /// Custom struct/type name specified via --struct-name flag
     pub struct_name: Option<String>,
+    /// Sampling strategy for large arrays specified via --sample flag
+    pub sample: Option<String>,
+    /// Suppress all progress output via --quiet flag
+    pub quiet: bool,
+    /// Show details and phase timings via --verbose flag
+    pub verbose: bool,
+    /// Progress output format (human or json) specified via --message-format flag
+    pub message_format: Option<String>,
+    /// Language-specific options specified as KEY=VALUE via repeated --lang-option flags
+    pub lang_options: Vec<String>,
+    /// Write one file per generated type via --split-files flag
+    pub split_files: bool,
 }
 
 impl CliArgs {
@@ -146,6 +162,8 @@ impl CliArgs {
     /// * `String` - A generated struct name
     fn generate_default_struct_name(&self) -> String {
         match self.get_input_path() {
+            // Input piped through stdin has no file name to derive a name from
+            Some(path) if path == "-" => "Data".to_string(),
             Some(path) => {
                 // Extract filename without extension
                 let filename = std::path::Path::new(path)
@@ -198,20 +216,62 @@ impl CliArgs {
 
     /// Validate the format parameter
     ///
-    /// This method checks if the provided format is supported by the tool.
+    /// This method checks if every requested format is supported by the tool.
     ///
     /// # Returns
     /// * `Result<(), String>` - Ok if valid, Err with message if invalid
     pub fn validate_format(&self) -> Result<(), String> {
-        match &self.format {
-            Some(format) => {
-                match format.to_lowercase().as_str() {
-                    "schema" | "go" | "rust" | "typescript" | "python" => Ok(()),
-                    _ => Err(format!("Unsupported format '{}'. Supported formats: schema, go, rust, typescript, python", format)),
-                }
+        if self.format.is_none() {
+            return Ok(()); // None is valid (defaults to schema)
+        }
+
+        let formats = self.get_formats();
+        if formats.is_empty() {
+            return Err("No format specified. Supported formats: schema, go, rust, typescript, python, kotlin, swift, java, csharp, dart, zod, proto, graphql, sql, avro, openapi, jtd".to_string());
+        }
+
+        for format in &formats {
+            match format.to_lowercase().as_str() {
+                "schema" | "go" | "rust" | "typescript" | "python" | "kotlin" | "swift" | "java" | "csharp" | "dart" | "zod" | "proto" | "graphql" | "sql" | "avro" | "openapi" | "jtd" => {}
+                _ => return Err(format!("Unsupported format '{}'. Supported formats: schema, go, rust, typescript, python, kotlin, swift, java, csharp, dart, zod, proto, graphql, sql, avro, openapi, jtd", format)),
             }
-            None => Ok(()), // None is valid (defaults to schema)
         }
+        Ok(())
+    }
+
+    /// Validate the format, sampling strategy and language options
+    ///
+    /// # Returns
+    /// * `Result<(), String>` - Ok if every value is valid, Err with the first problem found
+    pub fn validate(&self) -> Result<(), String> {
+        self.validate_format()?;
+        self.get_sampling_strategy()?;
+        self.get_language_options()?;
+        Ok(())
+    }
+
+    /// Get every requested format in the order given
+    ///
+    /// Formats may be comma-separated (`--format go,rust`) or given through repeated
+    /// flags. Names are lowercased and aliases such as `ts` resolved to their canonical
+    /// format before duplicates are dropped, keeping the first occurrence.
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The requested formats, or `["schema"]` if none were specified
+    pub fn get_formats(&self) -> Vec<String> {
+        let Some(format) = &self.format else {
+            return vec!["schema".to_string()];
+        };
+
+        let mut formats: Vec<String> = Vec::new();
+        for entry in format.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+            let entry = entry.to_lowercase();
+            let entry = GeneratorFactory::canonical_format(&entry).map_or(entry, str::to_string);
+            if !formats.contains(&entry) {
+                formats.push(entry);
+            }
+        }
+        formats
     }
 
     /// Get the effective format with default handling
@@ -223,13 +283,67 @@ impl CliArgs {
     pub fn get_format(&self) -> &str {
         self.format.as_deref().unwrap_or("schema")
     }
+
+    /// Create the reporter selected by --quiet, --verbose and --message-format
+    ///
+    /// `--quiet` wins over the other two flags; `--message-format json` emits one JSON
+    /// object per event, and otherwise human-readable progress lines are printed.
+    ///
+    /// # Returns
+    /// * `Arc<dyn Reporter>` - The reporter to install with `output::set_reporter`
+    pub fn create_reporter(&self) -> Arc<dyn Reporter> {
+        if self.quiet {
+            Arc::new(SilentReporter)
+        } else if self.message_format.as_deref() == Some("json") {
+            Arc::new(JsonLinesReporter)
+        } else {
+            Arc::new(HumanReporter::new(self.verbose))
+        }
+    }
+
+    /// Get the sampling strategy requested for large arrays
+    ///
+    /// This method parses the --sample value into a `SamplingStrategy`. When no
+    /// strategy is given, `SamplingStrategy::default()` applies to every output format.
+    ///
+    /// # Returns
+    /// * `Result<Option<SamplingStrategy>, String>` - The parsed strategy, or an error message if invalid
+    pub fn get_sampling_strategy(&self) -> Result<Option<SamplingStrategy>, String> {
+        match &self.sample {
+            Some(spec) => SamplingStrategy::parse(spec)
+                .map(Some)
+                .map_err(|err| err.to_string()),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the language-specific options given via --lang-option
+    ///
+    /// Each option has the form `KEY=VALUE`; later occurrences of a key override
+    /// earlier ones when applied to the generation options.
+    ///
+    /// # Returns
+    /// * `Result<Vec<(String, String)>, String>` - The parsed key/value pairs, or an error message if malformed
+    pub fn get_language_options(&self) -> Result<Vec<(String, String)>, String> {
+        self.lang_options
+            .iter()
+            .map(|option| match option.split_once('=') {
+                Some((key, value)) if !key.trim().is_empty() => {
+                    Ok((key.trim().to_string(), value.to_string()))
+                }
+                _ => Err(format!(
+                    "Invalid language option '{option}'. Expected KEY=VALUE, e.g. package=com.example"
+                )),
+            })
+            .collect()
+    }
 }
 
 /// Parse command line arguments into a CliArgs structure
 ///
 /// This function uses the clap library to parse command line arguments according
-/// to the application's defined interface. It handles all argument validation
-/// and returns a structured representation of the user's input.
+/// to the application's defined interface and returns a structured representation
+/// of the user's input. Values clap cannot check are validated by `CliArgs::validate`.
 ///
 /// # Returns
 /// * `CliArgs` - Parsed command line arguments
@@ -240,21 +354,24 @@ impl CliArgs {
 pub fn parse_args() -> CliArgs {
     let matches = build_cli().get_matches();
 
-    let args = CliArgs {
+    CliArgs {
         input: matches.get_one::<String>("input").cloned(),
         output: matches.get_one::<String>("output").cloned(),
         json_file: matches.get_one::<String>("json_file").cloned(),
-        format: matches.get_one::<String>("format").cloned(),
+        format: matches
+            .get_many::<String>("format")
+            .map(|formats| formats.cloned().collect::<Vec<_>>().join(",")),
         struct_name: matches.get_one::<String>("struct_name").cloned(),
-    };
-
-    // Validate format if provided
-    if let Err(err) = args.validate_format() {
-        eprintln!("Error: {}", err);
-        std::process::exit(1);
+        sample: matches.get_one::<String>("sample").cloned(),
+        quiet: matches.get_flag("quiet"),
+        verbose: matches.get_flag("verbose"),
+        message_format: matches.get_one::<String>("message_format").cloned(),
+        lang_options: matches
+            .get_many::<String>("lang_option")
+            .map(|options| options.cloned().collect())
+            .unwrap_or_default(),
+        split_files: matches.get_flag("split_files"),
     }
-
-    args
 }
 
 /// Print the application help message to stdout
@@ -289,8 +406,12 @@ pub fn print_version() {
 /// - Positional argument: `j2s input.json`
 /// - Input flag: `j2s --input input.json` or `j2s -i input.json`
 /// - Output control: `--output path` or `-o path`
+/// - Pipes: `-` (or no input) reads stdin, `-o -` writes to stdout
+/// - Progress output: `--quiet`, `--verbose`, `--message-format json`
 /// - Format selection: `--format go` or `-f go`
+/// - Multiple formats: `--format go,rust,schema` or repeated `-f` flags
 /// - Custom struct name: `--struct-name MyStruct`
+/// - Large array sampling: `--sample stride:2000`
 ///
 /// # Examples
 /// ```bash
@@ -300,6 +421,7 @@ pub fn print_version() {
 /// j2s -i data.json -o schema.json                  # Using short flags
 /// j2s data.json --format go                        # Generate Go struct
 /// j2s data.json --format rust --struct-name User   # Generate Rust struct with custom name
+/// j2s data.json -f go,rust,schema -o generated/    # Generate several formats into a directory
 /// ```
 fn build_cli() -> Command {
     Command::new("j2s")
@@ -315,7 +437,19 @@ fn build_cli() -> Command {
              - go: Go language structs with JSON tags\n  \
              - rust: Rust structs with serde annotations\n  \
              - typescript: TypeScript interfaces\n  \
-             - python: Python dataclasses with type hints\n\n\
+             - python: Python dataclasses with type hints\n  \
+             - kotlin: Kotlin data classes with kotlinx.serialization\n  \
+             - swift: Swift Codable structs\n  \
+             - java: Java records or POJOs with Jackson annotations\n  \
+             - csharp: C# records or classes for System.Text.Json\n  \
+             - dart: Dart classes with json_serializable annotations\n  \
+             - zod: zod schemas for TypeScript runtime validation\n  \
+             - proto: Protocol Buffers proto3 schemas\n  \
+             - graphql: GraphQL SDL type definitions\n  \
+             - sql: SQL CREATE TABLE statements for PostgreSQL or SQLite\n  \
+             - avro: Apache Avro record schemas (.avsc)\n  \
+             - openapi: OpenAPI 3.1 component schemas (YAML or JSON)\n  \
+             - jtd: JSON Type Definition (RFC 8927) schemas\n\n\
              EXAMPLES:\n  \
              j2s data.json                                    # Generate data.schema.json\n  \
              j2s --input data.json                            # Same as above using flag\n  \
@@ -323,15 +457,27 @@ fn build_cli() -> Command {
              j2s -i data.json -o schema.json                  # Using short flags\n  \
              j2s data.json --format go                        # Generate Go struct\n  \
              j2s data.json --format rust --struct-name User   # Generate Rust struct with custom name\n  \
-             j2s data.json -f typescript -s ApiResponse       # Generate TypeScript interface\n\n\
+             j2s data.json -f typescript -s ApiResponse       # Generate TypeScript interface\n  \
+             j2s data.json -f go,rust,schema -o generated/    # Generate several formats into a directory\n  \
+             curl -s $URL | j2s -f typescript > types.ts      # Read stdin, write the artifact to stdout\n  \
+             j2s data.json -f go --message-format json        # Machine-readable progress events\n  \
+             j2s data.json -f java --split-files -o src/model/ # One Java file per type\n  \
+             j2s data.json -f java --lang-option java_style=pojo --lang-option package=com.example\n  \
+             j2s data.json -f rust --lang-option rust_derives=Default,PartialEq --lang-option rust_deny_unknown_fields=true\n\n\
              PERFORMANCE:\n  \
              - Files up to 100MB are supported\n  \
              - Large files (>10MB) show progress indicators\n  \
-             - Deep nesting is automatically limited to prevent stack overflow",
+             - Arrays over 10000 items are sampled (see --sample)\n  \
+             - Deep nesting is automatically limited to prevent stack overflow\n\n\
+             SAMPLING STRATEGIES:\n  \
+             - full: Inspect every item\n  \
+             - head[:N]: First N items (default, N=1000)\n  \
+             - stride[:N]: N items evenly spaced across the array\n  \
+             - reservoir[:N[:SEED]]: N items chosen at random with a reproducible seed",
         )
         .arg(
             Arg::new("json_file")
-                .help("Input JSON file path")
+                .help("Input JSON file path; use '-' or omit to read from stdin")
                 .value_name("JSON_FILE")
                 .index(1)
                 .help_heading("INPUT"),
@@ -341,7 +487,7 @@ fn build_cli() -> Command {
                 .short('i')
                 .long("input")
                 .value_name("FILE")
-                .help("Input JSON file path (alternative to positional argument)")
+                .help("Input JSON file path (alternative to positional argument); '-' reads from stdin")
                 .help_heading("INPUT"),
         )
         .arg(
@@ -349,7 +495,7 @@ fn build_cli() -> Command {
                 .short('o')
                 .long("output")
                 .value_name("FILE")
-                .help("Output file path (default: <input_name>.<format_extension>)")
+                .help("Output file path, '-' for stdout, or output directory when several formats are requested (default: <input_name>.<format_extension>, or stdout for stdin input)")
                 .help_heading("OUTPUT"),
         )
         .arg(
@@ -357,7 +503,8 @@ fn build_cli() -> Command {
                 .short('f')
                 .long("format")
                 .value_name("FORMAT")
-                .help("Output format: schema, go, rust, typescript, python (default: schema)")
+                .action(ArgAction::Append)
+                .help("Output format(s): schema, go, rust, typescript, python, kotlin, swift, java, csharp, dart, zod, proto, graphql, sql, avro, openapi, jtd; comma-separate or repeat for several (default: schema)")
                 .help_heading("FORMAT"),
         )
         .arg(
@@ -368,6 +515,93 @@ fn build_cli() -> Command {
                 .help("Custom name for generated struct/type/interface (default: derived from filename)")
                 .help_heading("FORMAT"),
         )
+        .arg(
+            Arg::new("lang_option")
+                .long("lang-option")
+                .value_name("KEY=VALUE")
+                .action(ArgAction::Append)
+                .help("Language-specific generator option, e.g. package=com.example or java_style=pojo; repeat for several")
+                .long_help(
+                    "Language-specific generator option; repeat for several. Accepted keys:\n  \
+                     package=<name>                go, kotlin, java, proto: package declaration\n  \
+                     java_style=record|pojo        java: records (default) or classes with getters and setters\n  \
+                     file_stem=<name>              java, dart: name of the output file, without extension\n  \
+                     namespace=<name>              csharp, avro: namespace of the generated types\n  \
+                     csharp_style=record|class     csharp: sealed records (default) or classes\n  \
+                     python_style=dataclass|pydantic|typeddict  python: kind of class (default: dataclass)\n  \
+                     typeddict_total=true|false    python: NotRequired keys (default) or total=False TypedDicts\n  \
+                     pydantic_extra=ignore|forbid|allow  python: how pydantic models treat unknown keys\n  \
+                     existing_proto=<file>         proto: keep the field numbers of an existing .proto file\n  \
+                     proto_nesting=top_level|nested  proto: where messages for nested objects are declared\n  \
+                     graphql_input=true|false      graphql: also emit an input type for every object type\n  \
+                     sql_dialect=postgres|sqlite   sql: column types (default: postgres)\n  \
+                     sql_nested=jsonb|table        sql: nested objects as JSON columns (default) or child tables\n  \
+                     derive_debug=true|false       rust: derive Debug (default: true)\n  \
+                     derive_clone=true|false       rust: derive Clone (default: true)\n  \
+                     derive_partial_eq=true|false  rust: derive PartialEq (default: false)\n  \
+                     rust_derives=<Trait>,...      rust: extra derives, e.g. Default,PartialEq; Eq adds PartialEq, Eq and Hash reject f64 fields\n  \
+                     rust_rename_all=true|false    rust: one rename_all per struct when it covers every field (default: true)\n  \
+                     rust_deny_unknown_fields=true|false  rust: reject unknown keys (default: false)\n  \
+                     rust_borrow=none|str|cow      rust: borrow strings from the input (default: none)\n  \
+                     rust_borrow_fallback=true|false  rust: keep String where samples need JSON escapes (default: false)\n  \
+                     export=true|false             typescript: export every declaration (default: true)\n  \
+                     ts_declaration=interface|type typescript: declaration style (default: interface)\n  \
+                     ts_readonly=true|false        typescript: readonly properties and arrays (default: false)\n  \
+                     ts_optional=both|optional|nullable  typescript: optional property style (default: both)\n  \
+                     ts_any=any|unknown            typescript: type of unknown values (default: any)\n  \
+                     ts_enums=true|false           typescript: literal unions for repeated strings (default: false)\n  \
+                     ts_guards=true|false          typescript: emit isX type guards (default: false)\n  \
+                     go_tags=<key>[:snake_case|verbatim],...  go: extra struct tags, e.g. yaml,db:snake_case\n  \
+                     go_validate=true|false        go: validate:\"required\" on required fields (default: false)\n  \
+                     go_optional=pointer|omitempty|sql_null|generic  go: optional field style (default: pointer; sql_null does not round-trip JSON)\n  \
+                     go_any=interface|raw_message  go: type of unknown values (default: interface)\n  \
+                     go_version=<version>          go: targeted Go version (default: 1.22)\n  \
+                     openapi_format=yaml|json      openapi: document syntax (default: yaml)\n  \
+                     openapi_response=<name>       openapi: add a response whose body is the sample's schema\n  \
+                     openapi_responses=<dir>       openapi: add a response for every .json sample in the directory\n  \
+                     jtd_enum_max=<n>              jtd: most distinct strings inferred as an enum (default: 8)",
+                )
+                .help_heading("FORMAT"),
+        )
+        .arg(
+            Arg::new("split_files")
+                .long("split-files")
+                .action(ArgAction::SetTrue)
+                .help("Write one file per generated type into the output directory (e.g. one .java file per class)")
+                .help_heading("OUTPUT"),
+        )
+        .arg(
+            Arg::new("quiet")
+                .short('q')
+                .long("quiet")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("verbose")
+                .help("Suppress progress output; errors are still printed")
+                .help_heading("OUTPUT"),
+        )
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .long("verbose")
+                .action(ArgAction::SetTrue)
+                .help("Show structure details and phase timings")
+                .help_heading("OUTPUT"),
+        )
+        .arg(
+            Arg::new("message_format")
+                .long("message-format")
+                .value_name("FORMAT")
+                .value_parser(["human", "json"])
+                .help("Progress output format: human or json (one JSON object per line)")
+                .help_heading("OUTPUT"),
+        )
+        .arg(
+            Arg::new("sample")
+                .long("sample")
+                .value_name("STRATEGY")
+                .help("Sampling for arrays over 10000 items: full, head[:N], stride[:N], reservoir[:N[:SEED]]")
+                .help_heading("PERFORMANCE"),
+        )
 }
 
 #[cfg(test)]
@@ -382,6 +616,12 @@ mod tests {
             json_file: None,
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_input_path(), Some(&"test.json".to_string()));
     }
@@ -394,6 +634,12 @@ mod tests {
             json_file: Some("test.json".to_string()),
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_input_path(), Some(&"test.json".to_string()));
     }
@@ -406,6 +652,12 @@ mod tests {
             json_file: Some("positional.json".to_string()),
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_input_path(), Some(&"input.json".to_string()));
     }
@@ -418,6 +670,12 @@ mod tests {
             json_file: None,
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_input_path(), None);
     }
@@ -440,6 +698,15 @@ mod tests {
             json_file: matches.get_one::<String>("json_file").cloned(),
             format: matches.get_one::<String>("format").cloned(),
             struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
         };
 
         assert_eq!(args.json_file, Some("input.json".to_string()));
@@ -462,6 +729,15 @@ mod tests {
             json_file: matches.get_one::<String>("json_file").cloned(),
             format: matches.get_one::<String>("format").cloned(),
             struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
         };
 
         assert_eq!(args.input, Some("test.json".to_string()));
@@ -484,6 +760,15 @@ mod tests {
             json_file: matches.get_one::<String>("json_file").cloned(),
             format: matches.get_one::<String>("format").cloned(),
             struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
         };
 
         assert_eq!(args.json_file, Some("input.json".to_string()));
@@ -512,6 +797,15 @@ mod tests {
             json_file: matches.get_one::<String>("json_file").cloned(),
             format: matches.get_one::<String>("format").cloned(),
             struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
         };
 
         assert_eq!(args.input, Some("input.json".to_string()));
@@ -534,6 +828,15 @@ mod tests {
             json_file: matches.get_one::<String>("json_file").cloned(),
             format: matches.get_one::<String>("format").cloned(),
             struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
         };
 
         assert_eq!(args.input, Some("input.json".to_string()));
@@ -559,6 +862,18 @@ mod tests {
         assert!(help_text.contains("--output"));
     }
 
+    #[test]
+    fn test_long_help_lists_language_options() {
+        let help_text = build_cli().render_long_help().to_string();
+        for key in ["rust_rename_all=", "rust_deny_unknown_fields=", "rust_derives=", "ts_guards=", "go_optional="] {
+            assert!(help_text.contains(key), "missing {key}");
+        }
+
+        // The short help keeps the one-line summary
+        let short_help = build_cli().render_help().to_string();
+        assert!(!short_help.contains("rust_rename_all="));
+    }
+
     #[test]
     fn test_print_version_function() {
         // Test that print_version doesn't panic
@@ -644,6 +959,12 @@ mod tests {
             json_file: None,
             format: None,
             struct_name: Some("CustomUser".to_string()),
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_struct_name(), "CustomUser");
     }
@@ -656,6 +977,12 @@ mod tests {
             json_file: None,
             format: None,
             struct_name: Some("custom_user-data".to_string()),
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_struct_name(), "CustomUserData");
     }
@@ -668,6 +995,12 @@ mod tests {
             json_file: None,
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_struct_name(), "UserData");
     }
@@ -680,6 +1013,12 @@ mod tests {
             json_file: Some("api-response.json".to_string()),
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_struct_name(), "ApiResponse");
     }
@@ -692,6 +1031,12 @@ mod tests {
             json_file: None,
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_struct_name(), "Data");
     }
@@ -704,6 +1049,12 @@ mod tests {
             json_file: None,
             format: Some("go".to_string()),
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert!(args.validate_format().is_ok());
 
@@ -713,6 +1064,12 @@ mod tests {
             json_file: None,
             format: Some("rust".to_string()),
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert!(args.validate_format().is_ok());
 
@@ -722,6 +1079,12 @@ mod tests {
             json_file: None,
             format: Some("typescript".to_string()),
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert!(args.validate_format().is_ok());
 
@@ -731,6 +1094,12 @@ mod tests {
             json_file: None,
             format: Some("python".to_string()),
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert!(args.validate_format().is_ok());
 
@@ -740,6 +1109,12 @@ mod tests {
             json_file: None,
             format: Some("schema".to_string()),
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert!(args.validate_format().is_ok());
     }
@@ -750,8 +1125,14 @@ mod tests {
             input: None,
             output: None,
             json_file: None,
-            format: Some("java".to_string()),
+            format: Some("cpp".to_string()),
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert!(args.validate_format().is_err());
 
@@ -761,6 +1142,12 @@ mod tests {
             json_file: None,
             format: Some("invalid".to_string()),
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert!(args.validate_format().is_err());
     }
@@ -773,6 +1160,12 @@ mod tests {
             json_file: None,
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert!(args.validate_format().is_ok());
     }
@@ -785,6 +1178,12 @@ mod tests {
             json_file: None,
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_format(), "schema");
     }
@@ -797,6 +1196,12 @@ mod tests {
             json_file: None,
             format: Some("go".to_string()),
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_format(), "go");
     }
@@ -814,6 +1219,15 @@ mod tests {
             json_file: matches.get_one::<String>("json_file").cloned(),
             format: matches.get_one::<String>("format").cloned(),
             struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
         };
 
         assert_eq!(args.json_file, Some("input.json".to_string()));
@@ -834,6 +1248,15 @@ mod tests {
             json_file: matches.get_one::<String>("json_file").cloned(),
             format: matches.get_one::<String>("format").cloned(),
             struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
         };
 
         assert_eq!(args.json_file, Some("input.json".to_string()));
@@ -861,6 +1284,15 @@ mod tests {
             json_file: matches.get_one::<String>("json_file").cloned(),
             format: matches.get_one::<String>("format").cloned(),
             struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
         };
 
         assert_eq!(args.json_file, Some("input.json".to_string()));
@@ -888,6 +1320,15 @@ mod tests {
             json_file: matches.get_one::<String>("json_file").cloned(),
             format: matches.get_one::<String>("format").cloned(),
             struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
         };
 
         assert_eq!(args.json_file, Some("input.json".to_string()));
@@ -903,7 +1344,221 @@ mod tests {
             json_file: None,
             format: None,
             struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
         };
         assert_eq!(args.get_struct_name(), "ComplexApiResponseV2");
     }
+
+    #[test]
+    fn test_parse_args_with_sample_flag() {
+        let cmd = build_cli();
+        let matches = cmd
+            .try_get_matches_from(vec!["j2s", "input.json", "--sample", "stride:2000"])
+            .unwrap();
+
+        let args = CliArgs {
+            input: matches.get_one::<String>("input").cloned(),
+            output: matches.get_one::<String>("output").cloned(),
+            json_file: matches.get_one::<String>("json_file").cloned(),
+            format: matches.get_one::<String>("format").cloned(),
+            struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
+        };
+
+        assert_eq!(args.sample, Some("stride:2000".to_string()));
+        assert_eq!(
+            args.get_sampling_strategy(),
+            Ok(Some(SamplingStrategy::Stride { size: 2000 }))
+        );
+    }
+
+    #[test]
+    fn test_get_sampling_strategy_invalid() {
+        let args = CliArgs {
+            input: None,
+            output: None,
+            json_file: Some("test.json".to_string()),
+            format: None,
+            struct_name: None,
+            sample: Some("random".to_string()),
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
+        };
+        assert!(args.get_sampling_strategy().is_err());
+
+        let args = CliArgs { sample: None, ..args };
+        assert_eq!(args.get_sampling_strategy(), Ok(None));
+    }
+
+    #[test]
+    fn test_parse_args_with_lang_options() {
+        let cmd = build_cli();
+        let matches = cmd
+            .try_get_matches_from(vec![
+                "j2s", "input.json", "-f", "java", "--lang-option", "java_style=pojo",
+                "--lang-option", "package=com.example", "--split-files",
+            ])
+            .unwrap();
+
+        let args = CliArgs {
+            input: matches.get_one::<String>("input").cloned(),
+            output: matches.get_one::<String>("output").cloned(),
+            json_file: matches.get_one::<String>("json_file").cloned(),
+            format: matches.get_one::<String>("format").cloned(),
+            struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
+        };
+
+        assert!(args.split_files);
+        assert_eq!(
+            args.get_language_options(),
+            Ok(vec![
+                ("java_style".to_string(), "pojo".to_string()),
+                ("package".to_string(), "com.example".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_get_language_options_invalid() {
+        let args = CliArgs {
+            input: None,
+            output: None,
+            json_file: Some("test.json".to_string()),
+            format: None,
+            struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: vec!["package".to_string()],
+            split_files: false,
+        };
+        assert!(args.get_language_options().is_err());
+
+        let args = CliArgs { lang_options: vec!["=value".to_string()], ..args };
+        assert!(args.get_language_options().is_err());
+
+        let args = CliArgs { lang_options: Vec::new(), ..args };
+        assert_eq!(args.get_language_options(), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_get_formats_comma_separated() {
+        let args = CliArgs {
+            input: None,
+            output: None,
+            json_file: Some("test.json".to_string()),
+            format: Some("go, rust,schema,go".to_string()),
+            struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
+        };
+        assert_eq!(args.get_formats(), vec!["go", "rust", "schema"]);
+        assert!(args.validate_format().is_ok());
+
+        let args = CliArgs { format: Some("GO,go,ts,TypeScript,Schema".to_string()), ..args };
+        assert_eq!(args.get_formats(), vec!["go", "typescript", "schema"]);
+        assert!(args.validate_format().is_ok());
+
+        let args = CliArgs { format: None, ..args };
+        assert_eq!(args.get_formats(), vec!["schema"]);
+    }
+
+    #[test]
+    fn test_validate_format_multiple_with_invalid_entry() {
+        let args = CliArgs {
+            input: None,
+            output: None,
+            json_file: None,
+            format: Some("go,invalid".to_string()),
+            struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
+        };
+        let err = args.validate_format().unwrap_err();
+        assert!(err.contains("'invalid'"));
+
+        let args = CliArgs { format: Some(",".to_string()), ..args };
+        assert!(args.validate_format().is_err());
+    }
+
+    #[test]
+    fn test_parse_args_with_repeated_format_flags() {
+        let cmd = build_cli();
+        let matches = cmd
+            .try_get_matches_from(vec!["j2s", "input.json", "-f", "go", "--format", "typescript,schema"])
+            .unwrap();
+
+        let args = CliArgs {
+            input: matches.get_one::<String>("input").cloned(),
+            output: matches.get_one::<String>("output").cloned(),
+            json_file: matches.get_one::<String>("json_file").cloned(),
+            format: matches
+                .get_many::<String>("format")
+                .map(|formats| formats.cloned().collect::<Vec<_>>().join(",")),
+            struct_name: matches.get_one::<String>("struct_name").cloned(),
+            sample: matches.get_one::<String>("sample").cloned(),
+            quiet: matches.get_flag("quiet"),
+            verbose: matches.get_flag("verbose"),
+            message_format: matches.get_one::<String>("message_format").cloned(),
+            lang_options: matches
+                .get_many::<String>("lang_option")
+                .map(|options| options.cloned().collect())
+                .unwrap_or_default(),
+            split_files: matches.get_flag("split_files"),
+        };
+
+        assert_eq!(args.get_formats(), vec!["go", "typescript", "schema"]);
+    }
+
+    #[test]
+    fn test_get_struct_name_from_stdin() {
+        let args = CliArgs {
+            input: Some("-".to_string()),
+            output: None,
+            json_file: None,
+            format: None,
+            struct_name: None,
+            sample: None,
+            quiet: false,
+            verbose: false,
+            message_format: None,
+            lang_options: Vec::new(),
+            split_files: false,
+        };
+        assert_eq!(args.get_struct_name(), "Data");
+    }
 }
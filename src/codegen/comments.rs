@@ -194,6 +194,50 @@ impl CommentGenerator for PythonCommentGenerator {
     }
 }
 
+/// Kotlin language comment generator (KDoc)
+pub struct KotlinCommentGenerator;
+
+impl CommentGenerator for KotlinCommentGenerator {
+    fn generate_file_header(&self, tool_name: &str, generation_time: &DateTime<Utc>) -> String {
+        format!(
+            "// Code generated by {} at {}; DO NOT EDIT.\n// This file was automatically generated from JSON data.\n\n",
+            tool_name,
+            generation_time.format("%Y-%m-%d %H:%M:%S UTC")
+        )
+    }
+    
+    fn generate_struct_comment(&self, struct_name: &str, description: Option<&str>) -> String {
+        match description {
+            Some(desc) => format!("/**\n * {} - {}\n */\n", struct_name, desc),
+            None => format!("/**\n * {} represents the structure of the JSON data\n */\n", struct_name),
+        }
+    }
+    
+    fn generate_field_comment(&self, field_name: &str, field_type: &str, description: Option<&str>) -> String {
+        match description {
+            Some(desc) => format!("    /** {} ({}): {} */\n", field_name, field_type, desc),
+            None => format!("    /** {} is of type {} */\n", field_name, field_type),
+        }
+    }
+    
+    fn generate_single_line_comment(&self, text: &str) -> String {
+        format!("// {}\n", text)
+    }
+    
+    fn generate_multi_line_comment(&self, lines: &[&str]) -> String {
+        let mut result = String::from("/**\n");
+        for line in lines {
+            result.push_str(&format!(" * {}\n", line));
+        }
+        result.push_str(" */\n");
+        result
+    }
+    
+    fn language_name(&self) -> &'static str {
+        "Kotlin"
+    }
+}
+
 /// Factory for creating comment generators
 pub struct CommentGeneratorFactory;
 
@@ -205,13 +249,14 @@ impl CommentGeneratorFactory {
             "rust" => Box::new(RustCommentGenerator),
             "typescript" | "ts" => Box::new(TypeScriptCommentGenerator),
             "python" | "py" => Box::new(PythonCommentGenerator),
+            "kotlin" | "kt" => Box::new(KotlinCommentGenerator),
             _ => Box::new(GoCommentGenerator), // Default fallback
         }
     }
     
     /// Get all supported languages
     pub fn supported_languages() -> Vec<&'static str> {
-        vec!["go", "rust", "typescript", "python"]
+        vec!["go", "rust", "typescript", "python", "kotlin"]
     }
 }
 
@@ -408,6 +453,21 @@ mod tests {
         assert!(struct_comment.contains("\"\"\"User - User data\"\"\""));
     }
     
+    #[test]
+    fn test_kotlin_comment_generator() {
+        let generator = KotlinCommentGenerator;
+        let timestamp = Utc.with_ymd_and_hms(2023, 12, 25, 10, 30, 0).unwrap();
+        
+        let header = generator.generate_file_header("j2s", &timestamp);
+        assert!(header.starts_with("// Code generated by j2s"));
+        
+        let struct_comment = generator.generate_struct_comment("User", Some("User data"));
+        assert_eq!(struct_comment, "/**\n * User - User data\n */\n");
+        
+        let field_comment = generator.generate_field_comment("name", "String", None);
+        assert_eq!(field_comment, "    /** name is of type String */\n");
+    }
+    
     #[test]
     fn test_comment_generator_factory() {
         let go_gen = CommentGeneratorFactory::create_generator("go");
@@ -422,6 +482,9 @@ mod tests {
         let py_gen = CommentGeneratorFactory::create_generator("python");
         assert_eq!(py_gen.language_name(), "Python");
         
+        let kotlin_gen = CommentGeneratorFactory::create_generator("kotlin");
+        assert_eq!(kotlin_gen.language_name(), "Kotlin");
+        
         // Test fallback
         let unknown_gen = CommentGeneratorFactory::create_generator("unknown");
         assert_eq!(unknown_gen.language_name(), "Go");
@@ -449,5 +512,6 @@ mod tests {
         assert!(languages.contains(&"rust"));
         assert!(languages.contains(&"typescript"));
         assert!(languages.contains(&"python"));
+        assert!(languages.contains(&"kotlin"));
     }
 }
\ No newline at end of file
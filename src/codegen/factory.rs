@@ -6,7 +6,10 @@
 
 use crate::codegen::generator::CodeGenerator;
 use crate::codegen::languages::{
-    go::GoGenerator, python::PythonGenerator, rust::RustGenerator, typescript::TypeScriptGenerator,
+    avro::AvroGenerator, csharp::CSharpGenerator, dart::DartGenerator, go::GoGenerator,
+    graphql::GraphqlGenerator, java::JavaGenerator, jtd::JtdGenerator, kotlin::KotlinGenerator,
+    openapi::OpenApiGenerator, proto::ProtoGenerator, python::PythonGenerator, rust::RustGenerator,
+    sql::SqlGenerator, swift::SwiftGenerator, typescript::TypeScriptGenerator, zod::ZodGenerator,
 };
 use crate::error::{J2sError, Result};
 
@@ -37,6 +40,18 @@ impl GeneratorFactory {
     /// * `"rust"` - Rust structs with serde annotations
     /// * `"typescript"` - TypeScript interfaces
     /// * `"python"` - Python dataclasses with type hints
+    /// * `"kotlin"` - Kotlin data classes for kotlinx.serialization
+    /// * `"swift"` - Swift structs conforming to Codable
+    /// * `"java"` - Java records or POJOs with Jackson annotations
+    /// * `"csharp"` - C# records or classes for System.Text.Json
+    /// * `"dart"` - Dart classes with json_serializable annotations
+    /// * `"zod"` - zod schemas for TypeScript runtime validation
+    /// * `"proto"` - Protocol Buffers proto3 schemas
+    /// * `"graphql"` - GraphQL SDL type definitions
+    /// * `"sql"` - SQL CREATE TABLE statements for PostgreSQL or SQLite
+    /// * `"avro"` - Apache Avro record schemas
+    /// * `"openapi"` - OpenAPI 3.1 component schemas
+    /// * `"jtd"` - JSON Type Definition schemas
     ///
     /// # Examples
     /// ```rust
@@ -51,8 +66,20 @@ impl GeneratorFactory {
             "rust" => Ok(Box::new(RustGenerator::new())),
             "typescript" | "ts" => Ok(Box::new(TypeScriptGenerator::new())),
             "python" | "py" => Ok(Box::new(PythonGenerator::new())),
+            "kotlin" | "kt" => Ok(Box::new(KotlinGenerator::new())),
+            "swift" => Ok(Box::new(SwiftGenerator::new())),
+            "java" => Ok(Box::new(JavaGenerator::new())),
+            "csharp" | "cs" => Ok(Box::new(CSharpGenerator::new())),
+            "dart" => Ok(Box::new(DartGenerator::new())),
+            "zod" => Ok(Box::new(ZodGenerator::new())),
+            "proto" => Ok(Box::new(ProtoGenerator::new())),
+            "graphql" => Ok(Box::new(GraphqlGenerator::new())),
+            "sql" => Ok(Box::new(SqlGenerator::new())),
+            "avro" => Ok(Box::new(AvroGenerator::new())),
+            "openapi" => Ok(Box::new(OpenApiGenerator::new())),
+            "jtd" => Ok(Box::new(JtdGenerator::new())),
             _ => Err(J2sError::codegen_error(format!(
-                "Unsupported format: '{}'. Supported formats are: go, rust, typescript, python",
+                "Unsupported format: '{}'. Supported formats are: go, rust, typescript, python, kotlin, swift, java, csharp, dart, zod, proto, graphql, sql, avro, openapi, jtd",
                 format
             ))),
         }
@@ -67,7 +94,7 @@ impl GeneratorFactory {
     /// # Returns
     /// * `Vec<&'static str>` - A list of supported format strings
     pub fn supported_formats() -> Vec<&'static str> {
-        vec!["go", "rust", "typescript", "python"]
+        vec!["go", "rust", "typescript", "python", "kotlin", "swift", "java", "csharp", "dart", "zod", "proto", "graphql", "sql", "avro", "openapi", "jtd"]
     }
 
     /// Check if a format is supported
@@ -83,7 +110,7 @@ impl GeneratorFactory {
     pub fn is_supported_format(format: &str) -> bool {
         matches!(
             format.to_lowercase().as_str(),
-            "go" | "rust" | "typescript" | "ts" | "python" | "py"
+            "go" | "rust" | "typescript" | "ts" | "python" | "py" | "kotlin" | "kt" | "swift" | "java" | "csharp" | "cs" | "dart" | "zod" | "proto" | "graphql" | "sql" | "avro" | "openapi" | "jtd"
         )
     }
 
@@ -103,6 +130,18 @@ impl GeneratorFactory {
             "rust" => Some("rust"),
             "typescript" | "ts" => Some("typescript"),
             "python" | "py" => Some("python"),
+            "kotlin" | "kt" => Some("kotlin"),
+            "swift" => Some("swift"),
+            "java" => Some("java"),
+            "csharp" | "cs" => Some("csharp"),
+            "dart" => Some("dart"),
+            "zod" => Some("zod"),
+            "proto" => Some("proto"),
+            "graphql" => Some("graphql"),
+            "sql" => Some("sql"),
+            "avro" => Some("avro"),
+            "openapi" => Some("openapi"),
+            "jtd" => Some("jtd"),
             _ => None,
         }
     }
@@ -123,6 +162,18 @@ impl GeneratorFactory {
             "rust" => Some("Rust structs with serde derive macros"),
             "typescript" | "ts" => Some("TypeScript interfaces with optional properties"),
             "python" | "py" => Some("Python dataclasses with type annotations"),
+            "kotlin" | "kt" => Some("Kotlin data classes with kotlinx.serialization"),
+            "swift" => Some("Swift Codable structs with CodingKeys"),
+            "java" => Some("Java records or POJOs with Jackson annotations"),
+            "csharp" | "cs" => Some("C# records or classes for System.Text.Json"),
+            "dart" => Some("Dart classes with json_serializable annotations"),
+            "zod" => Some("zod schemas with z.infer type exports"),
+            "proto" => Some("proto3 messages with stable field numbers"),
+            "graphql" => Some("GraphQL SDL types with non-null markers"),
+            "sql" => Some("CREATE TABLE statements with NOT NULL constraints"),
+            "avro" => Some("Avro record schemas with nullable unions"),
+            "openapi" => Some("OpenAPI 3.1 components linked by $ref"),
+            "jtd" => Some("JSON Type Definition (RFC 8927) schemas"),
             _ => None,
         }
     }
@@ -168,6 +219,17 @@ mod tests {
         assert_eq!(generator_alias.language_name(), "Python");
     }
 
+    #[test]
+    fn test_create_kotlin_generator() {
+        let generator = GeneratorFactory::create_generator("kotlin").unwrap();
+        assert_eq!(generator.language_name(), "Kotlin");
+        assert_eq!(generator.file_extension(), "kt");
+
+        // Test alias
+        let generator_alias = GeneratorFactory::create_generator("kt").unwrap();
+        assert_eq!(generator_alias.language_name(), "Kotlin");
+    }
+
     #[test]
     fn test_create_generator_case_insensitive() {
         let generator_upper = GeneratorFactory::create_generator("GO").unwrap();
@@ -181,10 +243,10 @@ mod tests {
 
     #[test]
     fn test_create_generator_unsupported() {
-        let result = GeneratorFactory::create_generator("java");
+        let result = GeneratorFactory::create_generator("cpp");
         assert!(result.is_err());
 
-        let result = GeneratorFactory::create_generator("cpp");
+        let result = GeneratorFactory::create_generator("cobol");
         assert!(result.is_err());
 
         let result = GeneratorFactory::create_generator("");
@@ -198,7 +260,19 @@ mod tests {
         assert!(formats.contains(&"rust"));
         assert!(formats.contains(&"typescript"));
         assert!(formats.contains(&"python"));
-        assert_eq!(formats.len(), 4);
+        assert!(formats.contains(&"kotlin"));
+        assert!(formats.contains(&"swift"));
+        assert!(formats.contains(&"java"));
+        assert!(formats.contains(&"csharp"));
+        assert!(formats.contains(&"dart"));
+        assert!(formats.contains(&"zod"));
+        assert!(formats.contains(&"proto"));
+        assert!(formats.contains(&"graphql"));
+        assert!(formats.contains(&"sql"));
+        assert!(formats.contains(&"avro"));
+        assert!(formats.contains(&"openapi"));
+        assert!(formats.contains(&"jtd"));
+        assert_eq!(formats.len(), 16);
     }
 
     #[test]
@@ -210,9 +284,11 @@ mod tests {
         assert!(GeneratorFactory::is_supported_format("ts"));
         assert!(GeneratorFactory::is_supported_format("python"));
         assert!(GeneratorFactory::is_supported_format("py"));
+        assert!(GeneratorFactory::is_supported_format("kotlin"));
+        assert!(GeneratorFactory::is_supported_format("kt"));
 
-        assert!(!GeneratorFactory::is_supported_format("java"));
         assert!(!GeneratorFactory::is_supported_format("cpp"));
+        assert!(!GeneratorFactory::is_supported_format("cobol"));
         assert!(!GeneratorFactory::is_supported_format(""));
         assert!(!GeneratorFactory::is_supported_format("javascript"));
     }
@@ -232,8 +308,9 @@ mod tests {
         assert_eq!(GeneratorFactory::canonical_format("python"), Some("python"));
         assert_eq!(GeneratorFactory::canonical_format("py"), Some("python"));
         assert_eq!(GeneratorFactory::canonical_format("PY"), Some("python"));
+        assert_eq!(GeneratorFactory::canonical_format("kt"), Some("kotlin"));
 
-        assert_eq!(GeneratorFactory::canonical_format("java"), None);
+        assert_eq!(GeneratorFactory::canonical_format("cpp"), None);
         assert_eq!(GeneratorFactory::canonical_format(""), None);
     }
 
@@ -246,7 +323,7 @@ mod tests {
         assert!(GeneratorFactory::format_description("python").is_some());
         assert!(GeneratorFactory::format_description("py").is_some());
 
-        assert!(GeneratorFactory::format_description("java").is_none());
+        assert!(GeneratorFactory::format_description("cpp").is_none());
         assert!(GeneratorFactory::format_description("").is_none());
 
         // Verify descriptions are meaningful
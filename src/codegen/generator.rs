@@ -4,7 +4,9 @@
 //! must implement, along with the configuration types used throughout the code generation
 //! process.
 
+use crate::codegen::types::SharedIr;
 use crate::error::Result;
+use crate::performance::SamplingStrategy;
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -28,6 +30,22 @@ pub trait CodeGenerator {
     /// * `Result<String>` - The generated source code or an error
     fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String>;
 
+    /// Generate code from struct definitions shared with other generators
+    ///
+    /// Generators built on `JsonToIrConverter` override this method to take their structs
+    /// from `ir`, so several formats are generated from a single inference pass. The
+    /// default implementation generates from the JSON document itself.
+    ///
+    /// # Arguments
+    /// * `ir` - The parsed document and the struct definitions inferred from it
+    /// * `options` - Configuration options for the generation process
+    ///
+    /// # Returns
+    /// * `Result<String>` - The generated source code or an error
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        self.generate(ir.json_value(), options)
+    }
+
     /// Get the file extension for the target language
     ///
     /// Returns the appropriate file extension (without the dot) for files in the target language.
@@ -37,6 +55,21 @@ pub trait CodeGenerator {
     /// * `&'static str` - The file extension (e.g., "go", "rs", "ts", "py")
     fn file_extension(&self) -> &'static str;
 
+    /// Get the file extension for output generated with the given options
+    ///
+    /// Formats whose syntax depends on a language option (such as OpenAPI written as
+    /// YAML or JSON) override this method. The default implementation returns
+    /// `file_extension`.
+    ///
+    /// # Arguments
+    /// * `options` - Configuration options for the generation process
+    ///
+    /// # Returns
+    /// * `&'static str` - The file extension (without the dot)
+    fn output_extension(&self, _options: &GenerationOptions) -> &'static str {
+        self.file_extension()
+    }
+
     /// Get the human-readable name of the target language
     ///
     /// Returns a display name for the language that can be used in user-facing messages
@@ -58,6 +91,27 @@ pub trait CodeGenerator {
     /// # Returns
     /// * `Result<()>` - Success or an error describing validation failures
     fn validate_options(&self, options: &GenerationOptions) -> Result<()>;
+
+    /// Generate code split into one file per type
+    ///
+    /// Languages that expect each type in its own file (such as Java) override this
+    /// method. The default implementation returns the output of `generate` as a single
+    /// file named after the root type.
+    ///
+    /// # Arguments
+    /// * `json_value` - The JSON data to generate code from
+    /// * `options` - Configuration options for the generation process
+    ///
+    /// # Returns
+    /// * `Result<Vec<(String, String)>>` - Pairs of file name and file content, or an error
+    fn generate_files(&self, json_value: &Value, options: &GenerationOptions) -> Result<Vec<(String, String)>> {
+        let file_name = format!(
+            "{}.{}",
+            options.get_struct_name("GeneratedStruct"),
+            self.output_extension(options)
+        );
+        Ok(vec![(file_name, self.generate(json_value, options)?)])
+    }
 }
 
 /// Configuration options for code generation
@@ -99,6 +153,12 @@ pub struct GenerationOptions {
     /// without modifying the core GenerationOptions structure. Each generator can
     /// define its own set of recognized options.
     pub language_options: HashMap<String, String>,
+
+    /// Strategy used to select elements of large arrays during type inference
+    ///
+    /// Defaults to `SamplingStrategy::default()`, the same strategy the schema generator
+    /// uses, so every output format is inferred from the same elements.
+    pub sampling: SamplingStrategy,
 }
 
 impl Default for GenerationOptions {
@@ -109,6 +169,7 @@ impl Default for GenerationOptions {
             optional_fields: true,
             type_mappings: HashMap::new(),
             language_options: HashMap::new(),
+            sampling: SamplingStrategy::default(),
         }
     }
 }
@@ -158,6 +219,12 @@ impl GenerationOptions {
         self
     }
 
+    /// Set the sampling strategy used for large arrays
+    pub fn with_sampling(mut self, sampling: SamplingStrategy) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
     /// Get the effective struct name, using a default if none is specified
     pub fn get_struct_name(&self, default: &str) -> String {
         self.struct_name
@@ -189,6 +256,7 @@ mod tests {
         assert!(options.optional_fields);
         assert!(options.type_mappings.is_empty());
         assert!(options.language_options.is_empty());
+        assert_eq!(options.sampling, SamplingStrategy::default());
     }
 
     #[test]
@@ -0,0 +1,348 @@
+//! # Apache Avro Schema Generator
+//!
+//! This module implements a generator for Avro schema files (`.avsc`). The root
+//! object becomes a named record; nested objects become named records defined
+//! inline at their first use and referenced by name afterwards, as Avro requires.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, collect_structs};
+use crate::error::{J2sError, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+/// Language option setting the namespace of the generated records
+const NAMESPACE_OPTION: &str = "namespace";
+
+/// An Avro record schema
+#[derive(Debug, Serialize)]
+struct AvroRecord {
+    /// Always "record"
+    #[serde(rename = "type")]
+    kind: &'static str,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc: Option<String>,
+    fields: Vec<AvroField>,
+}
+
+/// A field of an Avro record
+#[derive(Debug, Serialize)]
+struct AvroField {
+    name: String,
+    #[serde(rename = "type")]
+    field_type: AvroType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    doc: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default: Option<Value>,
+}
+
+/// An Avro array schema
+#[derive(Debug, Serialize)]
+struct AvroArray {
+    /// Always "array"
+    #[serde(rename = "type")]
+    kind: &'static str,
+    items: Box<AvroType>,
+}
+
+/// Any Avro schema a field can have
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum AvroType {
+    /// A primitive type or a reference to a named record
+    Name(String),
+    /// A union of schemas
+    Union(Vec<AvroType>),
+    /// An array schema
+    Array(AvroArray),
+    /// An inline record definition
+    Record(Box<AvroRecord>),
+}
+
+/// Apache Avro schema generator
+///
+/// This generator creates Avro record schemas from JSON data, including:
+/// - Field names kept from the JSON keys, with characters Avro does not allow replaced
+/// - `long`, `double`, `boolean` and `string` primitives
+/// - `["null", T]` unions with a `null` default for nullable fields
+/// - Named records for nested objects, in a configurable namespace
+///
+/// Values of unknown type have no Avro equivalent and are stored as JSON-encoded strings.
+pub struct AvroGenerator;
+
+impl AvroGenerator {
+    /// Create a new Avro schema generator
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Get the record name for a struct
+    fn record_name(&self, name: &str) -> String {
+        NameConverter::convert_type_name(name, "avro")
+    }
+
+    /// Build the Avro schema for a field's type, defining nested records at their first use
+    fn map_field_type(
+        &self,
+        field: &FieldDefinition,
+        structs: &HashMap<&str, &StructDefinition>,
+        defined: &mut HashSet<String>,
+        include_comments: bool,
+    ) -> AvroType {
+        let mut result = match &field.field_type {
+            FieldType::String | FieldType::Any => AvroType::Name("string".to_string()),
+            FieldType::Integer => AvroType::Name("long".to_string()),
+            FieldType::Number => AvroType::Name("double".to_string()),
+            FieldType::Boolean => AvroType::Name("boolean".to_string()),
+            FieldType::Custom(name) => {
+                let record_name = self.record_name(name);
+                match structs.get(name.as_str()) {
+                    Some(nested) if defined.insert(record_name.clone()) => AvroType::Record(Box::new(
+                        self.build_record(nested, None, structs, defined, include_comments),
+                    )),
+                    _ => AvroType::Name(record_name),
+                }
+            }
+        };
+
+        // Handle arrays
+        if field.is_array {
+            result = AvroType::Array(AvroArray {
+                kind: "array",
+                items: Box::new(result),
+            });
+        }
+
+        // Nullable fields are unions with null first, so that null can be the default
+        if field.is_optional {
+            result = AvroType::Union(vec![AvroType::Name("null".to_string()), result]);
+        }
+
+        result
+    }
+
+    /// Build a record schema for a struct and, inline, the records it references
+    fn build_record(
+        &self,
+        struct_def: &StructDefinition,
+        namespace: Option<&str>,
+        structs: &HashMap<&str, &StructDefinition>,
+        defined: &mut HashSet<String>,
+        include_comments: bool,
+    ) -> AvroRecord {
+        let fields = struct_def
+            .fields
+            .iter()
+            .map(|field| {
+                let mut comments = if include_comments { field.comments.clone() } else { Vec::new() };
+                if include_comments && matches!(field.field_type, FieldType::Any) {
+                    comments.push("JSON-encoded value of unknown type".to_string());
+                }
+
+                AvroField {
+                    name: field.code_name.clone(),
+                    field_type: self.map_field_type(field, structs, defined, include_comments),
+                    doc: join_doc(&comments),
+                    default: field.is_optional.then_some(Value::Null),
+                }
+            })
+            .collect();
+
+        AvroRecord {
+            kind: "record",
+            name: self.record_name(&struct_def.name),
+            namespace: namespace.map(str::to_string),
+            doc: if include_comments { join_doc(&struct_def.comments) } else { None },
+            fields,
+        }
+    }
+}
+
+impl Default for AvroGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for AvroGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        // Determine record name
+        let struct_name = options.get_struct_name("GeneratedRecord");
+        let sanitized_struct_name = self.record_name(&struct_name);
+
+        // Convert JSON to intermediate representation
+        let struct_def = ir.struct_for(IrSource::Document, "avro", &sanitized_struct_name)?;
+
+        let mut structs = HashMap::new();
+        collect_structs(&struct_def, &mut structs);
+
+        let mut defined = HashSet::from([self.record_name(&struct_def.name)]);
+        let record = self.build_record(
+            &struct_def,
+            options.get_language_option(NAMESPACE_OPTION).map(String::as_str),
+            &structs,
+            &mut defined,
+            options.include_comments,
+        );
+
+        let mut result = serde_json::to_string_pretty(&record)
+            .map_err(|e| J2sError::codegen_error(format!("Failed to serialize Avro schema: {e}")))?;
+        result.push('\n');
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "avsc"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "Avro"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        if let Some(namespace) = options.get_language_option(NAMESPACE_OPTION) {
+            let valid = namespace.split('.').all(|segment| {
+                segment
+                    .chars()
+                    .next()
+                    .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+                    && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            });
+            if !valid {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid Avro namespace: '{namespace}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Join comments into a `doc` attribute, if there are any
+fn join_doc(comments: &[String]) -> Option<String> {
+    (!comments.is_empty()).then(|| comments.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Build a field schema and serialize it to JSON for comparison
+    fn field_schema(generator: &AvroGenerator, field: &FieldDefinition) -> Value {
+        let structs = HashMap::new();
+        let mut defined = HashSet::new();
+        serde_json::to_value(generator.map_field_type(field, &structs, &mut defined, false)).unwrap()
+    }
+
+    #[test]
+    fn test_avro_generator_creation() {
+        let generator = AvroGenerator::new();
+        assert_eq!(generator.language_name(), "Avro");
+        assert_eq!(generator.file_extension(), "avsc");
+    }
+
+    #[test]
+    fn test_map_field_type() {
+        let generator = AvroGenerator::new();
+        let field = |field_type: FieldType| FieldDefinition::new("value", "value", field_type);
+
+        assert_eq!(field_schema(&generator, &field(FieldType::String)), json!("string"));
+        assert_eq!(field_schema(&generator, &field(FieldType::Integer)), json!("long"));
+        assert_eq!(field_schema(&generator, &field(FieldType::Number)), json!("double"));
+        assert_eq!(field_schema(&generator, &field(FieldType::Boolean)), json!("boolean"));
+        assert_eq!(field_schema(&generator, &field(FieldType::Any)), json!("string"));
+
+        // Test nullable and array types
+        assert_eq!(
+            field_schema(&generator, &field(FieldType::String).optional(true)),
+            json!(["null", "string"])
+        );
+        assert_eq!(
+            field_schema(&generator, &field(FieldType::Integer).array(true)),
+            json!({"type": "array", "items": "long"})
+        );
+        assert_eq!(
+            field_schema(&generator, &field(FieldType::Custom("tag".to_string())).array(true).optional(true)),
+            json!(["null", {"type": "array", "items": "Tag"}])
+        );
+    }
+
+    #[test]
+    fn test_generate_schema() {
+        let generator = AvroGenerator::new();
+        let json_data = json!({
+            "user_id": 42,
+            "first-name": "Ada",
+            "nickname": null,
+            "scores": [1.5, 2.0],
+            "address": {"city": "Paris"},
+            "billing": {"city": "Lyon"}
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("user")
+            .with_comments(false)
+            .with_language_option("namespace", "com.example.events");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("{\n  \"type\": \"record\",\n  \"name\": \"User\",\n  \"namespace\": \"com.example.events\",\n"));
+
+        let schema: Value = serde_json::from_str(&code).unwrap();
+        let fields = schema["fields"].as_array().unwrap();
+        let field = |name: &str| fields.iter().find(|field| field["name"] == name).unwrap();
+
+        assert_eq!(field("user_id")["type"], json!("long"));
+        assert_eq!(field("first_name")["type"], json!("string"));
+        assert_eq!(field("nickname")["type"], json!(["null", "string"]));
+        assert_eq!(field("nickname")["default"], Value::Null);
+        assert!(field("user_id").get("default").is_none());
+        assert_eq!(field("scores")["type"], json!({"type": "array", "items": "double"}));
+
+        // Each nested record is defined once, inline, with a namespace inherited from the root
+        let address = &field("address")["type"];
+        assert_eq!(address["type"], "record");
+        assert!(address.get("namespace").is_none());
+        assert_eq!(address["fields"][0], json!({"name": "city", "type": "string"}));
+        assert!(field("billing")["type"]["type"] == "record");
+    }
+
+    #[test]
+    fn test_shared_record_is_referenced_by_name() {
+        let generator = AvroGenerator::new();
+        let point = StructDefinition::new("Point").add_field(FieldDefinition::new("x", "x", FieldType::Number));
+        let root = StructDefinition::new("Line")
+            .add_field(FieldDefinition::new("start", "start", FieldType::Custom("Point".to_string())))
+            .add_field(FieldDefinition::new("end", "end", FieldType::Custom("Point".to_string())))
+            .add_nested_struct(point);
+
+        let mut structs = HashMap::new();
+        collect_structs(&root, &mut structs);
+        let mut defined = HashSet::new();
+        let record = generator.build_record(&root, None, &structs, &mut defined, false);
+        let schema = serde_json::to_value(record).unwrap();
+
+        assert_eq!(schema["fields"][0]["type"]["name"], "Point");
+        assert_eq!(schema["fields"][1]["type"], json!("Point"));
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = AvroGenerator::new();
+
+        let options = GenerationOptions::default().with_language_option("namespace", "com.example");
+        assert!(generator.validate_options(&options).is_ok());
+
+        let options = GenerationOptions::default().with_language_option("namespace", "com.my-company");
+        assert!(generator.validate_options(&options).is_err());
+    }
+}
@@ -0,0 +1,439 @@
+//! # C# Language Code Generator
+//!
+//! This module implements the code generator for the C# programming language.
+//! It generates sealed records or classes for System.Text.Json with
+//! `[JsonPropertyName]` attributes and nullable reference types.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, escape_comment_string, flatten_structs, generate_timestamp};
+use crate::error::{J2sError, Result};
+use serde_json::Value;
+
+/// Language option selecting the generated type style ("record" or "class")
+const STYLE_OPTION: &str = "csharp_style";
+
+/// Language option setting the namespace of the generated types
+const NAMESPACE_OPTION: &str = "namespace";
+
+/// The kind of C# type generated for each JSON object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CSharpStyle {
+    /// `public sealed record` types with init-only properties
+    Record,
+    /// `public class` types with settable properties
+    Class,
+}
+
+impl CSharpStyle {
+    /// Read the style from the generation options, defaulting to records
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        match options.get_language_option(STYLE_OPTION).map(String::as_str) {
+            None | Some("record") => Ok(Self::Record),
+            Some("class") => Ok(Self::Class),
+            Some(other) => Err(J2sError::codegen_error(format!(
+                "Invalid C# style: '{other}'. Supported styles: record, class"
+            ))),
+        }
+    }
+}
+
+/// C# language code generator
+///
+/// This generator creates C# type definitions from JSON data, including:
+/// - Proper C# naming conventions (PascalCase for types and properties)
+/// - `[JsonPropertyName]` attributes carrying the original JSON keys
+/// - Nullable types (`string?`, `long?`) for optional fields and `required` for the rest
+/// - `List<T>` for arrays and `JsonElement` for values of unknown type
+pub struct CSharpGenerator;
+
+impl CSharpGenerator {
+    /// Create a new C# code generator
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Map a FieldType to the appropriate C# type string
+    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool) -> String {
+        let mut result = match field_type {
+            FieldType::String => "string".to_string(),
+            FieldType::Integer => "long".to_string(),
+            FieldType::Number => "double".to_string(),
+            FieldType::Boolean => "bool".to_string(),
+            FieldType::Custom(name) => self.type_name(name),
+            FieldType::Any => "JsonElement".to_string(),
+        };
+
+        // Handle arrays
+        if is_array {
+            result = format!("List<{result}>");
+        }
+
+        // Handle optional fields with nullable types
+        if is_optional {
+            result.push('?');
+        }
+
+        result
+    }
+
+    /// Get the C# identifier used for a type, both to declare and to reference it
+    fn type_name(&self, name: &str) -> String {
+        NameConverter::sanitize_identifier_for_language(&NameConverter::to_pascal_case(name), "csharp")
+    }
+
+    /// Get the C# identifier used for a property
+    ///
+    /// The code_name may already carry an `@` escape from the converter. A member may
+    /// not share the name of its enclosing type, so such properties get a suffix.
+    fn property_name(&self, field: &FieldDefinition, type_name: &str) -> String {
+        let name = NameConverter::sanitize_identifier_for_language(field.code_name.trim_start_matches('@'), "csharp");
+        if name == type_name {
+            format!("{name}Value")
+        } else {
+            name
+        }
+    }
+
+    /// Generate a single property with its attribute and comments
+    fn generate_property(&self, field: &FieldDefinition, type_name: &str, style: CSharpStyle) -> String {
+        let mut result = String::new();
+
+        // Add comments if present
+        if !field.comments.is_empty() {
+            result.push_str(&format_summary(&field.comments, "    "));
+        }
+
+        let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
+        let property_name = self.property_name(field, type_name);
+        let required = if field.is_optional { "" } else { "required " };
+        let setter = match style {
+            CSharpStyle::Record => "init",
+            CSharpStyle::Class => "set",
+        };
+
+        result.push_str(&format!(
+            "    [JsonPropertyName(\"{}\")]\n",
+            escape_string_literal(&field.json_name)
+        ));
+        result.push_str(&format!(
+            "    public {required}{field_type} {property_name} {{ get; {setter}; }}\n"
+        ));
+        result
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "// Code generated by j2s (JSON to Struct) tool\n// Generated at: {timestamp}\n// DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+
+    /// Generate the using directives needed by the generated types
+    fn generate_usings(&self, struct_def: &StructDefinition) -> String {
+        let mut usings = Vec::new();
+
+        if any_field(struct_def, &|field| field.is_array) {
+            usings.push("using System.Collections.Generic;");
+        }
+        if any_field(struct_def, &|field| matches!(field.field_type, FieldType::Any)) {
+            usings.push("using System.Text.Json;");
+        }
+        if any_field(struct_def, &|_| true) {
+            usings.push("using System.Text.Json.Serialization;");
+        }
+
+        if usings.is_empty() {
+            String::new()
+        } else {
+            usings.join("\n") + "\n\n"
+        }
+    }
+
+    /// Generate a complete C# type definition in the requested style
+    fn generate_type(&self, struct_def: &StructDefinition, style: CSharpStyle, include_comments: bool) -> String {
+        let type_name = self.type_name(&struct_def.name);
+
+        let mut result = String::new();
+
+        // Add type comments if enabled
+        if include_comments {
+            if !struct_def.comments.is_empty() {
+                result.push_str(&format_summary(&struct_def.comments, ""));
+            } else {
+                result.push_str(&format_summary(
+                    &[format!("{type_name} represents the structure of the JSON data")],
+                    "",
+                ));
+            }
+        }
+
+        match style {
+            CSharpStyle::Record => result.push_str(&format!("public sealed record {type_name}\n{{\n")),
+            CSharpStyle::Class => result.push_str(&format!("public class {type_name}\n{{\n")),
+        }
+
+        let properties: Vec<String> = struct_def
+            .fields
+            .iter()
+            .map(|field| self.generate_property(field, &type_name, style))
+            .collect();
+        result.push_str(&properties.join("\n"));
+
+        result.push_str("}\n");
+        result
+    }
+}
+
+impl Default for CSharpGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for CSharpGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        let style = CSharpStyle::from_options(options)?;
+
+        // Determine type name
+        let struct_name = options.get_struct_name("GeneratedClass");
+        let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "csharp");
+
+        // Convert JSON to intermediate representation
+        let struct_def = ir.struct_for(IrSource::Document, "csharp", &sanitized_struct_name)?;
+
+        // Generate C# code
+        let mut result = String::new();
+
+        // Add file header comment if comments are enabled
+        if options.include_comments {
+            result.push_str(&self.generate_file_header());
+        }
+
+        // Nullable annotations are required for `string?` regardless of project settings
+        result.push_str("#nullable enable\n\n");
+        result.push_str(&self.generate_usings(&struct_def));
+
+        // Add file-scoped namespace if one was requested
+        if let Some(namespace) = options.get_language_option(NAMESPACE_OPTION) {
+            result.push_str(&format!("namespace {namespace};\n\n"));
+        }
+
+        // Generate nested types first, at every depth, then the main type
+        let types = flatten_structs(&struct_def);
+        for nested_struct in &types[..types.len() - 1] {
+            result.push_str(&self.generate_type(nested_struct, style, options.include_comments));
+            result.push('\n');
+        }
+        result.push_str(&self.generate_type(&struct_def, style, options.include_comments));
+
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "cs"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "C#"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        CSharpStyle::from_options(options)?;
+
+        if let Some(namespace) = options.get_language_option(NAMESPACE_OPTION) {
+            let keywords = NameConverter::get_reserved_keywords("csharp");
+            let valid = namespace.split('.').all(|segment| {
+                segment
+                    .chars()
+                    .next()
+                    .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+                    && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    && !keywords.contains(segment)
+            });
+            if !valid {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid C# namespace: '{namespace}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Recursively check whether any field satisfies the predicate
+fn any_field(struct_def: &StructDefinition, predicate: &dyn Fn(&FieldDefinition) -> bool) -> bool {
+    struct_def.fields.iter().any(predicate)
+        || struct_def
+            .nested_structs
+            .iter()
+            .any(|nested| any_field(nested, predicate))
+}
+
+/// Format comment lines as an XML documentation `<summary>` block
+fn format_summary(lines: &[String], indent: &str) -> String {
+    let mut result = format!("{indent}/// <summary>\n");
+    for line in lines {
+        let escaped = escape_comment_string(line)
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        result.push_str(&format!("{indent}/// {escaped}\n"));
+    }
+    result.push_str(&format!("{indent}/// </summary>\n"));
+    result
+}
+
+/// Escape a JSON key for use inside a C# string literal
+fn escape_string_literal(input: &str) -> String {
+    input.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_csharp_generator_creation() {
+        let generator = CSharpGenerator::new();
+        assert_eq!(generator.language_name(), "C#");
+        assert_eq!(generator.file_extension(), "cs");
+    }
+
+    #[test]
+    fn test_map_field_type() {
+        let generator = CSharpGenerator::new();
+
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false), "string");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false), "long");
+        assert_eq!(generator.map_field_type(&FieldType::Number, false, false), "double");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false), "bool");
+        assert_eq!(generator.map_field_type(&FieldType::Any, false, false), "JsonElement");
+
+        // Test nullable and list types
+        assert_eq!(generator.map_field_type(&FieldType::String, true, false), "string?");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, true, false), "long?");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true), "List<long>");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, true), "List<string>?");
+
+        let custom_type = FieldType::Custom("Profile".to_string());
+        assert_eq!(generator.map_field_type(&custom_type, true, false), "Profile?");
+    }
+
+    #[test]
+    fn test_generate_property() {
+        let generator = CSharpGenerator::new();
+
+        let field = FieldDefinition::new("user_id", "UserId", FieldType::Integer);
+        assert_eq!(
+            generator.generate_property(&field, "User", CSharpStyle::Record),
+            "    [JsonPropertyName(\"user_id\")]\n    public required long UserId { get; init; }\n"
+        );
+
+        let field = FieldDefinition::new("email", "Email", FieldType::String).optional(true);
+        assert_eq!(
+            generator.generate_property(&field, "User", CSharpStyle::Class),
+            "    [JsonPropertyName(\"email\")]\n    public string? Email { get; set; }\n"
+        );
+    }
+
+    #[test]
+    fn test_property_name_conflicts() {
+        let generator = CSharpGenerator::new();
+
+        // A property may not share its enclosing type's name
+        let field = FieldDefinition::new("user", "User", FieldType::String);
+        assert_eq!(generator.property_name(&field, "User"), "UserValue");
+
+        // Keywords keep their @ escape
+        let field = FieldDefinition::new("event", "@event", FieldType::String);
+        assert_eq!(generator.property_name(&field, "User"), "@event");
+    }
+
+    #[test]
+    fn test_generate_type_comments() {
+        let generator = CSharpGenerator::new();
+        let struct_def = StructDefinition::new("Page").add_comment("List<T> & friends");
+
+        let result = generator.generate_type(&struct_def, CSharpStyle::Class, true);
+        assert_eq!(
+            result,
+            "/// <summary>\n/// List&lt;T&gt; &amp; friends\n/// </summary>\npublic class Page\n{\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_simple_record() {
+        let generator = CSharpGenerator::new();
+        let json_data = json!({
+            "user_id": 42,
+            "name": "John Doe",
+            "nickname": null,
+            "tags": ["csharp"],
+            "profile": {
+                "bio": "Developer"
+            }
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("namespace", "Example.Models");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("#nullable enable\n\n"));
+        assert!(code.contains("using System.Collections.Generic;\n"));
+        assert!(code.contains("using System.Text.Json;\n"));
+        assert!(code.contains("using System.Text.Json.Serialization;\n"));
+        assert!(code.contains("namespace Example.Models;\n"));
+        assert!(code.contains("public sealed record User\n{\n"));
+        assert!(code.contains("    public required long UserId { get; init; }\n"));
+        assert!(code.contains("    public JsonElement? Nickname { get; init; }\n"));
+        assert!(code.contains("    public required List<string> Tags { get; init; }\n"));
+
+        // Nested types are declared before the main type
+        let nested_pos = code.find("public sealed record Profile").unwrap();
+        let main_pos = code.find("public sealed record User").unwrap();
+        assert!(nested_pos < main_pos);
+    }
+
+    #[test]
+    fn test_nested_type_references_match_declarations() {
+        let generator = CSharpGenerator::new();
+        let json_data = json!({"p": {"n": "x", "q": {"m": 1}}});
+
+        let code = generator.generate(&json_data, &GenerationOptions::default()).unwrap();
+        for key in ["p", "q"] {
+            let attribute = format!("[JsonPropertyName(\"{key}\")]");
+            let mut lines = code.lines().map(str::trim);
+            lines.find(|line| *line == attribute).unwrap();
+            let property = lines.next().unwrap();
+            let type_name = property.strip_prefix("public required ").unwrap().split(' ').next().unwrap();
+            assert!(code.contains(&format!("public sealed record {type_name}\n")), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = CSharpGenerator::new();
+
+        assert!(generator.validate_options(&GenerationOptions::default()).is_ok());
+
+        let options = GenerationOptions::default()
+            .with_language_option("csharp_style", "class")
+            .with_language_option("namespace", "Company.Api");
+        assert!(generator.validate_options(&options).is_ok());
+
+        let options = GenerationOptions::default().with_language_option("csharp_style", "struct");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("namespace", "Company.namespace");
+        assert!(generator.validate_options(&options).is_err());
+    }
+}
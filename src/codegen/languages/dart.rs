@@ -0,0 +1,409 @@
+//! # Dart Language Code Generator
+//!
+//! This module implements the code generator for the Dart programming language.
+//! It generates `@JsonSerializable()` model classes for the json_serializable
+//! package, with `final` fields, nullable types and `fromJson`/`toJson` stubs.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, escape_comment_string, flatten_structs, generate_timestamp};
+use crate::error::{J2sError, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Language option naming the generated file (without extension) for the `part` directive
+const FILE_STEM_OPTION: &str = "file_stem";
+
+/// Dart language code generator
+///
+/// This generator creates Dart class definitions from JSON data, including:
+/// - Proper Dart naming conventions (PascalCase for classes, camelCase for fields)
+/// - `@JsonSerializable()` classes with `@JsonKey(name: ...)` for renamed keys
+/// - Nullable types for optional fields and `required` constructor parameters for the rest
+/// - `fromJson`/`toJson` stubs delegating to the code written by build_runner
+pub struct DartGenerator {
+    /// Dart reserved words that need to be avoided in generated identifiers
+    keywords: HashSet<String>,
+}
+
+impl DartGenerator {
+    /// Create a new Dart code generator
+    pub fn new() -> Self {
+        Self {
+            keywords: NameConverter::get_reserved_keywords("dart"),
+        }
+    }
+
+    /// Map a FieldType to the appropriate Dart type string
+    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool) -> String {
+        let mut result = match field_type {
+            FieldType::String => "String".to_string(),
+            FieldType::Integer => "int".to_string(),
+            FieldType::Number => "double".to_string(),
+            FieldType::Boolean => "bool".to_string(),
+            FieldType::Custom(name) => self.class_name(name),
+            // Values of unknown type are kept as decoded JSON, which may be null
+            FieldType::Any => "Object?".to_string(),
+        };
+
+        // Handle arrays
+        if is_array {
+            result = format!("List<{result}>");
+        }
+
+        // Handle optional fields with nullable types
+        if is_optional && !result.ends_with('?') {
+            result.push('?');
+        }
+
+        result
+    }
+
+    /// Get the Dart identifier used for a field
+    ///
+    /// Identifiers starting with `_` are library-private in Dart and would be
+    /// skipped by json_serializable, so they get a public prefix.
+    fn field_name(&self, field: &FieldDefinition) -> String {
+        let name = NameConverter::sanitize_identifier(&field.code_name, &self.keywords);
+        if name.starts_with('_') {
+            format!("field{name}")
+        } else {
+            name
+        }
+    }
+
+    /// Get the Dart identifier used for a class, both to declare and to reference it
+    fn class_name(&self, name: &str) -> String {
+        NameConverter::sanitize_identifier(&NameConverter::to_pascal_case(name), &self.keywords)
+    }
+
+    /// Generate a single `final` field with its annotation and comments
+    fn generate_field(&self, field: &FieldDefinition) -> String {
+        let mut result = String::new();
+
+        // Add comments if present
+        for comment in &field.comments {
+            let escaped_comment = escape_comment_string(comment);
+            result.push_str(&format!("  /// {escaped_comment}\n"));
+        }
+
+        // Keep the original JSON key when the field name differs from it
+        let field_name = self.field_name(field);
+        if field.json_name != field_name {
+            result.push_str(&format!(
+                "  @JsonKey(name: '{}')\n",
+                escape_string_literal(&field.json_name)
+            ));
+        }
+
+        let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
+        result.push_str(&format!("  final {field_type} {field_name};\n"));
+        result
+    }
+
+    /// Generate the const constructor with named parameters
+    fn generate_constructor(&self, struct_def: &StructDefinition, class_name: &str) -> String {
+        if struct_def.fields.is_empty() {
+            return format!("  const {class_name}();\n");
+        }
+
+        let mut result = format!("  const {class_name}({{\n");
+        for field in &struct_def.fields {
+            let required = if field.is_optional { "" } else { "required " };
+            result.push_str(&format!("    {required}this.{},\n", self.field_name(field)));
+        }
+        result.push_str("  });\n");
+        result
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "// Code generated by j2s (JSON to Struct) tool\n// Generated at: {timestamp}\n// DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+
+    /// Generate a complete Dart class definition
+    fn generate_class(&self, struct_def: &StructDefinition, include_comments: bool) -> String {
+        let class_name = self.class_name(&struct_def.name);
+
+        let mut result = String::new();
+
+        // Add class comments if enabled
+        if include_comments {
+            if !struct_def.comments.is_empty() {
+                for comment in &struct_def.comments {
+                    let escaped_comment = escape_comment_string(comment);
+                    result.push_str(&format!("/// {escaped_comment}\n"));
+                }
+            } else {
+                result.push_str(&format!(
+                    "/// {class_name} represents the structure of the JSON data\n"
+                ));
+            }
+        }
+
+        // Nested objects are only serialized through their own toJson when asked to
+        let has_nested = struct_def
+            .fields
+            .iter()
+            .any(|field| matches!(field.field_type, FieldType::Custom(_)));
+        if has_nested {
+            result.push_str("@JsonSerializable(explicitToJson: true)\n");
+        } else {
+            result.push_str("@JsonSerializable()\n");
+        }
+        result.push_str(&format!("class {class_name} {{\n"));
+
+        for field in &struct_def.fields {
+            result.push_str(&self.generate_field(field));
+        }
+        if !struct_def.fields.is_empty() {
+            result.push('\n');
+        }
+
+        result.push_str(&self.generate_constructor(struct_def, &class_name));
+        result.push_str(&format!(
+            "\n  factory {class_name}.fromJson(Map<String, dynamic> json) =>\n      _${class_name}FromJson(json);\n"
+        ));
+        result.push_str(&format!(
+            "\n  Map<String, dynamic> toJson() => _${class_name}ToJson(this);\n"
+        ));
+
+        result.push_str("}\n");
+        result
+    }
+}
+
+impl Default for DartGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for DartGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        // Determine class name
+        let struct_name = options.get_struct_name("GeneratedClass");
+        let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "dart");
+
+        // Convert JSON to intermediate representation
+        let struct_def = ir.struct_for(IrSource::Document, "dart", &sanitized_struct_name)?;
+
+        // Generate Dart code
+        let mut result = String::new();
+
+        // Add file header comment if comments are enabled
+        if options.include_comments {
+            result.push_str(&self.generate_file_header());
+        }
+
+        // The part file is generated by build_runner next to this file
+        let file_stem = options
+            .get_language_option(FILE_STEM_OPTION)
+            .cloned()
+            .unwrap_or_else(|| NameConverter::to_snake_case(&sanitized_struct_name));
+        result.push_str("import 'package:json_annotation/json_annotation.dart';\n\n");
+        result.push_str(&format!("part '{file_stem}.g.dart';\n\n"));
+
+        // Generate nested classes first, at every depth, then the main class
+        let classes = flatten_structs(&struct_def);
+        for nested_struct in &classes[..classes.len() - 1] {
+            result.push_str(&self.generate_class(nested_struct, options.include_comments));
+            result.push('\n');
+        }
+        result.push_str(&self.generate_class(&struct_def, options.include_comments));
+
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "dart"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "Dart"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        if let Some(file_stem) = options.get_language_option(FILE_STEM_OPTION) {
+            let valid = !file_stem.is_empty()
+                && file_stem
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.');
+            if !valid {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid Dart file name: '{file_stem}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Escape a JSON key for use inside a single-quoted Dart string literal
+///
+/// Besides quotes and backslashes, `$` must be escaped because Dart strings
+/// support interpolation (keys such as `$ref` are common in JSON documents).
+fn escape_string_literal(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('\'', "\\'")
+        .replace('$', "\\$")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_dart_generator_creation() {
+        let generator = DartGenerator::new();
+        assert_eq!(generator.language_name(), "Dart");
+        assert_eq!(generator.file_extension(), "dart");
+    }
+
+    #[test]
+    fn test_map_field_type() {
+        let generator = DartGenerator::new();
+
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false), "String");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false), "int");
+        assert_eq!(generator.map_field_type(&FieldType::Number, false, false), "double");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false), "bool");
+        assert_eq!(generator.map_field_type(&FieldType::Any, false, false), "Object?");
+
+        // Test nullable and list types
+        assert_eq!(generator.map_field_type(&FieldType::String, true, false), "String?");
+        assert_eq!(generator.map_field_type(&FieldType::Any, true, false), "Object?");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true), "List<int>");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, true), "List<String>?");
+
+        let custom_type = FieldType::Custom("Profile".to_string());
+        assert_eq!(generator.map_field_type(&custom_type, true, false), "Profile?");
+    }
+
+    #[test]
+    fn test_generate_field() {
+        let generator = DartGenerator::new();
+
+        let field = FieldDefinition::new("name", "name", FieldType::String)
+            .add_comment("The user's name");
+        assert_eq!(
+            generator.generate_field(&field),
+            "  /// The user's name\n  final String name;\n"
+        );
+
+        let field = FieldDefinition::new("user_id", "userId", FieldType::Integer);
+        assert_eq!(
+            generator.generate_field(&field),
+            "  @JsonKey(name: 'user_id')\n  final int userId;\n"
+        );
+
+        // Keys that need escaping inside a Dart string
+        let field = FieldDefinition::new("$ref", "ref", FieldType::String);
+        assert!(generator.generate_field(&field).contains("@JsonKey(name: '\\$ref')"));
+    }
+
+    #[test]
+    fn test_field_name_sanitization() {
+        let generator = DartGenerator::new();
+
+        let field = FieldDefinition::new("default", "default", FieldType::String);
+        assert_eq!(generator.field_name(&field), "default_");
+
+        // Private identifiers would be ignored by json_serializable
+        let field = FieldDefinition::new("1st", "_1st", FieldType::String);
+        assert_eq!(generator.field_name(&field), "field_1st");
+    }
+
+    #[test]
+    fn test_generate_empty_class() {
+        let generator = DartGenerator::new();
+
+        let result = generator.generate_class(&StructDefinition::new("Empty"), false);
+        assert!(result.starts_with("@JsonSerializable()\nclass Empty {\n  const Empty();\n"));
+        assert!(result.contains("_$EmptyFromJson(json);"));
+        assert!(result.ends_with("  Map<String, dynamic> toJson() => _$EmptyToJson(this);\n}\n"));
+    }
+
+    #[test]
+    fn test_generate_simple_class() {
+        let generator = DartGenerator::new();
+        let json_data = json!({
+            "user_id": 42,
+            "name": "John Doe",
+            "nickname": null,
+            "tags": ["dart"],
+            "profile": {
+                "bio": "Developer"
+            }
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false);
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("import 'package:json_annotation/json_annotation.dart';\n\npart 'user.g.dart';\n\n"));
+        assert!(code.contains("@JsonSerializable(explicitToJson: true)\nclass User {\n"));
+        assert!(code.contains("  @JsonKey(name: 'user_id')\n  final int userId;\n"));
+        assert!(code.contains("  final Object? nickname;\n"));
+        assert!(code.contains("  final List<String> tags;\n"));
+        assert!(code.contains("    required this.userId,\n"));
+        assert!(code.contains("    this.nickname,\n"));
+        assert!(code.contains("  factory User.fromJson(Map<String, dynamic> json) =>\n      _$UserFromJson(json);\n"));
+
+        // Nested classes are declared before the main class
+        let nested_pos = code.find("class Profile").unwrap();
+        let main_pos = code.find("class User {").unwrap();
+        assert!(nested_pos < main_pos);
+    }
+
+    #[test]
+    fn test_part_directive_uses_file_stem() {
+        let generator = DartGenerator::new();
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("file_stem", "api_models");
+
+        let code = generator.generate(&json!({"id": 1}), &options).unwrap();
+        assert!(code.contains("part 'api_models.g.dart';\n"));
+    }
+
+    #[test]
+    fn test_nested_class_references_match_declarations() {
+        let generator = DartGenerator::new();
+        let json_data = json!({"p": {"n": "x", "q": {"m": 1}}});
+
+        let code = generator.generate(&json_data, &GenerationOptions::default()).unwrap();
+        for key in ["p", "q"] {
+            let suffix = format!(" {key};");
+            let type_name = code
+                .lines()
+                .find_map(|line| line.trim().strip_prefix("final ")?.strip_suffix(suffix.as_str()))
+                .unwrap();
+            assert!(code.contains(&format!("class {type_name} {{")), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = DartGenerator::new();
+
+        assert!(generator.validate_options(&GenerationOptions::default()).is_ok());
+
+        let options = GenerationOptions::default().with_language_option("file_stem", "user_model");
+        assert!(generator.validate_options(&options).is_ok());
+
+        let options = GenerationOptions::default().with_language_option("file_stem", "user'model");
+        assert!(generator.validate_options(&options).is_err());
+    }
+}
@@ -6,18 +6,281 @@
 
 use crate::codegen::comments::{CommentGenerator, GoCommentGenerator};
 use crate::codegen::generator::{CodeGenerator, GenerationOptions};
-use crate::codegen::types::{FieldDefinition, FieldType, StructDefinition};
+use crate::codegen::types::{DEFAULT_MAX_DEPTH, FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
 use crate::codegen::utils::{NameConverter, escape_comment_string};
-use crate::error::Result;
+use crate::error::{J2sError, Result};
 use serde_json::Value;
 use std::collections::HashSet;
 
+/// Language option listing struct tags emitted next to `json`, e.g. "yaml,db:snake_case"
+const TAGS_OPTION: &str = "go_tags";
+
+/// Language option adding `validate:"required"` to required fields ("true" or "false")
+const VALIDATE_OPTION: &str = "go_validate";
+
+/// Language option choosing how optional fields are represented
+/// ("pointer", "omitempty", "sql_null" or "generic"; `sql_null` does not round-trip JSON)
+const OPTIONAL_OPTION: &str = "go_optional";
+
+/// Language option choosing the type of values of unknown type
+const ANY_OPTION: &str = "go_any";
+
+/// Language option setting the targeted Go version, e.g. "1.21"
+const VERSION_OPTION: &str = "go_version";
+
+/// Go version assumed when none is given
+const DEFAULT_GO_VERSION: (u32, u32) = (1, 22);
+
+/// How optional fields are represented
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OptionalPolicy {
+    /// `*T` with `omitempty`
+    Pointer,
+    /// Plain `T` with `omitempty`, so absent values decode to the zero value
+    OmitEmpty,
+    /// `sql.NullString`, `sql.NullInt64`, ... and `sql.Null[T]` for structs.
+    /// These only implement `Scanner`/`Valuer`, not JSON marshaling, so they encode as
+    /// `{"String":"x","Valid":true}` and fail to decode plain values; use `Generic` for JSON
+    SqlNull,
+    /// A generic `Optional[T]` wrapper declared in the generated file
+    Generic,
+}
+
+impl OptionalPolicy {
+    /// Read the optional policy from the language options, defaulting to pointers
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        match options.get_language_option(OPTIONAL_OPTION).map(String::as_str) {
+            None | Some("pointer") => Ok(Self::Pointer),
+            Some("omitempty") => Ok(Self::OmitEmpty),
+            Some("sql_null") => Ok(Self::SqlNull),
+            Some("generic") => Ok(Self::Generic),
+            Some(other) => Err(J2sError::codegen_error(format!(
+                "Invalid Go optional policy: '{other}'. Supported policies: pointer, omitempty, sql_null, generic"
+            ))),
+        }
+    }
+}
+
+/// Type used for values of unknown type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AnyType {
+    /// `any`, or `interface{}` before Go 1.18
+    Interface,
+    /// `json.RawMessage`, leaving decoding to the caller
+    RawMessage,
+}
+
+impl AnyType {
+    /// Read the unknown-value type from the language options, defaulting to interfaces
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        match options.get_language_option(ANY_OPTION).map(String::as_str) {
+            None | Some("interface") => Ok(Self::Interface),
+            Some("raw_message") => Ok(Self::RawMessage),
+            Some(other) => Err(J2sError::codegen_error(format!(
+                "Invalid Go any type: '{other}'. Supported types: interface, raw_message"
+            ))),
+        }
+    }
+}
+
+/// How the name inside an extra struct tag is derived from the JSON key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TagNaming {
+    /// The JSON key as it is
+    Verbatim,
+    /// The JSON key converted to snake_case
+    SnakeCase,
+}
+
+/// An extra struct tag such as `yaml:"name"`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExtraTag {
+    key: String,
+    naming: TagNaming,
+}
+
+/// Struct tags emitted for every field, besides `json`
+#[derive(Debug, Clone, Default)]
+struct StructTags {
+    extra: Vec<ExtraTag>,
+    validate: bool,
+}
+
+impl StructTags {
+    /// Read the tag settings from the language options
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        let mut extra: Vec<ExtraTag> = Vec::new();
+        if let Some(tags) = options.get_language_option(TAGS_OPTION) {
+            for entry in tags.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+                let (key, naming) = match entry.split_once(':') {
+                    Some((key, "verbatim")) => (key, TagNaming::Verbatim),
+                    Some((key, "snake_case")) => (key, TagNaming::SnakeCase),
+                    Some((_, other)) => {
+                        return Err(J2sError::codegen_error(format!(
+                            "Invalid Go tag naming strategy: '{other}'. Supported strategies: snake_case, verbatim"
+                        )));
+                    }
+                    None => (entry, TagNaming::Verbatim),
+                };
+
+                let valid_key = !key.is_empty()
+                    && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+                if !valid_key {
+                    return Err(J2sError::codegen_error(format!("Invalid Go struct tag key: '{key}'")));
+                }
+                if key == "json" || key == "validate" {
+                    return Err(J2sError::codegen_error(format!(
+                        "The '{key}' tag cannot be listed in {TAGS_OPTION}; use {VALIDATE_OPTION} for validation tags"
+                    )));
+                }
+                if extra.iter().any(|tag| tag.key == key) {
+                    return Err(J2sError::codegen_error(format!("Duplicate Go struct tag key: '{key}'")));
+                }
+
+                extra.push(ExtraTag { key: key.to_string(), naming });
+            }
+        }
+
+        let validate = match options.get_language_option(VALIDATE_OPTION).map(String::as_str) {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(other) => {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid Go validate setting: '{other}'. Supported values: true, false"
+                )));
+            }
+        };
+
+        Ok(Self { extra, validate })
+    }
+
+    /// Build the complete tag literal for a field
+    fn render(&self, field: &FieldDefinition, omit_empty: bool) -> String {
+        let omit_empty = omit_empty && field.is_optional;
+        let mut tags = Vec::new();
+        if omit_empty {
+            tags.push(format!("json:\"{},omitempty\"", field.json_name));
+        } else {
+            tags.push(format!("json:\"{}\"", field.json_name));
+        }
+
+        for tag in &self.extra {
+            let name = match tag.naming {
+                TagNaming::Verbatim => field.json_name.clone(),
+                TagNaming::SnakeCase => NameConverter::to_snake_case(&field.json_name),
+            };
+            // Database scanners have no omitempty; every other common decoder does
+            if omit_empty && tag.key != "db" {
+                tags.push(format!("{}:\"{name},omitempty\"", tag.key));
+            } else {
+                tags.push(format!("{}:\"{name}\"", tag.key));
+            }
+        }
+
+        if self.validate && !field.is_optional {
+            tags.push("validate:\"required\"".to_string());
+        }
+
+        format!("`{}`", tags.join(" "))
+    }
+}
+
+/// Settings for the generated Go code, read from the language options
+#[derive(Debug, Clone)]
+struct GoStyle {
+    tags: StructTags,
+    optional: OptionalPolicy,
+    any_type: AnyType,
+    /// Targeted Go version as (major, minor)
+    version: (u32, u32),
+}
+
+impl Default for GoStyle {
+    fn default() -> Self {
+        Self {
+            tags: StructTags::default(),
+            optional: OptionalPolicy::Pointer,
+            any_type: AnyType::Interface,
+            version: DEFAULT_GO_VERSION,
+        }
+    }
+}
+
+impl GoStyle {
+    /// Read every Go setting from the language options
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        let version = match options.get_language_option(VERSION_OPTION) {
+            Some(version) => parse_go_version(version).ok_or_else(|| {
+                J2sError::codegen_error(format!("Invalid Go version: '{version}'. Expected a version such as 1.21"))
+            })?,
+            None => DEFAULT_GO_VERSION,
+        };
+
+        let optional = OptionalPolicy::from_options(options)?;
+        if optional == OptionalPolicy::Generic && version < (1, 18) {
+            return Err(J2sError::codegen_error(format!(
+                "The generic optional policy needs Go 1.18 or later, but {VERSION_OPTION} is {}.{}",
+                version.0, version.1
+            )));
+        }
+
+        Ok(Self {
+            tags: StructTags::from_options(options)?,
+            optional,
+            any_type: AnyType::from_options(options)?,
+            version,
+        })
+    }
+
+    /// Whether optional fields get `omitempty`; wrapper structs are never empty
+    fn omit_empty(&self) -> bool {
+        matches!(self.optional, OptionalPolicy::Pointer | OptionalPolicy::OmitEmpty)
+    }
+}
+
+/// Parse a Go version such as "1.21" or "1.21.3" into (major, minor)
+fn parse_go_version(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    if parts.any(|patch| patch.parse::<u32>().is_err()) {
+        return None;
+    }
+    Some((major, minor))
+}
+
+/// Declaration of the generic wrapper used by the generic optional policy
+const OPTIONAL_TYPE: &str = r#"// Optional holds a value that may be absent or null in the JSON data
+type Optional[T any] struct {
+	Value T
+	Valid bool
+}
+
+// MarshalJSON encodes the value, or null when it is not valid
+func (o Optional[T]) MarshalJSON() ([]byte, error) {
+	if !o.Valid {
+		return []byte("null"), nil
+	}
+	return json.Marshal(o.Value)
+}
+
+// UnmarshalJSON decodes the value, leaving it invalid for null
+func (o *Optional[T]) UnmarshalJSON(data []byte) error {
+	if string(data) == "null" {
+		*o = Optional[T]{}
+		return nil
+	}
+	o.Valid = true
+	return json.Unmarshal(data, &o.Value)
+}
+"#;
+
 /// Go language code generator
 ///
 /// This generator creates Go struct definitions from JSON data, including:
 /// - Proper Go naming conventions (PascalCase for types, camelCase for fields)
 /// - JSON struct tags for serialization
-/// - Pointer types for optional fields
+/// - Pointers, omitempty values or nullable wrappers for optional fields
 /// - Appropriate type mappings for Go's type system
 pub struct GoGenerator {
     /// Go reserved keywords that need to be avoided in generated identifiers
@@ -54,52 +317,65 @@ impl GoGenerator {
     }
 
     /// Map a FieldType to the appropriate Go type string
-    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool) -> String {
+    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool, style: &GoStyle) -> String {
         let base_type = match field_type {
             FieldType::String => "string",
             FieldType::Integer => "int64",
             FieldType::Number => "float64",
             FieldType::Boolean => "bool",
             FieldType::Custom(name) => name,
-            FieldType::Any => {
-                if is_array {
-                    // For arrays with mixed types, use interface{} as element type
-                    "interface{}"
-                } else {
-                    // For single Any fields, use interface{}
-                    "interface{}"
-                }
-            }
+            FieldType::Any => match style.any_type {
+                AnyType::RawMessage => "json.RawMessage",
+                AnyType::Interface if style.version >= (1, 18) => "any",
+                AnyType::Interface => "interface{}",
+            },
         };
 
-        let mut result = base_type.to_string();
-
-        // Handle arrays
+        // Slices and unknown values are already nil when absent, so they are never wrapped
         if is_array {
-            result = format!("[]{result}");
+            return format!("[]{base_type}");
         }
-
-        // Handle optional fields with pointers
-        if is_optional {
-            result = format!("*{result}");
+        if !is_optional || matches!(field_type, FieldType::Any) {
+            return base_type.to_string();
         }
 
-        result
+        match style.optional {
+            OptionalPolicy::Pointer => format!("*{base_type}"),
+            OptionalPolicy::OmitEmpty => base_type.to_string(),
+            OptionalPolicy::SqlNull => match field_type {
+                FieldType::String => "sql.NullString".to_string(),
+                FieldType::Integer => "sql.NullInt64".to_string(),
+                FieldType::Number => "sql.NullFloat64".to_string(),
+                FieldType::Boolean => "sql.NullBool".to_string(),
+                // The generic sql.Null[T] was added in Go 1.22
+                _ if style.version >= (1, 22) => format!("sql.Null[{base_type}]"),
+                _ => format!("*{base_type}"),
+            },
+            OptionalPolicy::Generic => format!("Optional[{base_type}]"),
+        }
     }
 
-    /// Generate a Go struct field declaration
-    fn generate_field(&self, field: &FieldDefinition, include_comments: bool) -> String {
+    /// Get the sanitized name and the type of a field, as written in the struct
+    fn field_columns(&self, field: &FieldDefinition, style: &GoStyle) -> (String, String) {
         // The code_name is already converted to the proper case by JsonToIrConverter
         let sanitized_name = NameConverter::sanitize_identifier(&field.code_name, &self.keywords);
-        
-        let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
-        
-        // Generate JSON tag
-        let json_tag = if field.is_optional {
-            format!("`json:\"{},omitempty\"`", field.json_name)
-        } else {
-            format!("`json:\"{}\"`", field.json_name)
-        };
+        let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array, style);
+        (sanitized_name, field_type)
+    }
+
+    /// Generate a Go struct field declaration
+    ///
+    /// The name and type are padded to the given widths so that the types and
+    /// tags of a struct line up in columns, as gofmt lays them out.
+    fn generate_field(
+        &self,
+        field: &FieldDefinition,
+        include_comments: bool,
+        style: &GoStyle,
+        widths: (usize, usize),
+    ) -> String {
+        let (sanitized_name, field_type) = self.field_columns(field, style);
+        let struct_tag = style.tags.render(field, style.omit_empty());
 
         // Add comments if enabled
         let mut result = String::new();
@@ -122,7 +398,10 @@ impl GoGenerator {
             }
         }
 
-        result.push_str(&format!("\t{sanitized_name} {field_type} {json_tag}"));
+        let (name_width, type_width) = widths;
+        result.push_str(&format!(
+            "\t{sanitized_name:<name_width$} {field_type:<type_width$} {struct_tag}"
+        ));
         result
     }
 
@@ -150,8 +429,46 @@ impl GoGenerator {
         ordered
     }
 
+    /// Generate the imports and helper types needed by the fields of the given structs
+    fn generate_preamble(&self, structs: &[&StructDefinition], style: &GoStyle) -> String {
+        let field_types: Vec<String> = structs
+            .iter()
+            .flat_map(|struct_def| struct_def.fields.iter())
+            .map(|field| self.map_field_type(&field.field_type, field.is_optional, field.is_array, style))
+            .collect();
+        let uses_optional = field_types.iter().any(|field_type| field_type.starts_with("Optional["));
+
+        let mut imports = Vec::new();
+        if field_types.iter().any(|field_type| field_type.starts_with("sql.")) {
+            imports.push("database/sql");
+        }
+        if uses_optional || field_types.iter().any(|field_type| field_type.contains("json.RawMessage")) {
+            imports.push("encoding/json");
+        }
+
+        let mut result = String::new();
+        match imports.as_slice() {
+            [] => {}
+            [import] => result.push_str(&format!("import \"{import}\"\n\n")),
+            _ => {
+                result.push_str("import (\n");
+                for import in &imports {
+                    result.push_str(&format!("\t\"{import}\"\n"));
+                }
+                result.push_str(")\n\n");
+            }
+        }
+
+        if uses_optional {
+            result.push_str(OPTIONAL_TYPE);
+            result.push('\n');
+        }
+
+        result
+    }
+
     /// Generate a complete Go struct definition
-    fn generate_struct(&self, struct_def: &StructDefinition, include_comments: bool) -> String {
+    fn generate_struct(&self, struct_def: &StructDefinition, include_comments: bool, style: &GoStyle) -> String {
         let struct_name = NameConverter::to_pascal_case(&struct_def.name);
         let sanitized_name = NameConverter::sanitize_identifier(&struct_name, &self.keywords);
 
@@ -174,9 +491,15 @@ impl GoGenerator {
         // Start struct definition
         result.push_str(&format!("type {sanitized_name} struct {{\n"));
 
+        // Align names and types across the struct, as gofmt does
+        let widths = struct_def.fields.iter().fold((0, 0), |(name_width, type_width), field| {
+            let (name, field_type) = self.field_columns(field, style);
+            (name_width.max(name.chars().count()), type_width.max(field_type.chars().count()))
+        });
+
         // Add fields
         for field in &struct_def.fields {
-            result.push_str(&self.generate_field(field, include_comments));
+            result.push_str(&self.generate_field(field, include_comments, style, widths));
             result.push('\n');
         }
 
@@ -195,7 +518,13 @@ impl Default for GoGenerator {
 
 impl CodeGenerator for GoGenerator {
     fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
         use crate::codegen::types::JsonToIrConverter;
+
+        let json_value = ir.json_value();
         
         // Validate nesting depth before processing
         let max_depth = JsonToIrConverter::validate_nesting_depth(json_value)?;
@@ -211,19 +540,21 @@ impl CodeGenerator for GoGenerator {
         // Get structure statistics for better error reporting
         let stats = JsonToIrConverter::get_structure_stats(json_value);
         
-        // Create converter for Go language with appropriate max depth
-        let mut converter = if max_depth > 20 {
-            JsonToIrConverter::with_max_depth("go", max_depth + 5)
+        // Allow deeper nesting than the converter's default when the input needs it
+        let converter_depth = if max_depth > DEFAULT_MAX_DEPTH {
+            max_depth + 5
         } else {
-            JsonToIrConverter::new("go")
+            DEFAULT_MAX_DEPTH
         };
         
+        let style = GoStyle::from_options(options)?;
+
         // Determine struct name
         let struct_name = options.get_struct_name("GeneratedStruct");
         let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "go");
         
         // Convert JSON to intermediate representation
-        let struct_def = converter.convert_to_struct(json_value, &sanitized_struct_name)?;
+        let struct_def = ir.struct_with_max_depth(IrSource::Document, "go", &sanitized_struct_name, converter_depth)?;
         
         // Generate Go code
         let mut result = String::new();
@@ -244,16 +575,21 @@ impl CodeGenerator for GoGenerator {
         // Add package declaration
         let package_name = options.get_language_option("package").unwrap_or(&"main".to_string()).clone();
         result.push_str(&format!("package {}\n\n", package_name));
-        
-        // Generate nested structs first (in dependency order)
+
+        // Add the imports and helper types the field types need
         let ordered_structs = self.order_structs_by_dependency(&struct_def);
+        let all_structs: Vec<&StructDefinition> =
+            ordered_structs.iter().copied().chain(std::iter::once(&struct_def)).collect();
+        result.push_str(&self.generate_preamble(&all_structs, &style));
+
+        // Generate nested structs first (in dependency order)
         for nested_struct in &ordered_structs {
-            result.push_str(&self.generate_struct(nested_struct, options.include_comments));
+            result.push_str(&self.generate_struct(nested_struct, options.include_comments, &style));
             result.push('\n');
         }
         
         // Generate main struct
-        result.push_str(&self.generate_struct(&struct_def, options.include_comments));
+        result.push_str(&self.generate_struct(&struct_def, options.include_comments, &style));
         
         Ok(result)
     }
@@ -266,9 +602,8 @@ impl CodeGenerator for GoGenerator {
         "Go"
     }
 
-    fn validate_options(&self, _options: &GenerationOptions) -> Result<()> {
-        // Basic validation - can be extended later
-        Ok(())
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        GoStyle::from_options(options).map(|_| ())
     }
 }
 
@@ -287,23 +622,24 @@ mod tests {
     #[test]
     fn test_map_field_type() {
         let generator = GoGenerator::new();
+        let style = GoStyle::default();
         
-        assert_eq!(generator.map_field_type(&FieldType::String, false, false), "string");
-        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false), "int64");
-        assert_eq!(generator.map_field_type(&FieldType::Number, false, false), "float64");
-        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false), "bool");
-        assert_eq!(generator.map_field_type(&FieldType::Any, false, false), "interface{}");
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false, &style), "string");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false, &style), "int64");
+        assert_eq!(generator.map_field_type(&FieldType::Number, false, false, &style), "float64");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false, &style), "bool");
+        assert_eq!(generator.map_field_type(&FieldType::Any, false, false, &style), "any");
         
         // Test optional types
-        assert_eq!(generator.map_field_type(&FieldType::String, true, false), "*string");
-        assert_eq!(generator.map_field_type(&FieldType::Integer, true, false), "*int64");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, false, &style), "*string");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, true, false, &style), "*int64");
         
         // Test array types
-        assert_eq!(generator.map_field_type(&FieldType::String, false, true), "[]string");
-        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true), "[]int64");
+        assert_eq!(generator.map_field_type(&FieldType::String, false, true, &style), "[]string");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true, &style), "[]int64");
         
-        // Test optional array types
-        assert_eq!(generator.map_field_type(&FieldType::String, true, true), "*[]string");
+        // Optional slices are nil when absent, so they are not pointers
+        assert_eq!(generator.map_field_type(&FieldType::String, true, true, &style), "[]string");
     }
 
     #[test]
@@ -314,7 +650,7 @@ mod tests {
         let field = FieldDefinition::new("user_name", "UserName", FieldType::String)
             .add_comment("The user's name");
         
-        let result = generator.generate_field(&field, true);
+        let result = generator.generate_field(&field, true, &GoStyle::default(), (0, 0));
         assert!(result.contains("UserName"));
         assert!(result.contains("string"));
         assert!(result.contains("`json:\"user_name\"`"));
@@ -329,7 +665,7 @@ mod tests {
         let field = FieldDefinition::new("email", "Email", FieldType::String)
             .optional(true);
         
-        let result = generator.generate_field(&field, true);
+        let result = generator.generate_field(&field, true, &GoStyle::default(), (0, 0));
         assert!(result.contains("Email"));
         assert!(result.contains("*string"));
         assert!(result.contains("`json:\"email,omitempty\"`"));
@@ -343,7 +679,7 @@ mod tests {
         let field = FieldDefinition::new("tags", "Tags", FieldType::String)
             .array(true);
         
-        let result = generator.generate_field(&field, true);
+        let result = generator.generate_field(&field, true, &GoStyle::default(), (0, 0));
         assert!(result.contains("Tags"));
         assert!(result.contains("[]string"));
         assert!(result.contains("`json:\"tags\"`"));
@@ -362,10 +698,10 @@ mod tests {
             .add_field(field2)
             .add_comment("User represents a user in the system");
         
-        let result = generator.generate_struct(&struct_def, true);
+        let result = generator.generate_struct(&struct_def, true, &GoStyle::default());
         assert!(result.contains("type User struct {"));
-        assert!(result.contains("Id int64"));
-        assert!(result.contains("Name string"));
+        assert!(result.contains("\tId   int64  `json:\"id\"`"));
+        assert!(result.contains("\tName string `json:\"name\"`"));
         assert!(result.contains("// User represents a user in the system"));
         assert!(result.ends_with("}\n"));
     }
@@ -376,7 +712,7 @@ mod tests {
         
         // The code_name should already be converted and sanitized
         let field = FieldDefinition::new("type", "Type_", FieldType::String);
-        let result = generator.generate_field(&field, true);
+        let result = generator.generate_field(&field, true, &GoStyle::default(), (0, 0));
         
         // Should be sanitized to avoid Go keyword conflict
         assert!(result.contains("Type_"));
@@ -411,9 +747,9 @@ mod tests {
         let code = result.unwrap();
         assert!(code.contains("package main"));
         assert!(code.contains("type User struct"));
-        assert!(code.contains("Name string"));
-        assert!(code.contains("Age int64"));
-        assert!(code.contains("IsActive bool"));
+        assert!(code.contains("Name     string"));
+        assert!(code.contains("Age      int64"));
+        assert!(code.contains("IsActive bool   `json"));
         assert!(code.contains("`json:\"name\"`"));
         assert!(code.contains("`json:\"age\"`"));
         assert!(code.contains("`json:\"is_active\"`"));
@@ -438,10 +774,10 @@ mod tests {
         assert!(result.is_ok());
         
         let code = result.unwrap();
-        assert!(code.contains("Name string"));
-        assert!(code.contains("Age int64"));
+        assert!(code.contains("Name  string"));
+        assert!(code.contains("Age   int64"));
         // Email should be optional since it was null in JSON
-        assert!(code.contains("Email *"));
+        assert!(code.contains("Email any"));
         assert!(code.contains("`json:\"email,omitempty\"`"));
     }
 
@@ -463,8 +799,8 @@ mod tests {
         assert!(result.is_ok());
         
         let code = result.unwrap();
-        assert!(code.contains("Tags []string"));
-        assert!(code.contains("Scores []int64"));
+        assert!(code.contains("Tags   []string"));
+        assert!(code.contains("Scores []int64 "));
         assert!(code.contains("`json:\"tags\"`"));
         assert!(code.contains("`json:\"scores\"`"));
     }
@@ -488,4 +824,148 @@ mod tests {
         let code = result.unwrap();
         assert!(code.contains("package models"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_extra_struct_tags() {
+        use serde_json::json;
+
+        let generator = GoGenerator::new();
+        let json_data = json!({"userId": 1, "displayName": null});
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("go_tags", "yaml,db:snake_case,mapstructure:verbatim")
+            .with_language_option("go_validate", "true");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.contains(
+            "\tDisplayName any   `json:\"displayName,omitempty\" yaml:\"displayName,omitempty\" db:\"display_name\" mapstructure:\"displayName,omitempty\"`\n"
+        ));
+        assert!(code.contains(
+            "\tUserId      int64 `json:\"userId\" yaml:\"userId\" db:\"user_id\" mapstructure:\"userId\" validate:\"required\"`\n"
+        ));
+    }
+
+    #[test]
+    fn test_validate_tag_options() {
+        let generator = GoGenerator::new();
+
+        let options = GenerationOptions::default().with_language_option("go_tags", "yaml, bson:snake_case");
+        assert!(generator.validate_options(&options).is_ok());
+
+        for tags in ["yaml:kebab", "json", "validate", "yaml,yaml", "my tag"] {
+            let options = GenerationOptions::default().with_language_option("go_tags", tags);
+            assert!(generator.validate_options(&options).is_err(), "{tags} should be rejected");
+        }
+
+        let options = GenerationOptions::default().with_language_option("go_validate", "yes");
+        assert!(generator.validate_options(&options).is_err());
+    }
+
+    #[test]
+    fn test_optional_policies() {
+        let generator = GoGenerator::new();
+        let style = |policy: &str| {
+            GoStyle::from_options(&GenerationOptions::default().with_language_option("go_optional", policy)).unwrap()
+        };
+        let profile = FieldType::Custom("Profile".to_string());
+
+        let omitempty = style("omitempty");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, true, false, &omitempty), "int64");
+        assert!(omitempty.omit_empty());
+
+        let sql_null = style("sql_null");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, false, &sql_null), "sql.NullString");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, true, false, &sql_null), "sql.NullInt64");
+        assert_eq!(generator.map_field_type(&FieldType::Number, true, false, &sql_null), "sql.NullFloat64");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, true, false, &sql_null), "sql.NullBool");
+        assert_eq!(generator.map_field_type(&profile, true, false, &sql_null), "sql.Null[Profile]");
+        assert!(!sql_null.omit_empty());
+
+        let generic = style("generic");
+        assert_eq!(generator.map_field_type(&profile, true, false, &generic), "Optional[Profile]");
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false, &generic), "string");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, true, &generic), "[]string");
+    }
+
+    #[test]
+    fn test_any_type_and_version() {
+        let generator = GoGenerator::new();
+        let style = |options: GenerationOptions| GoStyle::from_options(&options).unwrap();
+
+        let raw = style(GenerationOptions::default().with_language_option("go_any", "raw_message"));
+        assert_eq!(generator.map_field_type(&FieldType::Any, true, true, &raw), "[]json.RawMessage");
+
+        let old = style(
+            GenerationOptions::default()
+                .with_language_option("go_version", "1.17")
+                .with_language_option("go_optional", "sql_null"),
+        );
+        assert_eq!(generator.map_field_type(&FieldType::Any, false, false, &old), "interface{}");
+        assert_eq!(
+            generator.map_field_type(&FieldType::Custom("Profile".to_string()), true, false, &old),
+            "*Profile"
+        );
+    }
+
+    #[test]
+    fn test_generate_preamble() {
+        let generator = GoGenerator::new();
+        let style = |options: GenerationOptions| GoStyle::from_options(&options).unwrap();
+        let user = StructDefinition::new("User")
+            .add_field(FieldDefinition::new("age", "Age", FieldType::Integer).optional(true))
+            .add_field(FieldDefinition::new("extra", "Extra", FieldType::Any).optional(true));
+
+        assert_eq!(generator.generate_preamble(&[&user], &GoStyle::default()), "");
+
+        let generic = style(GenerationOptions::default().with_language_option("go_optional", "generic"));
+        let preamble = generator.generate_preamble(&[&user], &generic);
+        assert!(preamble.starts_with("import \"encoding/json\"\n\n// Optional holds"));
+        assert!(preamble.contains("type Optional[T any] struct {"));
+
+        let sql_raw = style(
+            GenerationOptions::default()
+                .with_language_option("go_optional", "sql_null")
+                .with_language_option("go_any", "raw_message"),
+        );
+        assert_eq!(
+            generator.generate_preamble(&[&user], &sql_raw),
+            "import (\n\t\"database/sql\"\n\t\"encoding/json\"\n)\n\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_with_raw_message() {
+        use serde_json::json;
+
+        let generator = GoGenerator::new();
+        let json_data = json!({"name": "Ada", "extra": null});
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("go_any", "raw_message");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("package main\n\nimport \"encoding/json\"\n\ntype User struct {\n"));
+        assert!(code.contains("\tExtra json.RawMessage `json:\"extra,omitempty\"`\n"));
+    }
+
+    #[test]
+    fn test_validate_optional_options() {
+        let generator = GoGenerator::new();
+
+        for (key, value) in [("go_optional", "maybe"), ("go_any", "object"), ("go_version", "go1.21")] {
+            let options = GenerationOptions::default().with_language_option(key, value);
+            assert!(generator.validate_options(&options).is_err(), "{key}={value} should be rejected");
+        }
+
+        let options = GenerationOptions::default()
+            .with_language_option("go_optional", "generic")
+            .with_language_option("go_version", "1.17");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("go_version", "1.21.3");
+        assert!(generator.validate_options(&options).is_ok());
+    }
+}
@@ -0,0 +1,342 @@
+//! # GraphQL SDL Generator
+//!
+//! This module implements a generator for GraphQL schema definition language (SDL).
+//! Each JSON object becomes an object `type`, and matching `input` types can be
+//! emitted for use as mutation arguments.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, generate_timestamp};
+use crate::error::{J2sError, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Language option enabling `input` types next to the object types ("true" or "false")
+const INPUT_OPTION: &str = "graphql_input";
+
+/// GraphQL SDL generator
+///
+/// This generator creates GraphQL type definitions from JSON data, including:
+/// - PascalCase type names and camelCase field names
+/// - `!` non-null markers for required fields and `[T!]` list types for arrays
+/// - A `JSON` custom scalar for values of unknown type
+/// - Optional `input` types mirroring every object type
+pub struct GraphqlGenerator;
+
+impl GraphqlGenerator {
+    /// Create a new GraphQL SDL generator
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Get the name of a type, with an `Input` suffix for input types
+    fn type_name(&self, name: &str, input: bool) -> String {
+        let type_name = NameConverter::convert_type_name(name, "graphql");
+        if input { format!("{type_name}Input") } else { type_name }
+    }
+
+    /// Map a FieldType to the appropriate GraphQL type reference
+    ///
+    /// Note that GraphQL's `Int` is a signed 32-bit integer.
+    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool, input: bool) -> String {
+        let mut result = match field_type {
+            FieldType::String => "String".to_string(),
+            FieldType::Integer => "Int".to_string(),
+            FieldType::Number => "Float".to_string(),
+            FieldType::Boolean => "Boolean".to_string(),
+            FieldType::Custom(name) => self.type_name(name, input),
+            FieldType::Any => "JSON".to_string(),
+        };
+
+        // Handle arrays; JSON elements stay nullable since they may hold null
+        if is_array {
+            if matches!(field_type, FieldType::Any) {
+                result = format!("[{result}]");
+            } else {
+                result = format!("[{result}!]");
+            }
+        }
+
+        // Required fields are non-null
+        if !is_optional {
+            result.push('!');
+        }
+
+        result
+    }
+
+    /// Generate a single field definition
+    fn generate_field(&self, field: &FieldDefinition, input: bool, include_comments: bool) -> String {
+        let mut result = String::new();
+
+        // Add field descriptions if present
+        if include_comments && !field.comments.is_empty() {
+            result.push_str(&description(&field.comments, "  "));
+        }
+
+        let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array, input);
+        result.push_str(&format!("  {}: {field_type}", field.code_name));
+        result
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "# Code generated by j2s (JSON to Struct) tool\n# Generated at: {timestamp}\n# DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+
+    /// Generate an object type or input type definition
+    fn generate_type(&self, struct_def: &StructDefinition, input: bool, include_comments: bool) -> String {
+        let type_name = self.type_name(&struct_def.name, input);
+        let keyword = if input { "input" } else { "type" };
+
+        let mut result = String::new();
+
+        // Add type descriptions if enabled
+        if include_comments && !struct_def.comments.is_empty() {
+            result.push_str(&description(&struct_def.comments, ""));
+        }
+
+        if struct_def.fields.is_empty() {
+            // GraphQL types must declare at least one field
+            result.push_str(&format!("{keyword} {type_name} {{\n  _empty: Boolean\n}}\n"));
+            return result;
+        }
+
+        result.push_str(&format!("{keyword} {type_name} {{\n"));
+        for field in &struct_def.fields {
+            result.push_str(&self.generate_field(field, input, include_comments));
+            result.push('\n');
+        }
+        result.push_str("}\n");
+        result
+    }
+}
+
+impl Default for GraphqlGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for GraphqlGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        // Determine type name
+        let struct_name = options.get_struct_name("GeneratedType");
+        let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "graphql");
+
+        // Convert JSON to intermediate representation
+        let struct_def = ir.struct_for(IrSource::Document, "graphql", &sanitized_struct_name)?;
+        let types = collect_types(&struct_def);
+        let with_inputs = options
+            .get_language_option(INPUT_OPTION)
+            .is_some_and(|input| input == "true");
+
+        // Generate SDL
+        let mut result = String::new();
+
+        // Add file header comment if comments are enabled
+        if options.include_comments {
+            result.push_str(&self.generate_file_header());
+        }
+
+        let mut definitions = Vec::new();
+
+        // The JSON scalar must be declared before a schema can use it
+        let uses_json = types
+            .iter()
+            .flat_map(|struct_def| struct_def.fields.iter())
+            .any(|field| matches!(field.field_type, FieldType::Any));
+        if uses_json {
+            definitions.push("scalar JSON\n".to_string());
+        }
+
+        for struct_def in &types {
+            definitions.push(self.generate_type(struct_def, false, options.include_comments));
+        }
+        if with_inputs {
+            for struct_def in &types {
+                definitions.push(self.generate_type(struct_def, true, options.include_comments));
+            }
+        }
+
+        result.push_str(&definitions.join("\n"));
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "graphql"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "GraphQL"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        if let Some(input) = options.get_language_option(INPUT_OPTION)
+            && !matches!(input.as_str(), "true" | "false")
+        {
+            return Err(J2sError::codegen_error(format!(
+                "Invalid GraphQL input setting: '{input}'. Supported values: true, false"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Flatten a type and all of its nested types, parents first, once per name
+fn collect_types(struct_def: &StructDefinition) -> Vec<&StructDefinition> {
+    fn collect<'a>(
+        struct_def: &'a StructDefinition,
+        seen: &mut HashSet<&'a str>,
+        types: &mut Vec<&'a StructDefinition>,
+    ) {
+        if !seen.insert(struct_def.name.as_str()) {
+            return;
+        }
+        types.push(struct_def);
+        for nested in &struct_def.nested_structs {
+            collect(nested, seen, types);
+        }
+    }
+
+    let mut seen = HashSet::new();
+    let mut types = Vec::new();
+    collect(struct_def, &mut seen, &mut types);
+    types
+}
+
+/// Format comments as a GraphQL block string description
+fn description(comments: &[String], indent: &str) -> String {
+    let mut result = format!("{indent}\"\"\"\n");
+    for comment in comments {
+        let escaped_comment = comment.replace("\"\"\"", "\\\"\"\"");
+        result.push_str(&format!("{indent}{escaped_comment}\n"));
+    }
+    result.push_str(&format!("{indent}\"\"\"\n"));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_graphql_generator_creation() {
+        let generator = GraphqlGenerator::new();
+        assert_eq!(generator.language_name(), "GraphQL");
+        assert_eq!(generator.file_extension(), "graphql");
+    }
+
+    #[test]
+    fn test_map_field_type() {
+        let generator = GraphqlGenerator::new();
+
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false, false), "String!");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false, false), "Int!");
+        assert_eq!(generator.map_field_type(&FieldType::Number, true, false, false), "Float");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false, false), "Boolean!");
+        assert_eq!(generator.map_field_type(&FieldType::Any, true, false, false), "JSON");
+
+        // Test list types
+        assert_eq!(generator.map_field_type(&FieldType::String, false, true, false), "[String!]!");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, true, true, false), "[Int!]");
+        assert_eq!(generator.map_field_type(&FieldType::Any, false, true, false), "[JSON]!");
+
+        // Test object and input references
+        let custom_type = FieldType::Custom("user_profile".to_string());
+        assert_eq!(generator.map_field_type(&custom_type, false, false, false), "UserProfile!");
+        assert_eq!(generator.map_field_type(&custom_type, true, true, true), "[UserProfileInput!]");
+    }
+
+    #[test]
+    fn test_generate_type() {
+        let generator = GraphqlGenerator::new();
+        let struct_def = StructDefinition::new("User")
+            .add_comment("A registered user")
+            .add_field(FieldDefinition::new("user_id", "userId", FieldType::Integer))
+            .add_field(FieldDefinition::new("email", "email", FieldType::String).optional(true));
+
+        assert_eq!(
+            generator.generate_type(&struct_def, false, true),
+            "\"\"\"\nA registered user\n\"\"\"\ntype User {\n  userId: Int!\n  email: String\n}\n"
+        );
+        assert_eq!(
+            generator.generate_type(&struct_def, true, false),
+            "input UserInput {\n  userId: Int!\n  email: String\n}\n"
+        );
+
+        let empty = generator.generate_type(&StructDefinition::new("Empty"), false, false);
+        assert_eq!(empty, "type Empty {\n  _empty: Boolean\n}\n");
+    }
+
+    #[test]
+    fn test_description_escaping() {
+        let comments = vec!["Uses \"\"\" quotes".to_string()];
+        assert_eq!(description(&comments, "  "), "  \"\"\"\n  Uses \\\"\"\" quotes\n  \"\"\"\n");
+    }
+
+    #[test]
+    fn test_generate_schema() {
+        let generator = GraphqlGenerator::new();
+        let json_data = json!({
+            "user_id": 42,
+            "tags": ["a", "b"],
+            "metadata": null,
+            "profile": {
+                "display_name": "Ada"
+            }
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false);
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("scalar JSON\n\ntype User {\n"));
+        assert!(code.contains("  userId: Int!\n"));
+        assert!(code.contains("  tags: [String!]!\n"));
+        assert!(code.contains("  metadata: JSON\n"));
+        assert!(code.contains("  displayName: String!\n"));
+        assert!(!code.contains("input "));
+    }
+
+    #[test]
+    fn test_generate_input_types() {
+        let generator = GraphqlGenerator::new();
+        let json_data = json!({"id": 1, "address": {"city": "Paris"}});
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("graphql_input", "true");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(!code.contains("scalar JSON"));
+        assert!(code.contains("input UserInput {\n"));
+
+        // Input types only reference other input types
+        let input_start = code.find("input UserInput").unwrap();
+        let address_line = code[input_start..].lines().find(|line| line.starts_with("  address: ")).unwrap();
+        assert!(address_line.ends_with("Input!"));
+        assert_eq!(code.matches("\ninput ").count(), 2);
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = GraphqlGenerator::new();
+
+        let options = GenerationOptions::default().with_language_option("graphql_input", "yes");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("graphql_input", "false");
+        assert!(generator.validate_options(&options).is_ok());
+    }
+}
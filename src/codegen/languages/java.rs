@@ -0,0 +1,569 @@
+//! # Java Language Code Generator
+//!
+//! This module implements the code generator for the Java programming language.
+//! It generates Java records, or classic POJOs for Java 8 code bases, annotated for
+//! Jackson with `@JsonProperty` and `@JsonIgnoreProperties`.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, escape_comment_string, flatten_structs, generate_timestamp};
+use crate::error::{J2sError, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Language option selecting the generated type style ("record" or "pojo")
+const STYLE_OPTION: &str = "java_style";
+
+/// Language option setting the package declaration of the generated files
+const PACKAGE_OPTION: &str = "package";
+
+/// Language option naming the file the code is written to, without extension
+const FILE_STEM_OPTION: &str = "file_stem";
+
+/// The kind of Java type generated for each JSON object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JavaStyle {
+    /// Immutable `record` types (Java 16+)
+    Record,
+    /// Classes with private fields, getters and setters (Java 8+)
+    Pojo,
+}
+
+impl JavaStyle {
+    /// Read the style from the generation options, defaulting to records
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        match options.get_language_option(STYLE_OPTION).map(String::as_str) {
+            None | Some("record") => Ok(Self::Record),
+            Some("pojo") => Ok(Self::Pojo),
+            Some(other) => Err(J2sError::codegen_error(format!(
+                "Invalid Java style: '{other}'. Supported styles: record, pojo"
+            ))),
+        }
+    }
+}
+
+/// Java language code generator
+///
+/// This generator creates Java type definitions from JSON data, including:
+/// - Proper Java naming conventions (PascalCase for types, camelCase for fields)
+/// - `@JsonProperty` on every field and `@JsonIgnoreProperties(ignoreUnknown = true)` on every type
+/// - Primitive types for required fields and boxed types for optional ones
+/// - `List<T>` for arrays and Jackson's `JsonNode` for values of unknown type
+pub struct JavaGenerator {
+    /// Java keywords that need to be avoided in generated identifiers
+    keywords: HashSet<String>,
+}
+
+impl JavaGenerator {
+    /// Create a new Java code generator
+    pub fn new() -> Self {
+        Self {
+            keywords: NameConverter::get_reserved_keywords("java"),
+        }
+    }
+
+    /// Map a FieldType to the appropriate Java type string
+    ///
+    /// Required scalars use primitives, while optional scalars and list elements
+    /// use their boxed counterparts so they can hold `null`.
+    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool) -> String {
+        let boxed = is_optional || is_array;
+        let base_type = match field_type {
+            FieldType::String => "String".to_string(),
+            FieldType::Integer if boxed => "Long".to_string(),
+            FieldType::Integer => "long".to_string(),
+            FieldType::Number if boxed => "Double".to_string(),
+            FieldType::Number => "double".to_string(),
+            FieldType::Boolean if boxed => "Boolean".to_string(),
+            FieldType::Boolean => "boolean".to_string(),
+            FieldType::Custom(name) => self.type_name(name),
+            FieldType::Any => "JsonNode".to_string(),
+        };
+
+        if is_array {
+            format!("List<{base_type}>")
+        } else {
+            base_type
+        }
+    }
+
+    /// Get the Java identifier used for a field
+    fn field_name(&self, field: &FieldDefinition) -> String {
+        NameConverter::sanitize_identifier(&field.code_name, &self.keywords)
+    }
+
+    /// Get the Java identifier used for a type, both to declare and to reference it
+    fn type_name(&self, name: &str) -> String {
+        NameConverter::sanitize_identifier(&NameConverter::to_pascal_case(name), &self.keywords)
+    }
+
+    /// Generate the `@JsonProperty` annotation for a field
+    fn json_property(&self, field: &FieldDefinition) -> String {
+        format!("@JsonProperty(\"{}\")", escape_string_literal(&field.json_name))
+    }
+
+    /// Generate Javadoc lines for a field from its comments
+    fn generate_field_comments(&self, field: &FieldDefinition) -> String {
+        let mut result = String::new();
+        for comment in &field.comments {
+            let escaped_comment = escape_comment_string(comment);
+            result.push_str(&format!("    /** {escaped_comment} */\n"));
+        }
+        result
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "// Code generated by j2s (JSON to Struct) tool\n// Generated at: {timestamp}\n// DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+
+    /// Generate the import statements needed by the given types
+    fn generate_imports(&self, structs: &[&StructDefinition]) -> String {
+        let fields = || structs.iter().flat_map(|struct_def| struct_def.fields.iter());
+
+        let mut imports = vec!["import com.fasterxml.jackson.annotation.JsonIgnoreProperties;"];
+        if fields().next().is_some() {
+            imports.push("import com.fasterxml.jackson.annotation.JsonProperty;");
+        }
+        if fields().any(|field| matches!(field.field_type, FieldType::Any)) {
+            imports.push("import com.fasterxml.jackson.databind.JsonNode;");
+        }
+        if fields().any(|field| field.is_array) {
+            imports.push("import java.util.List;");
+        }
+
+        imports.join("\n") + "\n\n"
+    }
+
+    /// Generate the Javadoc comment for a type
+    fn generate_type_comment(&self, struct_def: &StructDefinition, type_name: &str) -> String {
+        let lines: Vec<String> = if struct_def.comments.is_empty() {
+            vec![format!("{type_name} represents the structure of the JSON data")]
+        } else {
+            struct_def
+                .comments
+                .iter()
+                .map(|comment| escape_comment_string(comment))
+                .collect()
+        };
+
+        let mut result = String::from("/**\n");
+        for line in lines {
+            result.push_str(&format!(" * {line}\n"));
+        }
+        result.push_str(" */\n");
+        result
+    }
+
+    /// Generate a complete Java type definition in the requested style
+    fn generate_type(
+        &self,
+        struct_def: &StructDefinition,
+        style: JavaStyle,
+        is_public: bool,
+        include_comments: bool,
+    ) -> String {
+        let type_name = self.type_name(&struct_def.name);
+
+        let mut result = String::new();
+        if include_comments {
+            result.push_str(&self.generate_type_comment(struct_def, &type_name));
+        }
+        result.push_str("@JsonIgnoreProperties(ignoreUnknown = true)\n");
+
+        let modifier = if is_public { "public " } else { "" };
+        match style {
+            JavaStyle::Record => self.generate_record_body(struct_def, &type_name, modifier, &mut result),
+            JavaStyle::Pojo => self.generate_pojo_body(struct_def, &type_name, modifier, &mut result),
+        }
+        result
+    }
+
+    /// Generate a `record` declaration with one annotated component per field
+    fn generate_record_body(
+        &self,
+        struct_def: &StructDefinition,
+        type_name: &str,
+        modifier: &str,
+        result: &mut String,
+    ) {
+        if struct_def.fields.is_empty() {
+            result.push_str(&format!("{modifier}record {type_name}() {{\n}}\n"));
+            return;
+        }
+
+        result.push_str(&format!("{modifier}record {type_name}(\n"));
+        let components: Vec<String> = struct_def
+            .fields
+            .iter()
+            .map(|field| {
+                let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
+                format!(
+                    "{}    {} {field_type} {}",
+                    self.generate_field_comments(field),
+                    self.json_property(field),
+                    self.field_name(field)
+                )
+            })
+            .collect();
+        result.push_str(&components.join(",\n"));
+        result.push_str("\n) {\n}\n");
+    }
+
+    /// Generate a class with private fields, getters and setters
+    fn generate_pojo_body(
+        &self,
+        struct_def: &StructDefinition,
+        type_name: &str,
+        modifier: &str,
+        result: &mut String,
+    ) {
+        result.push_str(&format!("{modifier}class {type_name} {{\n"));
+
+        // Fields
+        for field in &struct_def.fields {
+            let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
+            result.push_str(&self.generate_field_comments(field));
+            result.push_str(&format!("    {}\n", self.json_property(field)));
+            result.push_str(&format!("    private {field_type} {};\n", self.field_name(field)));
+        }
+
+        // Accessors
+        for field in &struct_def.fields {
+            let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
+            let name = self.field_name(field);
+            let suffix = capitalize(&name);
+            let getter_prefix = if field_type == "boolean" { "is" } else { "get" };
+
+            result.push_str(&format!(
+                "\n    public {field_type} {getter_prefix}{suffix}() {{\n        return {name};\n    }}\n"
+            ));
+            result.push_str(&format!(
+                "\n    public void set{suffix}({field_type} {name}) {{\n        this.{name} = {name};\n    }}\n"
+            ));
+        }
+
+        result.push_str("}\n");
+    }
+
+    /// Build the main type definition from the shared intermediate representation
+    fn convert(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<StructDefinition> {
+        // Determine type name
+        let struct_name = options.get_struct_name("GeneratedClass");
+        let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "java");
+
+        // Convert JSON to intermediate representation
+        ir.struct_for(IrSource::Document, "java", &sanitized_struct_name)
+    }
+
+    /// Generate the header and package declaration shared by every file
+    fn generate_preamble(&self, options: &GenerationOptions) -> String {
+        let mut result = String::new();
+
+        // Add file header comment if comments are enabled
+        if options.include_comments {
+            result.push_str(&self.generate_file_header());
+        }
+
+        // Add package declaration if one was requested
+        if let Some(package_name) = options.get_language_option(PACKAGE_OPTION) {
+            result.push_str(&format!("package {package_name};\n\n"));
+        }
+
+        result
+    }
+}
+
+impl Default for JavaGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for JavaGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        let style = JavaStyle::from_options(options)?;
+        let struct_def = self.convert(ir, options)?;
+        let types = flatten_structs(&struct_def);
+
+        // Generate Java code
+        let mut result = self.generate_preamble(options);
+        result.push_str(&self.generate_imports(&types));
+
+        // Nested types come first and stay package-private, since a Java file
+        // may only declare one public top-level type
+        for nested in &types[..types.len() - 1] {
+            result.push_str(&self.generate_type(nested, style, false, options.include_comments));
+            result.push('\n');
+        }
+
+        // The main type may only be public in a file named after it
+        let is_public = options
+            .get_language_option(FILE_STEM_OPTION)
+            .is_some_and(|stem| *stem == self.type_name(&struct_def.name));
+        result.push_str(&self.generate_type(&struct_def, style, is_public, options.include_comments));
+
+        Ok(result)
+    }
+
+    fn generate_files(&self, json_value: &Value, options: &GenerationOptions) -> Result<Vec<(String, String)>> {
+        let style = JavaStyle::from_options(options)?;
+        let struct_def = self.convert(&SharedIr::new(json_value, options.sampling.clone()), options)?;
+
+        // Every type goes into its own public `<TypeName>.java` file
+        let files = flatten_structs(&struct_def)
+            .into_iter()
+            .map(|type_def| {
+                let mut content = self.generate_preamble(options);
+                content.push_str(&self.generate_imports(&[type_def]));
+                content.push_str(&self.generate_type(type_def, style, true, options.include_comments));
+                (format!("{}.java", self.type_name(&type_def.name)), content)
+            })
+            .collect();
+
+        Ok(files)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "java"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "Java"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        JavaStyle::from_options(options)?;
+
+        if let Some(package_name) = options.get_language_option(PACKAGE_OPTION) {
+            let valid = package_name.split('.').all(|segment| {
+                segment
+                    .chars()
+                    .next()
+                    .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+                    && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    && !self.keywords.contains(segment)
+            });
+            if !valid {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid Java package name: '{package_name}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Uppercase the first character of an identifier for accessor names
+fn capitalize(input: &str) -> String {
+    let mut chars = input.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Escape a JSON key for use inside a Java string literal
+fn escape_string_literal(input: &str) -> String {
+    input.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_java_generator_creation() {
+        let generator = JavaGenerator::new();
+        assert_eq!(generator.language_name(), "Java");
+        assert_eq!(generator.file_extension(), "java");
+    }
+
+    #[test]
+    fn test_map_field_type() {
+        let generator = JavaGenerator::new();
+
+        // Required scalars are primitives
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false), "String");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false), "long");
+        assert_eq!(generator.map_field_type(&FieldType::Number, false, false), "double");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false), "boolean");
+        assert_eq!(generator.map_field_type(&FieldType::Any, false, false), "JsonNode");
+
+        // Optional scalars and list elements are boxed
+        assert_eq!(generator.map_field_type(&FieldType::Integer, true, false), "Long");
+        assert_eq!(generator.map_field_type(&FieldType::Number, true, false), "Double");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, true, false), "Boolean");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true), "List<Long>");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, true), "List<String>");
+
+        let custom_type = FieldType::Custom("Profile".to_string());
+        assert_eq!(generator.map_field_type(&custom_type, true, false), "Profile");
+    }
+
+    #[test]
+    fn test_generate_record() {
+        let generator = JavaGenerator::new();
+        let struct_def = StructDefinition::new("User")
+            .add_field(FieldDefinition::new("user_id", "userId", FieldType::Integer))
+            .add_field(FieldDefinition::new("email", "email", FieldType::String).optional(true));
+
+        let result = generator.generate_type(&struct_def, JavaStyle::Record, true, false);
+        assert_eq!(
+            result,
+            "@JsonIgnoreProperties(ignoreUnknown = true)\n\
+             public record User(\n    \
+             @JsonProperty(\"user_id\") long userId,\n    \
+             @JsonProperty(\"email\") String email\n\
+             ) {\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_empty_record() {
+        let generator = JavaGenerator::new();
+
+        let result = generator.generate_type(&StructDefinition::new("Empty"), JavaStyle::Record, false, false);
+        assert_eq!(result, "@JsonIgnoreProperties(ignoreUnknown = true)\nrecord Empty() {\n}\n");
+    }
+
+    #[test]
+    fn test_generate_pojo() {
+        let generator = JavaGenerator::new();
+        let struct_def = StructDefinition::new("User")
+            .add_field(FieldDefinition::new("active", "active", FieldType::Boolean))
+            .add_field(FieldDefinition::new("score", "score", FieldType::Number).optional(true));
+
+        let result = generator.generate_type(&struct_def, JavaStyle::Pojo, true, false);
+        assert!(result.starts_with("@JsonIgnoreProperties(ignoreUnknown = true)\npublic class User {\n"));
+        assert!(result.contains("    @JsonProperty(\"active\")\n    private boolean active;\n"));
+        assert!(result.contains("    @JsonProperty(\"score\")\n    private Double score;\n"));
+        assert!(result.contains("    public boolean isActive() {\n        return active;\n    }\n"));
+        assert!(result.contains("    public Double getScore() {\n        return score;\n    }\n"));
+        assert!(result.contains("    public void setScore(Double score) {\n        this.score = score;\n    }\n"));
+        assert!(result.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_keyword_and_key_escaping() {
+        let generator = JavaGenerator::new();
+        let struct_def = StructDefinition::new("Config")
+            .add_field(FieldDefinition::new("default", "default", FieldType::String))
+            .add_field(FieldDefinition::new("say \"hi\"", "sayHi", FieldType::String));
+
+        let result = generator.generate_type(&struct_def, JavaStyle::Record, true, false);
+        assert!(result.contains("@JsonProperty(\"default\") String default_"));
+        assert!(result.contains("@JsonProperty(\"say \\\"hi\\\"\") String sayHi"));
+    }
+
+    #[test]
+    fn test_generate_single_file() {
+        let generator = JavaGenerator::new();
+        let json_data = json!({
+            "user_id": 42,
+            "tags": ["java"],
+            "extra": {},
+            "profile": {
+                "bio": "Developer"
+            }
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("package", "com.example.model")
+            .with_language_option("file_stem", "User");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("package com.example.model;\n\n"));
+        assert!(code.contains("import com.fasterxml.jackson.annotation.JsonIgnoreProperties;\n"));
+        assert!(code.contains("import com.fasterxml.jackson.annotation.JsonProperty;\n"));
+        assert!(code.contains("import com.fasterxml.jackson.databind.JsonNode;\n"));
+        assert!(code.contains("import java.util.List;\n"));
+
+        // Only the root type is public, and nested types are declared before it
+        assert_eq!(code.matches("public record").count(), 1);
+        let nested_pos = code.find("\nrecord Profile").unwrap();
+        let main_pos = code.find("public record User(").unwrap();
+        assert!(nested_pos < main_pos);
+        assert!(code.contains("@JsonProperty(\"extra\") JsonNode extra"));
+        assert!(code.contains("@JsonProperty(\"tags\") List<String> tags"));
+
+        // javac only accepts a public type in a file named after it
+        let options = options.with_language_option("file_stem", "users");
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(!code.contains("public record"));
+        assert!(code.contains("\nrecord User("));
+    }
+
+    #[test]
+    fn test_nested_type_references_match_declarations() {
+        let generator = JavaGenerator::new();
+        let json_data = json!({"p": {"n": "x", "q": {"m": 1}}});
+
+        let code = generator.generate(&json_data, &GenerationOptions::default()).unwrap();
+        for key in ["p", "q"] {
+            let annotation = format!("@JsonProperty(\"{key}\") ");
+            let component = code.lines().find_map(|line| line.trim().strip_prefix(annotation.as_str())).unwrap();
+            let type_name = component.split_whitespace().next().unwrap();
+            assert!(code.contains(&format!("record {type_name}(")), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_generate_files() {
+        let generator = JavaGenerator::new();
+        let json_data = json!({
+            "name": "Alice",
+            "address": {
+                "geo": {
+                    "lat": 1.5
+                }
+            }
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("java_style", "pojo");
+
+        let files = generator.generate_files(&json_data, &options).unwrap();
+        assert_eq!(files.len(), 3);
+
+        // Deeply nested types get their own files, dependencies first
+        let (name, content) = &files[2];
+        assert_eq!(name, "User.java");
+        assert!(content.contains("public class User {"));
+        assert!(!content.contains("import java.util.List;"));
+        for (name, content) in &files {
+            assert!(name.ends_with(".java"));
+            assert_eq!(content.matches("public class").count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = JavaGenerator::new();
+
+        assert!(generator.validate_options(&GenerationOptions::default()).is_ok());
+
+        let options = GenerationOptions::default()
+            .with_language_option("java_style", "pojo")
+            .with_language_option("package", "com.example");
+        assert!(generator.validate_options(&options).is_ok());
+
+        let options = GenerationOptions::default().with_language_option("java_style", "bean");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("package", "com.class");
+        assert!(generator.validate_options(&options).is_err());
+    }
+}
@@ -0,0 +1,445 @@
+//! # JSON Type Definition Generator
+//!
+//! This module implements a generator for JSON Type Definition (RFC 8927) schemas.
+//! JTD can express maps, enums and tagged unions that the intermediate
+//! representation has no notion of, so the schema is inferred from the sample
+//! values directly: every value seen at a position is merged into one schema.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::utils::detect_enum_values;
+use crate::error::{J2sError, Result};
+use crate::performance::SamplingStrategy;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Language option setting the largest number of distinct strings inferred as an enum
+const ENUM_MAX_OPTION: &str = "jtd_enum_max";
+
+/// Default for the enum option
+const DEFAULT_ENUM_MAX: usize = 8;
+
+/// Objects with more distinct keys than this are inferred as maps when their values agree
+const MAP_KEY_THRESHOLD: usize = 32;
+
+/// Most variants a tagged union is inferred with
+const MAX_VARIANTS: usize = 16;
+
+/// Keys preferred, in this order, as the discriminator of a tagged union
+const PREFERRED_TAGS: &[&str] = &["type", "kind", "tag", "event", "event_type", "eventType"];
+
+/// JSON Type Definition generator
+///
+/// This generator creates JTD schemas from JSON data, including:
+/// - `properties` for keys present in every sample and `optionalProperties` for the rest
+/// - `nullable: true` for positions where null was seen
+/// - `elements` for arrays and `values` for objects keyed by data such as ids or dates
+/// - `enum` for strings drawn from a small set of repeated values
+/// - `discriminator`/`mapping` for objects tagged by a string property
+///
+/// JTD has no 64-bit integers, so integers outside the `int32` range become `float64`.
+pub struct JtdGenerator;
+
+/// Settings used while inferring a schema
+struct Inference {
+    /// Largest number of distinct strings inferred as an enum; 0 disables enums
+    enum_max: usize,
+    /// Strategy for picking the array elements to inspect
+    sampling: SamplingStrategy,
+}
+
+impl JtdGenerator {
+    /// Create a new JTD schema generator
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for JtdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Inference {
+    /// Infer a schema accepting every one of the given values
+    fn infer(&self, values: &[&Value]) -> Map<String, Value> {
+        let present: Vec<&Value> = values.iter().copied().filter(|value| !value.is_null()).collect();
+        if present.is_empty() {
+            // The empty form accepts anything, null included
+            return Map::new();
+        }
+
+        let mut schema = if present.iter().all(|value| value.is_boolean()) {
+            type_form("boolean")
+        } else if present.iter().all(|value| value.is_number()) {
+            type_form(integer_or_float(&present))
+        } else if present.iter().all(|value| value.is_string()) {
+            let strings: Vec<&str> = present.iter().filter_map(|value| value.as_str()).collect();
+            self.string_schema(&strings)
+        } else if present.iter().all(|value| value.is_array()) {
+            let elements: Vec<&Value> = present
+                .iter()
+                .filter_map(|value| value.as_array())
+                .flat_map(|array| self.sampling.select_indices(array.len()).into_iter().map(|index| &array[index]))
+                .collect();
+            let mut schema = Map::new();
+            schema.insert("elements".to_string(), Value::Object(self.infer(&elements)));
+            schema
+        } else if present.iter().all(|value| value.is_object()) {
+            let objects: Vec<&Map<String, Value>> = present.iter().filter_map(|value| value.as_object()).collect();
+            self.object_schema(&objects)
+        } else {
+            // Values of mixed kinds can only be described by the empty form
+            return Map::new();
+        };
+
+        if present.len() < values.len() {
+            schema.insert("nullable".to_string(), Value::Bool(true));
+        }
+        schema
+    }
+
+    /// Infer a timestamp, enum or plain string schema
+    fn string_schema(&self, strings: &[&str]) -> Map<String, Value> {
+        if strings
+            .iter()
+            .all(|string| chrono::DateTime::parse_from_rfc3339(string).is_ok())
+        {
+            return type_form("timestamp");
+        }
+
+        if let Some(values) = detect_enum_values(strings, self.enum_max) {
+            let mut schema = Map::new();
+            let values = values.into_iter().map(Value::String).collect();
+            schema.insert("enum".to_string(), Value::Array(values));
+            return schema;
+        }
+
+        type_form("string")
+    }
+
+    /// Infer a discriminator, values or properties schema for objects
+    fn object_schema(&self, objects: &[&Map<String, Value>]) -> Map<String, Value> {
+        if let Some(tag) = self.find_discriminator(objects) {
+            let mut groups: BTreeMap<&str, Vec<&Map<String, Value>>> = BTreeMap::new();
+            for object in objects {
+                if let Some(value) = object.get(tag).and_then(Value::as_str) {
+                    groups.entry(value).or_default().push(object);
+                }
+            }
+
+            let mapping = groups
+                .into_iter()
+                .map(|(value, group)| (value.to_string(), Value::Object(self.properties_schema(&group, Some(tag)))))
+                .collect();
+
+            let mut schema = Map::new();
+            schema.insert("discriminator".to_string(), Value::String(tag.to_string()));
+            schema.insert("mapping".to_string(), Value::Object(mapping));
+            return schema;
+        }
+
+        if looks_like_map(objects) {
+            let values: Vec<&Value> = objects.iter().flat_map(|object| object.values()).collect();
+            let mut schema = Map::new();
+            schema.insert("values".to_string(), Value::Object(self.infer(&values)));
+            return schema;
+        }
+
+        self.properties_schema(objects, None)
+    }
+
+    /// Infer a properties schema, leaving out the discriminator if there is one
+    fn properties_schema(&self, objects: &[&Map<String, Value>], skip: Option<&str>) -> Map<String, Value> {
+        let keys: BTreeSet<&str> = objects
+            .iter()
+            .flat_map(|object| object.keys())
+            .map(String::as_str)
+            .filter(|key| Some(*key) != skip)
+            .collect();
+
+        let mut properties = Map::new();
+        let mut optional_properties = Map::new();
+        for key in keys {
+            let children: Vec<&Value> = objects.iter().filter_map(|object| object.get(key)).collect();
+            let schema = Value::Object(self.infer(&children));
+            if children.len() == objects.len() {
+                properties.insert(key.to_string(), schema);
+            } else {
+                optional_properties.insert(key.to_string(), schema);
+            }
+        }
+
+        let mut schema = Map::new();
+        if !properties.is_empty() || optional_properties.is_empty() {
+            schema.insert("properties".to_string(), Value::Object(properties));
+        }
+        if !optional_properties.is_empty() {
+            schema.insert("optionalProperties".to_string(), Value::Object(optional_properties));
+        }
+        schema
+    }
+
+    /// Find a string property whose values tell apart objects of different shapes
+    fn find_discriminator<'a>(&self, objects: &[&'a Map<String, Value>]) -> Option<&'a str> {
+        let first = objects.first()?;
+        let mut candidates: Vec<&str> = first
+            .keys()
+            .map(String::as_str)
+            .filter(|key| {
+                objects
+                    .iter()
+                    .all(|object| object.get(*key).is_some_and(Value::is_string))
+            })
+            .collect();
+        candidates.sort_by_key(|key| {
+            PREFERRED_TAGS
+                .iter()
+                .position(|tag| tag == key)
+                .unwrap_or(PREFERRED_TAGS.len())
+        });
+
+        candidates.into_iter().find(|tag| {
+            let mut shapes: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+            for object in objects {
+                if let Some(value) = object.get(*tag).and_then(Value::as_str) {
+                    shapes.entry(value).or_default().extend(object.keys().map(String::as_str));
+                }
+            }
+
+            // A tag only makes a union when its variants carry different properties
+            let mut distinct_shapes = shapes.values().collect::<Vec<_>>();
+            distinct_shapes.dedup();
+            shapes.len() >= 2 && shapes.len() <= MAX_VARIANTS && distinct_shapes.len() >= 2
+        })
+    }
+}
+
+impl CodeGenerator for JtdGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        let inference = Inference {
+            enum_max: enum_max(options)?,
+            sampling: options.sampling.clone(),
+        };
+
+        let schema = Value::Object(inference.infer(&[json_value]));
+        let mut result = serde_json::to_string_pretty(&schema)
+            .map_err(|e| J2sError::codegen_error(format!("Failed to serialize JTD schema: {e}")))?;
+        result.push('\n');
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "jtd.json"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "JTD"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        enum_max(options).map(|_| ())
+    }
+}
+
+/// Read the enum option, falling back to the default
+fn enum_max(options: &GenerationOptions) -> Result<usize> {
+    match options.get_language_option(ENUM_MAX_OPTION) {
+        Some(value) => value.parse().map_err(|_| {
+            J2sError::codegen_error(format!(
+                "Invalid JTD enum limit: '{value}'. Expected a non-negative number"
+            ))
+        }),
+        None => Ok(DEFAULT_ENUM_MAX),
+    }
+}
+
+/// Build a type form schema
+fn type_form(type_name: &str) -> Map<String, Value> {
+    let mut schema = Map::new();
+    schema.insert("type".to_string(), Value::String(type_name.to_string()));
+    schema
+}
+
+/// Pick `int32` when every number is an integer in its range, `float64` otherwise
+fn integer_or_float(numbers: &[&Value]) -> &'static str {
+    let all_int32 = numbers
+        .iter()
+        .all(|number| number.as_i64().is_some_and(|value| i32::try_from(value).is_ok()));
+    if all_int32 { "int32" } else { "float64" }
+}
+
+/// Check whether a key is data, such as a numeric id, a date or a UUID, rather than a name
+fn is_data_key(key: &str) -> bool {
+    let is_uuid = key.len() == 36
+        && key
+            .chars()
+            .enumerate()
+            .all(|(i, c)| if matches!(i, 8 | 13 | 18 | 23) { c == '-' } else { c.is_ascii_hexdigit() });
+    is_uuid || key.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Check whether objects are maps: keyed by data, or by many keys, with values of one kind
+fn looks_like_map(objects: &[&Map<String, Value>]) -> bool {
+    let keys: BTreeSet<&str> = objects
+        .iter()
+        .flat_map(|object| object.keys())
+        .map(String::as_str)
+        .collect();
+    if keys.is_empty() || !(keys.iter().all(|key| is_data_key(key)) || keys.len() > MAP_KEY_THRESHOLD) {
+        return false;
+    }
+
+    let kinds: BTreeSet<u8> = objects
+        .iter()
+        .flat_map(|object| object.values())
+        .filter(|value| !value.is_null())
+        .map(|value| match value {
+            Value::Bool(_) => 0,
+            Value::Number(_) => 1,
+            Value::String(_) => 2,
+            Value::Array(_) => 3,
+            _ => 4,
+        })
+        .collect();
+    kinds.len() <= 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Infer a schema for one sample with the default settings
+    fn infer(value: Value) -> Value {
+        let inference = Inference {
+            enum_max: DEFAULT_ENUM_MAX,
+            sampling: SamplingStrategy::default(),
+        };
+        Value::Object(inference.infer(&[&value]))
+    }
+
+    #[test]
+    fn test_jtd_generator_creation() {
+        let generator = JtdGenerator::new();
+        assert_eq!(generator.language_name(), "JTD");
+        assert_eq!(generator.file_extension(), "jtd.json");
+    }
+
+    #[test]
+    fn test_primitive_types() {
+        assert_eq!(infer(json!(true)), json!({"type": "boolean"}));
+        assert_eq!(infer(json!(42)), json!({"type": "int32"}));
+        assert_eq!(infer(json!(5_000_000_000_i64)), json!({"type": "float64"}));
+        assert_eq!(infer(json!(1.5)), json!({"type": "float64"}));
+        assert_eq!(infer(json!("Ada")), json!({"type": "string"}));
+        assert_eq!(infer(json!("2024-01-15T10:30:00Z")), json!({"type": "timestamp"}));
+        assert_eq!(infer(json!(null)), json!({}));
+        assert_eq!(infer(json!([1, "a"])), json!({"elements": {}}));
+    }
+
+    #[test]
+    fn test_optional_and_nullable_properties() {
+        let schema = infer(json!([
+            {"id": 1, "nickname": null, "email": "a@example.com"},
+            {"id": 2, "nickname": "Bo"}
+        ]));
+
+        assert_eq!(
+            schema,
+            json!({"elements": {
+                "properties": {
+                    "id": {"type": "int32"},
+                    "nickname": {"type": "string", "nullable": true}
+                },
+                "optionalProperties": {
+                    "email": {"type": "string"}
+                }
+            }})
+        );
+    }
+
+    #[test]
+    fn test_enum_detection() {
+        let schema = infer(json!([
+            {"status": "active", "name": "Ada"},
+            {"status": "inactive", "name": "Bo"},
+            {"status": "active", "name": "Cy"},
+            {"status": "inactive", "name": "Di"}
+        ]));
+        let properties = &schema["elements"]["properties"];
+
+        assert_eq!(properties["status"], json!({"enum": ["active", "inactive"]}));
+        assert_eq!(properties["name"], json!({"type": "string"}));
+
+        // A single sample is not enough to tell an enum apart
+        assert_eq!(infer(json!({"status": "active"}))["properties"]["status"], json!({"type": "string"}));
+    }
+
+    #[test]
+    fn test_map_detection() {
+        let schema = infer(json!({
+            "prices": {"2024-01-01": 9.5, "2024-01-02": 10},
+            "users": {
+                "3f2504e0-4f89-11d3-9a0c-0305e82c3301": {"name": "Ada"},
+                "7c9e6679-7425-40de-944b-e07fc1f90ae7": {"name": "Bo"}
+            }
+        }));
+        let properties = &schema["properties"];
+
+        assert_eq!(properties["prices"], json!({"values": {"type": "float64"}}));
+        assert_eq!(
+            properties["users"],
+            json!({"values": {"properties": {"name": {"type": "string"}}}})
+        );
+    }
+
+    #[test]
+    fn test_discriminator_detection() {
+        let schema = infer(json!([
+            {"type": "click", "x": 10, "y": 20},
+            {"type": "key", "code": "Enter"},
+            {"type": "click", "x": 5, "y": 7}
+        ]));
+
+        assert_eq!(
+            schema,
+            json!({"elements": {
+                "discriminator": "type",
+                "mapping": {
+                    "click": {"properties": {"x": {"type": "int32"}, "y": {"type": "int32"}}},
+                    "key": {"properties": {"code": {"type": "string"}}}
+                }
+            }})
+        );
+
+        // A tag shared by objects of one shape is a plain property
+        let schema = infer(json!([{"type": "a", "x": 1}, {"type": "b", "x": 2}]));
+        assert!(schema["elements"].get("discriminator").is_none());
+    }
+
+    #[test]
+    fn test_generate_schema() {
+        let generator = JtdGenerator::new();
+        let json_data = json!({"id": 1, "tags": ["a", "b"], "profile": {"bio": null}});
+
+        let options = GenerationOptions::default().with_struct_name("User");
+        let code = generator.generate(&json_data, &options).unwrap();
+        let schema: Value = serde_json::from_str(&code).unwrap();
+
+        assert_eq!(schema["properties"]["tags"], json!({"elements": {"type": "string"}}));
+        assert_eq!(schema["properties"]["profile"]["properties"]["bio"], json!({}));
+        assert!(code.ends_with("}\n"));
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = JtdGenerator::new();
+
+        let options = GenerationOptions::default().with_language_option("jtd_enum_max", "0");
+        assert!(generator.validate_options(&options).is_ok());
+
+        let options = GenerationOptions::default().with_language_option("jtd_enum_max", "many");
+        assert!(generator.validate_options(&options).is_err());
+    }
+}
@@ -0,0 +1,461 @@
+//! # Kotlin Language Code Generator
+//!
+//! This module implements the code generator for the Kotlin programming language.
+//! It generates `@Serializable` data classes for kotlinx.serialization with appropriate
+//! type mappings, KDoc comments, and follows Kotlin naming conventions.
+
+use crate::codegen::comments::{CommentGenerator, KotlinCommentGenerator};
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, escape_comment_string, flatten_structs, generate_timestamp};
+use crate::error::{J2sError, Result};
+use serde_json::Value;
+use std::collections::HashSet;
+
+/// Kotlin language code generator
+///
+/// This generator creates Kotlin data class definitions from JSON data, including:
+/// - Proper Kotlin naming conventions (PascalCase for classes, camelCase for properties)
+/// - `@Serializable` annotations and `@SerialName` for renamed keys
+/// - Nullable types with `null` defaults for optional fields
+/// - `List<T>` for arrays and `JsonElement` for values of unknown type
+pub struct KotlinGenerator {
+    /// Kotlin hard keywords that need to be avoided in generated identifiers
+    keywords: HashSet<String>,
+    /// Comment generator for KDoc comments
+    comment_generator: KotlinCommentGenerator,
+}
+
+impl KotlinGenerator {
+    /// Create a new Kotlin code generator
+    pub fn new() -> Self {
+        Self {
+            keywords: NameConverter::get_reserved_keywords("kotlin"),
+            comment_generator: KotlinCommentGenerator,
+        }
+    }
+
+    /// Get the Kotlin class name for a struct, used both to declare and to reference it
+    fn type_name(&self, name: &str) -> String {
+        NameConverter::sanitize_identifier(&NameConverter::to_pascal_case(name), &self.keywords)
+    }
+
+    /// Map a FieldType to the appropriate Kotlin type string
+    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool) -> String {
+        let mut result = match field_type {
+            FieldType::String => "String".to_string(),
+            FieldType::Integer => "Long".to_string(),
+            FieldType::Number => "Double".to_string(),
+            FieldType::Boolean => "Boolean".to_string(),
+            FieldType::Custom(name) => self.type_name(name),
+            // kotlinx.serialization cannot handle `Any`, so keep the raw JSON element
+            FieldType::Any => "JsonElement".to_string(),
+        };
+
+        // Handle arrays
+        if is_array {
+            result = format!("List<{result}>");
+        }
+
+        // Handle optional fields with nullable types
+        if is_optional {
+            result.push('?');
+        }
+
+        result
+    }
+
+    /// Generate a Kotlin constructor property for a data class field
+    fn generate_field(&self, field: &FieldDefinition, include_comments: bool) -> String {
+        // The code_name is already converted to the proper case by JsonToIrConverter
+        let sanitized_name = NameConverter::sanitize_identifier(&field.code_name, &self.keywords);
+
+        let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
+
+        // Add KDoc if enabled
+        let mut result = String::new();
+        if include_comments {
+            if !field.comments.is_empty() {
+                for comment in &field.comments {
+                    let escaped_comment = escape_comment_string(comment);
+                    result.push_str(&format!("    /** {escaped_comment} */\n"));
+                }
+            } else {
+                // Generate automatic field comment using enhanced inference
+                use crate::codegen::comments::utils::infer_field_description;
+                let description = infer_field_description(&field.json_name, &field_type);
+                let field_comment = self.comment_generator.generate_field_comment(
+                    &field.json_name,
+                    &field_type,
+                    Some(&description),
+                );
+                result.push_str(&field_comment);
+            }
+        }
+
+        // Keep the original JSON key when the property name differs from it
+        if field.json_name != sanitized_name {
+            result.push_str(&format!(
+                "    @SerialName(\"{}\")\n",
+                escape_string_literal(&field.json_name)
+            ));
+        }
+
+        // Nullable properties default to null so missing keys deserialize
+        if field.is_optional {
+            result.push_str(&format!("    val {sanitized_name}: {field_type} = null,"));
+        } else {
+            result.push_str(&format!("    val {sanitized_name}: {field_type},"));
+        }
+        result
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "// Code generated by j2s (JSON to Struct) tool\n// Generated at: {timestamp}\n// DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+
+    /// Generate the import statements needed by the generated classes
+    fn generate_imports(&self, struct_def: &StructDefinition) -> String {
+        let mut imports = Vec::new();
+
+        if self.uses_serial_name(struct_def) {
+            imports.push("import kotlinx.serialization.SerialName");
+        }
+        imports.push("import kotlinx.serialization.Serializable");
+        if uses_json_element(struct_def) {
+            imports.push("import kotlinx.serialization.json.JsonElement");
+        }
+
+        imports.join("\n") + "\n\n"
+    }
+
+    /// Recursively check whether any field needs a `@SerialName` annotation
+    fn uses_serial_name(&self, struct_def: &StructDefinition) -> bool {
+        struct_def.fields.iter().any(|field| {
+            field.json_name != NameConverter::sanitize_identifier(&field.code_name, &self.keywords)
+        }) || struct_def
+            .nested_structs
+            .iter()
+            .any(|nested| self.uses_serial_name(nested))
+    }
+
+    /// Generate a complete Kotlin data class definition
+    fn generate_class(&self, struct_def: &StructDefinition, include_comments: bool) -> String {
+        let sanitized_name = self.type_name(&struct_def.name);
+
+        let mut result = String::new();
+
+        // Add class KDoc if enabled
+        if include_comments {
+            if !struct_def.comments.is_empty() {
+                let escaped: Vec<String> = struct_def
+                    .comments
+                    .iter()
+                    .map(|comment| escape_comment_string(comment))
+                    .collect();
+                let lines: Vec<&str> = escaped.iter().map(String::as_str).collect();
+                result.push_str(&self.comment_generator.generate_multi_line_comment(&lines));
+            } else {
+                // Generate automatic class comment
+                let class_comment = self.comment_generator.generate_struct_comment(&sanitized_name, None);
+                result.push_str(&class_comment);
+            }
+        }
+
+        result.push_str("@Serializable\n");
+
+        // A data class needs at least one property, so empty objects become plain classes
+        if struct_def.fields.is_empty() {
+            result.push_str(&format!("class {sanitized_name}\n"));
+            return result;
+        }
+
+        result.push_str(&format!("data class {sanitized_name}(\n"));
+        for field in &struct_def.fields {
+            result.push_str(&self.generate_field(field, include_comments));
+            result.push('\n');
+        }
+        result.push_str(")\n");
+
+        result
+    }
+}
+
+impl Default for KotlinGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for KotlinGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        // Determine class name
+        let struct_name = options.get_struct_name("GeneratedClass");
+        let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "kotlin");
+
+        // Convert JSON to intermediate representation
+        let struct_def = ir.struct_for(IrSource::Document, "kotlin", &sanitized_struct_name)?;
+
+        // Generate Kotlin code
+        let mut result = String::new();
+
+        // Add file header comment if comments are enabled
+        if options.include_comments {
+            result.push_str(&self.generate_file_header());
+        }
+
+        // Add package declaration if one was requested
+        if let Some(package_name) = options.get_language_option("package") {
+            result.push_str(&format!("package {package_name}\n\n"));
+        }
+
+        result.push_str(&self.generate_imports(&struct_def));
+
+        // Generate nested classes first, at every depth, then the main class
+        let classes = flatten_structs(&struct_def);
+        for nested_struct in &classes[..classes.len() - 1] {
+            result.push_str(&self.generate_class(nested_struct, options.include_comments));
+            result.push('\n');
+        }
+        result.push_str(&self.generate_class(&struct_def, options.include_comments));
+
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "kt"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "Kotlin"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        if let Some(package_name) = options.get_language_option("package") {
+            let valid = package_name.split('.').all(|segment| {
+                segment
+                    .chars()
+                    .next()
+                    .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+                    && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            });
+            if !valid {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid Kotlin package name: '{package_name}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Recursively check whether any field falls back to `JsonElement`
+fn uses_json_element(struct_def: &StructDefinition) -> bool {
+    struct_def
+        .fields
+        .iter()
+        .any(|field| matches!(field.field_type, FieldType::Any))
+        || struct_def.nested_structs.iter().any(uses_json_element)
+}
+
+/// Escape a JSON key for use inside a Kotlin string literal
+///
+/// Besides quotes and backslashes, `$` must be escaped because Kotlin strings
+/// support templates (keys such as `$ref` are common in JSON documents).
+fn escape_string_literal(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_kotlin_generator_creation() {
+        let generator = KotlinGenerator::new();
+        assert_eq!(generator.language_name(), "Kotlin");
+        assert_eq!(generator.file_extension(), "kt");
+    }
+
+    #[test]
+    fn test_map_field_type() {
+        let generator = KotlinGenerator::new();
+
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false), "String");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false), "Long");
+        assert_eq!(generator.map_field_type(&FieldType::Number, false, false), "Double");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false), "Boolean");
+        assert_eq!(generator.map_field_type(&FieldType::Any, false, false), "JsonElement");
+
+        // Test nullable and list types
+        assert_eq!(generator.map_field_type(&FieldType::String, true, false), "String?");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true), "List<Long>");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, true), "List<String>?");
+
+        let custom_type = FieldType::Custom("Profile".to_string());
+        assert_eq!(generator.map_field_type(&custom_type, true, false), "Profile?");
+    }
+
+    #[test]
+    fn test_generate_field() {
+        let generator = KotlinGenerator::new();
+
+        let field = FieldDefinition::new("name", "name", FieldType::String)
+            .add_comment("The user's name");
+        let result = generator.generate_field(&field, true);
+        assert!(result.contains("    /** The user's name */\n"));
+        assert!(result.ends_with("    val name: String,"));
+        assert!(!result.contains("@SerialName"));
+    }
+
+    #[test]
+    fn test_generate_field_optional() {
+        let generator = KotlinGenerator::new();
+
+        let field = FieldDefinition::new("email", "email", FieldType::String).optional(true);
+        let result = generator.generate_field(&field, false);
+        assert_eq!(result, "    val email: String? = null,");
+    }
+
+    #[test]
+    fn test_generate_field_with_serial_name() {
+        let generator = KotlinGenerator::new();
+
+        let field = FieldDefinition::new("first_name", "firstName", FieldType::String);
+        let result = generator.generate_field(&field, false);
+        assert!(result.contains("    @SerialName(\"first_name\")\n"));
+        assert!(result.contains("val firstName: String,"));
+
+        // Keys that need escaping inside a Kotlin string
+        let field = FieldDefinition::new("$ref", "ref", FieldType::String);
+        let result = generator.generate_field(&field, false);
+        assert!(result.contains("@SerialName(\"\\$ref\")"));
+    }
+
+    #[test]
+    fn test_keyword_sanitization() {
+        let generator = KotlinGenerator::new();
+
+        let field = FieldDefinition::new("when", "when", FieldType::String);
+        let result = generator.generate_field(&field, false);
+        assert!(result.contains("@SerialName(\"when\")"));
+        assert!(result.contains("val when_: String,"));
+    }
+
+    #[test]
+    fn test_generate_empty_class() {
+        let generator = KotlinGenerator::new();
+
+        let result = generator.generate_class(&StructDefinition::new("Empty"), false);
+        assert_eq!(result, "@Serializable\nclass Empty\n");
+    }
+
+    #[test]
+    fn test_generate_simple_class() {
+        let generator = KotlinGenerator::new();
+        let json_data = json!({
+            "user_id": 42,
+            "name": "John Doe",
+            "score": 9.5,
+            "active": true,
+            "nickname": null,
+            "tags": ["kotlin", "android"]
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false);
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("import kotlinx.serialization.SerialName\n"));
+        assert!(code.contains("import kotlinx.serialization.Serializable\n"));
+        assert!(code.contains("import kotlinx.serialization.json.JsonElement\n"));
+        assert!(code.contains("@Serializable\ndata class User(\n"));
+        assert!(code.contains("    @SerialName(\"user_id\")\n    val userId: Long,\n"));
+        assert!(code.contains("    val name: String,\n"));
+        assert!(code.contains("    val score: Double,\n"));
+        assert!(code.contains("    val active: Boolean,\n"));
+        assert!(code.contains("    val nickname: JsonElement? = null,\n"));
+        assert!(code.contains("    val tags: List<String>,\n"));
+        assert!(code.ends_with(")\n"));
+    }
+
+    #[test]
+    fn test_generate_nested_classes() {
+        let generator = KotlinGenerator::new();
+        let json_data = json!({
+            "profile": {
+                "bio": "Developer",
+                "links": [{"url": "https://example.com"}]
+            }
+        });
+
+        let options = GenerationOptions::default().with_struct_name("Account");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("// Code generated by j2s (JSON to Struct) tool\n// Generated at: "));
+        assert!(code.contains("// DO NOT EDIT - This file was automatically generated\n\n"));
+        assert!(code.contains("val profile: Profile"));
+        assert!(code.contains("/**\n * Account represents the structure of the JSON data\n */\n"));
+        assert!(!code.contains("JsonElement"));
+
+        // Nested classes are declared before the root class
+        let profile_pos = code.find("data class Profile").unwrap();
+        let account_pos = code.find("data class Account(").unwrap();
+        assert!(profile_pos < account_pos);
+    }
+
+    #[test]
+    fn test_nested_class_references_match_declarations() {
+        let generator = KotlinGenerator::new();
+        let json_data = json!({"p": {"n": "x", "q": {"m": 1}}});
+
+        let code = generator.generate(&json_data, &GenerationOptions::default()).unwrap();
+        for key in ["p", "q"] {
+            let property = format!("val {key}: ");
+            let field_line = code.lines().find_map(|line| line.trim().strip_prefix(property.as_str())).unwrap();
+            let type_name = field_line.trim_end_matches(',');
+            assert!(code.contains(&format!("data class {type_name}(")), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_generate_with_package() {
+        let generator = KotlinGenerator::new();
+        let json_data = json!({"id": 1});
+
+        let options = GenerationOptions::default()
+            .with_comments(false)
+            .with_language_option("package", "com.example.model");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("package com.example.model\n\nimport kotlinx.serialization.Serializable\n"));
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = KotlinGenerator::new();
+        assert!(generator.validate_options(&GenerationOptions::default()).is_ok());
+
+        let options = GenerationOptions::default().with_language_option("package", "com.example");
+        assert!(generator.validate_options(&options).is_ok());
+
+        let options = GenerationOptions::default().with_language_option("package", "com..example");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("package", "1com");
+        assert!(generator.validate_options(&options).is_err());
+    }
+}
@@ -10,6 +10,18 @@
 //! - **Rust**: Generates Rust structs with serde derive macros and Option types
 //! - **TypeScript**: Generates TypeScript interfaces with optional properties
 //! - **Python**: Generates Python dataclasses with type annotations
+//! - **Kotlin**: Generates kotlinx.serialization data classes with nullable types
+//! - **Swift**: Generates Codable structs with CodingKeys and optionals
+//! - **Java**: Generates Jackson-annotated records or POJOs
+//! - **C#**: Generates System.Text.Json records or classes with nullable reference types
+//! - **Dart**: Generates json_serializable classes with final fields
+//! - **Zod**: Generates zod schemas with inferred TypeScript types for runtime validation
+//! - **Protocol Buffers**: Generates proto3 messages with stable field numbers
+//! - **GraphQL**: Generates GraphQL SDL object and input types
+//! - **SQL**: Generates PostgreSQL or SQLite CREATE TABLE statements from JSON records
+//! - **Avro**: Generates Apache Avro record schemas
+//! - **OpenAPI**: Generates OpenAPI 3.1 components with $ref-linked schemas
+//! - **JTD**: Generates JSON Type Definition (RFC 8927) schemas
 //!
 //! ## Adding New Languages
 //!
@@ -21,7 +33,19 @@
 //! 4. Update the `GeneratorFactory` to include your new generator
 //! 5. Add appropriate tests
 
+pub mod avro;
+pub mod csharp;
+pub mod dart;
 pub mod go;
+pub mod graphql;
+pub mod java;
+pub mod jtd;
+pub mod kotlin;
+pub mod openapi;
+pub mod proto;
 pub mod python;
 pub mod rust;
-pub mod typescript;
\ No newline at end of file
+pub mod sql;
+pub mod swift;
+pub mod typescript;
+pub mod zod;
\ No newline at end of file
@@ -0,0 +1,649 @@
+//! # OpenAPI 3.1 Components Generator
+//!
+//! This module turns a JSON sample into an OpenAPI 3.1 document fragment. The
+//! schema is inferred by `schema_generator`, since OpenAPI 3.1 schemas are JSON
+//! Schema 2020-12; every object schema is then moved under `components/schemas`
+//! and linked by `$ref`. The sample can also be attached to a named response, and
+//! a directory of captured responses adds one named response per file, so a set of
+//! captured responses turns into a starter API specification.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::utils::{NameConverter, generate_timestamp};
+use crate::error::{J2sError, Result};
+use crate::schema_generator::{JsonSchema, SchemaType, generate_schema_with_sampling};
+use serde_json::Value;
+
+/// Language option selecting the document syntax ("yaml" or "json")
+const SYNTAX_OPTION: &str = "openapi_format";
+
+/// Language option naming a response under `components/responses` that returns the sample
+const RESPONSE_OPTION: &str = "openapi_response";
+
+/// Language option naming a directory whose `.json` files each become a named response
+const RESPONSES_OPTION: &str = "openapi_responses";
+
+/// The syntax of the generated document
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DocumentSyntax {
+    /// YAML block style
+    Yaml,
+    /// Pretty-printed JSON
+    Json,
+}
+
+impl DocumentSyntax {
+    /// Read the syntax from the generation options, defaulting to YAML
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        match options.get_language_option(SYNTAX_OPTION).map(String::as_str) {
+            None | Some("yaml") => Ok(Self::Yaml),
+            Some("json") => Ok(Self::Json),
+            Some(other) => Err(J2sError::codegen_error(format!(
+                "Invalid OpenAPI format: '{other}'. Supported formats: yaml, json"
+            ))),
+        }
+    }
+}
+
+/// A document node whose mapping keys keep their insertion order
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    /// A string, number, boolean or null
+    Scalar(Value),
+    /// A mapping with ordered keys
+    Map(Vec<(String, Node)>),
+    /// A sequence
+    List(Vec<Node>),
+}
+
+impl Node {
+    /// Create a string scalar
+    fn string(value: impl Into<String>) -> Self {
+        Self::Scalar(Value::String(value.into()))
+    }
+
+    /// Render the node as pretty-printed JSON
+    fn to_json(&self, indent: usize) -> String {
+        let padding = "  ".repeat(indent + 1);
+        let closing = "  ".repeat(indent);
+        match self {
+            Self::Scalar(value) => value.to_string(),
+            Self::Map(entries) if entries.is_empty() => "{}".to_string(),
+            Self::List(items) if items.is_empty() => "[]".to_string(),
+            Self::Map(entries) => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(key, node)| format!("{padding}{}: {}", Value::String(key.clone()), node.to_json(indent + 1)))
+                    .collect();
+                format!("{{\n{}\n{closing}}}", entries.join(",\n"))
+            }
+            Self::List(items) => {
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|node| format!("{padding}{}", node.to_json(indent + 1)))
+                    .collect();
+                format!("[\n{}\n{closing}]", items.join(",\n"))
+            }
+        }
+    }
+
+    /// Render the node as YAML block-style lines at the given indentation
+    fn to_yaml(&self, indent: usize, output: &mut String) {
+        let padding = "  ".repeat(indent);
+        match self {
+            Self::Scalar(_) => {
+                output.push_str(&format!("{padding}{}\n", self.inline_yaml()));
+            }
+            Self::Map(entries) => {
+                for (key, node) in entries {
+                    output.push_str(&format!("{padding}{}:", yaml_string(key)));
+                    node.write_yaml_value(indent, output);
+                }
+            }
+            Self::List(items) => {
+                for node in items {
+                    output.push_str(&format!("{padding}-"));
+                    node.write_yaml_value(indent, output);
+                }
+            }
+        }
+    }
+
+    /// Write the value after a `key:` or `-`, inline for scalars and empty collections
+    fn write_yaml_value(&self, indent: usize, output: &mut String) {
+        match self {
+            Self::Map(entries) if !entries.is_empty() => {
+                output.push('\n');
+                self.to_yaml(indent + 1, output);
+            }
+            Self::List(items) if !items.is_empty() => {
+                output.push('\n');
+                self.to_yaml(indent + 1, output);
+            }
+            _ => output.push_str(&format!(" {}\n", self.inline_yaml())),
+        }
+    }
+
+    /// Render a scalar or empty collection as an inline YAML value
+    fn inline_yaml(&self) -> String {
+        match self {
+            Self::Scalar(Value::String(value)) => yaml_string(value),
+            Self::Scalar(value) => value.to_string(),
+            Self::Map(_) => "{}".to_string(),
+            Self::List(_) => "[]".to_string(),
+        }
+    }
+}
+
+/// Render a string as a YAML scalar, quoting it when it would not read back as the same string
+fn yaml_string(value: &str) -> String {
+    let mut chars = value.chars();
+    let is_plain = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '/' || first == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | '{' | '}' | ' '))
+        && !value.ends_with(' ')
+        && !matches!(
+            value.to_ascii_lowercase().as_str(),
+            "true" | "false" | "null" | "yes" | "no" | "on" | "off" | "y" | "n"
+        );
+    if is_plain {
+        value.to_string()
+    } else {
+        Value::String(value.to_string()).to_string()
+    }
+}
+
+/// OpenAPI 3.1 components generator
+///
+/// This generator creates an OpenAPI 3.1 document fragment from JSON data, including:
+/// - Every object schema under `components/schemas`, linked by `$ref`
+/// - Component names derived from the property path, made unique when they collide
+/// - An optional named response under `components/responses` returning the sample
+/// - One named response per captured `.json` sample in a directory
+/// - YAML (default) or JSON syntax
+pub struct OpenApiGenerator;
+
+impl OpenApiGenerator {
+    /// Create a new OpenAPI components generator
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Convert a schema to a document node, moving object schemas into the components
+    ///
+    /// Objects with properties are registered under `components/schemas` with the given
+    /// name, made unique if needed, and replaced by a `$ref`.
+    fn schema_node(&self, schema: &JsonSchema, name: &str, components: &mut Vec<(String, Node)>) -> Node {
+        match (&schema.type_name, &schema.properties) {
+            (SchemaType::Object, Some(properties)) if !properties.is_empty() => {
+                let mut keys: Vec<&String> = properties.keys().collect();
+                keys.sort();
+
+                // Reserve the slot first so that parents are listed before their children
+                let slot = components.len();
+                components.push((String::new(), Node::Map(Vec::new())));
+
+                let mut property_nodes = Vec::new();
+                for key in keys {
+                    let child_name = format!("{name}{}", component_name(key));
+                    property_nodes.push((key.clone(), self.schema_node(&properties[key], &child_name, components)));
+                }
+
+                let mut entries = vec![("type".to_string(), Node::string("object"))];
+                entries.push(("properties".to_string(), Node::Map(property_nodes)));
+                if let Some(required) = &schema.required {
+                    let mut required = required.clone();
+                    required.sort();
+                    entries.push((
+                        "required".to_string(),
+                        Node::List(required.into_iter().map(Node::string).collect()),
+                    ));
+                }
+                append_annotations(schema, &mut entries);
+
+                // Property paths can collide after case conversion, so pick a free name
+                let final_name = free_name(name, components);
+                components[slot] = (final_name.clone(), Node::Map(entries));
+                schema_ref(&final_name)
+            }
+            (SchemaType::Array, _) => {
+                let mut entries = vec![("type".to_string(), Node::string("array"))];
+                if let Some(items) = &schema.items {
+                    entries.push(("items".to_string(), self.schema_node(items, &format!("{name}Item"), components)));
+                }
+                append_annotations(schema, &mut entries);
+                Node::Map(entries)
+            }
+            (type_name, _) => {
+                let type_name = serde_json::to_value(type_name).unwrap_or(Value::Null);
+                let mut entries = vec![("type".to_string(), Node::Scalar(type_name))];
+                append_annotations(schema, &mut entries);
+                Node::Map(entries)
+            }
+        }
+    }
+
+    /// Register the schemas of a sample and get a `$ref` to its root schema
+    ///
+    /// The root schema is listed first under `name`, made unique if needed, even when
+    /// it is not an object, so every sample keeps a component of its own.
+    fn sample_node(&self, schema: &JsonSchema, name: &str, components: &mut Vec<(String, Node)>) -> Node {
+        let start = components.len();
+        let root = self.schema_node(schema, name, components);
+
+        // Objects with properties are already registered and referenced
+        if matches!(&root, Node::Map(entries) if matches!(entries.as_slice(), [(key, _)] if key == "$ref")) {
+            return root;
+        }
+
+        let final_name = free_name(name, components);
+        components.insert(start, (final_name.clone(), root));
+        schema_ref(&final_name)
+    }
+
+    /// Build the document for a JSON sample and any captured response samples
+    fn build_document(
+        &self,
+        json_value: &Value,
+        root_name: &str,
+        response: Option<&str>,
+        samples: &[(String, Value)],
+        options: &GenerationOptions,
+    ) -> Node {
+        let schema = generate_schema_with_sampling(json_value, &options.sampling, false);
+
+        let mut schemas = Vec::new();
+        let root_ref = self.sample_node(&schema, root_name, &mut schemas);
+
+        let mut responses = Vec::new();
+        if let Some(response) = response {
+            responses.push((response.to_string(), response_node(response, root_ref.clone())));
+        }
+
+        // Samples with the same schema share their components
+        let mut seen = vec![(schema, root_ref)];
+        for (name, sample) in samples {
+            if responses.iter().any(|(taken, _)| taken == name) {
+                continue;
+            }
+            let sample_schema = generate_schema_with_sampling(sample, &options.sampling, false);
+            let sample_ref = match seen.iter().find(|(known, _)| *known == sample_schema) {
+                Some((_, node)) => node.clone(),
+                None => {
+                    let node = self.sample_node(&sample_schema, &component_name(name), &mut schemas);
+                    seen.push((sample_schema, node.clone()));
+                    node
+                }
+            };
+            responses.push((name.clone(), response_node(name, sample_ref)));
+        }
+
+        let mut components = vec![("schemas".to_string(), Node::Map(schemas))];
+        if !responses.is_empty() {
+            components.push(("responses".to_string(), Node::Map(responses)));
+        }
+
+        Node::Map(vec![
+            ("openapi".to_string(), Node::string("3.1.0")),
+            (
+                "info".to_string(),
+                Node::Map(vec![
+                    ("title".to_string(), Node::string(format!("{root_name} API"))),
+                    ("version".to_string(), Node::string("0.1.0")),
+                ]),
+            ),
+            ("components".to_string(), Node::Map(components)),
+        ])
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "# Code generated by j2s (JSON to Struct) tool\n# Generated at: {timestamp}\n# DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+}
+
+impl Default for OpenApiGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for OpenApiGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        let syntax = DocumentSyntax::from_options(options)?;
+
+        // Determine the root component name
+        let struct_name = options.get_struct_name("GeneratedSchema");
+        let root_name = component_name(&struct_name);
+
+        let response = options.get_language_option(RESPONSE_OPTION).map(String::as_str);
+        let samples = match options.get_language_option(RESPONSES_OPTION) {
+            Some(dir) => read_response_samples(dir)?,
+            None => Vec::new(),
+        };
+        let document = self.build_document(json_value, &root_name, response, &samples, options);
+
+        match syntax {
+            DocumentSyntax::Yaml => {
+                let mut result = String::new();
+
+                // Add file header comment if comments are enabled
+                if options.include_comments {
+                    result.push_str(&self.generate_file_header());
+                }
+
+                document.to_yaml(0, &mut result);
+                Ok(result)
+            }
+            DocumentSyntax::Json => Ok(format!("{}\n", document.to_json(0))),
+        }
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "openapi.yaml"
+    }
+
+    fn output_extension(&self, options: &GenerationOptions) -> &'static str {
+        match DocumentSyntax::from_options(options) {
+            Ok(DocumentSyntax::Json) => "openapi.json",
+            _ => self.file_extension(),
+        }
+    }
+
+    fn language_name(&self) -> &'static str {
+        "OpenAPI"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        DocumentSyntax::from_options(options)?;
+
+        if let Some(response) = options.get_language_option(RESPONSE_OPTION)
+            && (response.is_empty()
+                || !response.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-')))
+        {
+            return Err(J2sError::codegen_error(format!(
+                "Invalid OpenAPI response name: '{response}'. Names may only contain letters, digits, '.', '_' and '-'"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Read every `.json` file of a directory as a response sample named after the file
+///
+/// Files are read in name order so the document does not depend on the directory listing.
+fn read_response_samples(dir: &str) -> Result<Vec<(String, Value)>> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| J2sError::file_error(format!("Failed to read response directory '{dir}': {e}")))?;
+
+    let mut paths: Vec<std::path::PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|extension| extension == "json"))
+        .collect();
+    paths.sort();
+    if paths.is_empty() {
+        return Err(J2sError::file_error(format!("No .json response samples found in '{dir}'")));
+    }
+
+    paths
+        .iter()
+        .map(|path| {
+            let content = std::fs::read_to_string(path).map_err(|e| {
+                J2sError::file_error(format!("Failed to read response sample '{}': {e}", path.display()))
+            })?;
+            let sample = serde_json::from_str(&content).map_err(|e| {
+                J2sError::json_error(format!("Invalid JSON in response sample '{}': {e}", path.display()))
+            })?;
+            let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("response");
+            Ok((response_name(stem), sample))
+        })
+        .collect()
+}
+
+/// Turn a file stem into a response name, which may only contain letters, digits, `.`, `_` and `-`
+fn response_name(stem: &str) -> String {
+    stem.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') { c } else { '_' })
+        .collect()
+}
+
+/// Build a response whose `application/json` body follows the given schema
+fn response_node(name: &str, schema: Node) -> Node {
+    Node::Map(vec![
+        ("description".to_string(), Node::string(format!("{name} response"))),
+        (
+            "content".to_string(),
+            Node::Map(vec![(
+                "application/json".to_string(),
+                Node::Map(vec![("schema".to_string(), schema)]),
+            )]),
+        ),
+    ])
+}
+
+/// Build a `$ref` to a component schema
+fn schema_ref(name: &str) -> Node {
+    Node::Map(vec![("$ref".to_string(), Node::string(format!("#/components/schemas/{name}")))])
+}
+
+/// Pick a component name that is not taken yet, appending a counter if needed
+fn free_name(name: &str, components: &[(String, Node)]) -> String {
+    let mut final_name = name.to_string();
+    let mut counter = 2;
+    while components.iter().any(|(taken, _)| *taken == final_name) {
+        final_name = format!("{name}{counter}");
+        counter += 1;
+    }
+    final_name
+}
+
+/// Append the title and description of a schema to its node entries
+fn append_annotations(schema: &JsonSchema, entries: &mut Vec<(String, Node)>) {
+    if let Some(title) = &schema.title {
+        entries.push(("title".to_string(), Node::string(title.clone())));
+    }
+    if let Some(description) = &schema.description {
+        entries.push(("description".to_string(), Node::string(description.clone())));
+    }
+}
+
+/// Turn a property or type name into a component name
+///
+/// Component names may only contain letters, digits, `.`, `_` and `-`.
+fn component_name(name: &str) -> String {
+    let converted = NameConverter::to_pascal_case(&NameConverter::clean_string(name));
+    if converted.is_empty() { "Schema".to_string() } else { converted }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_openapi_generator_creation() {
+        let generator = OpenApiGenerator::new();
+        assert_eq!(generator.language_name(), "OpenAPI");
+        assert_eq!(generator.file_extension(), "openapi.yaml");
+
+        let options = GenerationOptions::default().with_language_option("openapi_format", "json");
+        assert_eq!(generator.output_extension(&options), "openapi.json");
+        assert_eq!(generator.output_extension(&GenerationOptions::default()), "openapi.yaml");
+    }
+
+    #[test]
+    fn test_yaml_string_quoting() {
+        assert_eq!(yaml_string("User"), "User");
+        assert_eq!(yaml_string("$ref"), "$ref");
+        assert_eq!(yaml_string("application/json"), "application/json");
+        assert_eq!(yaml_string("#/components/schemas/User"), "\"#/components/schemas/User\"");
+        assert_eq!(yaml_string("200"), "\"200\"");
+        assert_eq!(yaml_string("null"), "\"null\"");
+        assert_eq!(yaml_string("No"), "\"No\"");
+        assert_eq!(yaml_string("a: b"), "\"a: b\"");
+        assert_eq!(yaml_string(""), "\"\"");
+    }
+
+    #[test]
+    fn test_node_rendering() {
+        let node = Node::Map(vec![
+            ("name".to_string(), Node::string("Ada")),
+            ("tags".to_string(), Node::List(vec![Node::string("a"), Node::Map(vec![("x".to_string(), Node::Scalar(json!(1)))])])),
+            ("empty".to_string(), Node::Map(Vec::new())),
+        ]);
+
+        let mut yaml = String::new();
+        node.to_yaml(0, &mut yaml);
+        assert_eq!(yaml, "name: Ada\ntags:\n  - a\n  -\n    x: 1\nempty: {}\n");
+
+        assert_eq!(
+            node.to_json(0),
+            "{\n  \"name\": \"Ada\",\n  \"tags\": [\n    \"a\",\n    {\n      \"x\": 1\n    }\n  ],\n  \"empty\": {}\n}"
+        );
+    }
+
+    #[test]
+    fn test_components_with_refs() {
+        let generator = OpenApiGenerator::new();
+        let json_data = json!({
+            "id": 1,
+            "nickname": null,
+            "address": {"city": "Paris"},
+            "orders": [{"total": 9.5}]
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("user")
+            .with_language_option("openapi_format", "json");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        let document: Value = serde_json::from_str(&code).unwrap();
+        assert_eq!(document["openapi"], "3.1.0");
+
+        let schemas = &document["components"]["schemas"];
+        let user = &schemas["User"];
+        assert_eq!(user["type"], "object");
+        assert_eq!(user["properties"]["id"], json!({"type": "integer"}));
+        assert_eq!(user["properties"]["nickname"], json!({"type": "null"}));
+        assert_eq!(user["properties"]["address"], json!({"$ref": "#/components/schemas/UserAddress"}));
+        assert_eq!(
+            user["properties"]["orders"],
+            json!({"type": "array", "items": {"$ref": "#/components/schemas/UserOrdersItem"}})
+        );
+        assert_eq!(user["required"], json!(["address", "id", "orders"]));
+        assert_eq!(schemas["UserAddress"]["properties"]["city"], json!({"type": "string"}));
+        assert_eq!(schemas["UserOrdersItem"]["properties"]["total"], json!({"type": "number"}));
+
+        // Components are listed parents first
+        let user_pos = code.find("\"User\":").unwrap();
+        let address_pos = code.find("\"UserAddress\":").unwrap();
+        assert!(user_pos < address_pos);
+    }
+
+    #[test]
+    fn test_named_response() {
+        let generator = OpenApiGenerator::new();
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("openapi_response", "GetUser");
+
+        let code = generator.generate(&json!({"id": 1}), &options).unwrap();
+        assert!(code.starts_with("openapi: \"3.1.0\"\ninfo:\n  title: User API\n  version: \"0.1.0\"\ncomponents:\n  schemas:\n    User:\n"));
+        assert!(code.contains(
+            "  responses:\n    GetUser:\n      description: GetUser response\n      content:\n        application/json:\n          schema:\n            $ref: \"#/components/schemas/User\"\n"
+        ));
+    }
+
+    #[test]
+    fn test_root_array_and_primitive() {
+        let generator = OpenApiGenerator::new();
+        let options = GenerationOptions::default()
+            .with_struct_name("Users")
+            .with_language_option("openapi_format", "json");
+
+        let code = generator.generate(&json!([{"id": 1}]), &options).unwrap();
+        let document: Value = serde_json::from_str(&code).unwrap();
+        assert_eq!(document["components"]["schemas"]["UsersItem"]["type"], "object");
+        assert_eq!(
+            document["components"]["schemas"]["Users"],
+            json!({"type": "array", "items": {"$ref": "#/components/schemas/UsersItem"}})
+        );
+        // The root is listed before the schemas it refers to
+        assert!(code.find("\"Users\":").unwrap() < code.find("\"UsersItem\":").unwrap());
+
+        let code = generator.generate(&json!("text"), &options).unwrap();
+        let document: Value = serde_json::from_str(&code).unwrap();
+        assert_eq!(document["components"]["schemas"]["Users"], json!({"type": "string"}));
+    }
+
+    #[test]
+    fn test_responses_from_directory() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("get-user.json"), r#"{"id": 1, "address": {"city": "Paris"}}"#).unwrap();
+        std::fs::write(dir.path().join("list_users.json"), r#"[{"id": 1}, {"id": 2}]"#).unwrap();
+        std::fs::write(dir.path().join("me.json"), r#"{"id": 7, "address": {"city": "Oslo"}}"#).unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "not a sample").unwrap();
+
+        let generator = OpenApiGenerator::new();
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_language_option("openapi_format", "json")
+            .with_language_option("openapi_responses", dir.path().to_str().unwrap());
+
+        let code = generator.generate(&json!({"id": 1, "address": {"city": "Rome"}}), &options).unwrap();
+        let document: Value = serde_json::from_str(&code).unwrap();
+        let schemas = &document["components"]["schemas"];
+        let responses = &document["components"]["responses"];
+
+        // Every .json file becomes a response named after it, in name order
+        let names: Vec<&String> = responses.as_object().unwrap().keys().collect();
+        assert_eq!(names, ["get-user", "list_users", "me"]);
+        let body = |name: &str| responses[name]["content"]["application/json"]["schema"].clone();
+
+        // Samples with the input's schema reuse its components
+        assert_eq!(body("get-user"), json!({"$ref": "#/components/schemas/User"}));
+        assert_eq!(body("me"), json!({"$ref": "#/components/schemas/User"}));
+        assert!(schemas.get("GetUser").is_none());
+
+        // Other samples get components named after the file, including array roots
+        assert_eq!(body("list_users"), json!({"$ref": "#/components/schemas/ListUsers"}));
+        assert_eq!(
+            schemas["ListUsers"],
+            json!({"type": "array", "items": {"$ref": "#/components/schemas/ListUsersItem"}})
+        );
+        assert_eq!(schemas["ListUsersItem"]["properties"]["id"], json!({"type": "integer"}));
+    }
+
+    #[test]
+    fn test_responses_directory_errors() {
+        let generator = OpenApiGenerator::new();
+        let dir = tempfile::TempDir::new().unwrap();
+        let options = GenerationOptions::default()
+            .with_language_option("openapi_responses", dir.path().to_str().unwrap());
+        assert!(generator.generate(&json!({"id": 1}), &options).unwrap_err().is_file_error());
+
+        std::fs::write(dir.path().join("broken.json"), "{").unwrap();
+        assert!(generator.generate(&json!({"id": 1}), &options).unwrap_err().is_json_error());
+
+        let options = GenerationOptions::default().with_language_option("openapi_responses", "/nonexistent/responses");
+        assert!(generator.generate(&json!({"id": 1}), &options).unwrap_err().is_file_error());
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = OpenApiGenerator::new();
+
+        let options = GenerationOptions::default().with_language_option("openapi_format", "toml");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("openapi_response", "Get User");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("openapi_response", "GetUser");
+        assert!(generator.validate_options(&options).is_ok());
+    }
+}
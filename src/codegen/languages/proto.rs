@@ -0,0 +1,838 @@
+//! # Protocol Buffers Schema Generator
+//!
+//! This module implements a generator for `proto3` schema files. Each JSON object
+//! becomes a message with numbered fields. Field numbers can be read from an
+//! existing `.proto` file so that regenerating a schema never renumbers fields
+//! that are already on the wire.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, generate_timestamp};
+use crate::error::{J2sError, Result};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+/// Language option selecting where nested messages are declared ("top_level" or "nested")
+const NESTING_OPTION: &str = "proto_nesting";
+
+/// Language option setting the package declaration of the generated file
+const PACKAGE_OPTION: &str = "package";
+
+/// Language option naming an existing .proto file to take field numbers from
+const EXISTING_PROTO_OPTION: &str = "existing_proto";
+
+/// Field numbers 19000 through 19999 are reserved for the protobuf implementation
+const IMPLEMENTATION_RESERVED: std::ops::RangeInclusive<u32> = 19000..=19999;
+
+/// The largest field number protobuf allows
+const MAX_FIELD_NUMBER: u32 = 536_870_911;
+
+/// Where messages for nested objects are declared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProtoNesting {
+    /// Every message is declared at the top level of the file
+    TopLevel,
+    /// Messages are declared inside the message that references them
+    Nested,
+}
+
+impl ProtoNesting {
+    /// Read the nesting style from the generation options, defaulting to top-level messages
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        match options.get_language_option(NESTING_OPTION).map(String::as_str) {
+            None | Some("top_level") => Ok(Self::TopLevel),
+            Some("nested") => Ok(Self::Nested),
+            Some(other) => Err(J2sError::codegen_error(format!(
+                "Invalid proto nesting: '{other}'. Supported values: top_level, nested"
+            ))),
+        }
+    }
+}
+
+/// Field numbers and reservations of one message in an existing .proto file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct MessageNumbers {
+    /// Field names and their numbers, in declaration order
+    fields: Vec<(String, u32)>,
+    /// Reserved number ranges, inclusive at both ends
+    reserved_ranges: Vec<(u32, u32)>,
+    /// Reserved field names
+    reserved_names: Vec<String>,
+}
+
+impl MessageNumbers {
+    /// Check whether a number falls into one of the reserved ranges
+    fn reserved_range_containing(&self, number: u32) -> Option<(u32, u32)> {
+        self.reserved_ranges
+            .iter()
+            .copied()
+            .find(|(start, end)| (*start..=*end).contains(&number))
+    }
+}
+
+/// Protocol Buffers schema generator
+///
+/// This generator creates `proto3` message definitions from JSON data, including:
+/// - PascalCase message names and snake_case field names, with `json_name` where needed
+/// - `int64`, `double`, `bool` and `string` scalars and `google.protobuf.Value` for unknown values
+/// - `repeated` for arrays and `optional` for nullable fields
+/// - Stable field numbers, optionally continued from an existing .proto file
+pub struct ProtoGenerator;
+
+impl ProtoGenerator {
+    /// Create a new Protocol Buffers schema generator
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Map a FieldType to the appropriate protobuf type
+    fn map_field_type(&self, field_type: &FieldType) -> String {
+        match field_type {
+            FieldType::String => "string".to_string(),
+            FieldType::Integer => "int64".to_string(),
+            FieldType::Number => "double".to_string(),
+            FieldType::Boolean => "bool".to_string(),
+            FieldType::Custom(name) => NameConverter::convert_type_name(name, "proto"),
+            FieldType::Any => "google.protobuf.Value".to_string(),
+        }
+    }
+
+    /// Get the field label for a field, including its trailing space
+    fn field_label(&self, field: &FieldDefinition) -> &'static str {
+        if field.is_array {
+            "repeated "
+        } else if field.is_optional && !matches!(field.field_type, FieldType::Any) {
+            // google.protobuf.Value already represents null
+            "optional "
+        } else {
+            ""
+        }
+    }
+
+    /// Generate a single field declaration
+    fn generate_field(&self, field: &FieldDefinition, number: u32, indent: &str, include_comments: bool) -> String {
+        let mut result = String::new();
+
+        // Add field comments if present
+        if include_comments {
+            for comment in &field.comments {
+                result.push_str(&format!("{indent}// {comment}\n"));
+            }
+        }
+
+        let field_type = self.map_field_type(&field.field_type);
+        let label = self.field_label(field);
+        result.push_str(&format!("{indent}{label}{field_type} {} = {number}", field.code_name));
+
+        // The JSON mapping uses lowerCamelCase names unless json_name says otherwise
+        if default_json_name(&field.code_name) != field.json_name {
+            let json_name = Value::String(field.json_name.clone());
+            result.push_str(&format!(" [json_name = {json_name}]"));
+        }
+
+        result.push_str(";\n");
+        result
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "// Code generated by j2s (JSON to Struct) tool\n// Generated at: {timestamp}\n// DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+
+    /// Generate a message, declaring nested messages inside it when requested
+    fn generate_message(
+        &self,
+        struct_def: &StructDefinition,
+        existing: &HashMap<String, MessageNumbers>,
+        nesting: ProtoNesting,
+        indent: &str,
+        include_comments: bool,
+    ) -> Result<String> {
+        let message_name = NameConverter::convert_type_name(&struct_def.name, "proto");
+        let field_names: Vec<&str> = struct_def.fields.iter().map(|f| f.code_name.as_str()).collect();
+        let numbered = number_fields(&message_name, &field_names, existing.get(&message_name))?;
+        let inner_indent = format!("{indent}  ");
+
+        let mut result = String::new();
+
+        // Add message comments if enabled
+        if include_comments {
+            for comment in &struct_def.comments {
+                result.push_str(&format!("{indent}// {comment}\n"));
+            }
+        }
+
+        result.push_str(&format!("{indent}message {message_name} {{\n"));
+
+        let mut sections = Vec::new();
+
+        // Numbers and names of removed fields must never be reused
+        let mut reserved = String::new();
+        if !numbered.reserved_ranges.is_empty() {
+            let ranges: Vec<String> = numbered
+                .reserved_ranges
+                .iter()
+                .map(|&(start, end)| match end {
+                    end if end == start => start.to_string(),
+                    MAX_FIELD_NUMBER => format!("{start} to max"),
+                    end => format!("{start} to {end}"),
+                })
+                .collect();
+            reserved.push_str(&format!("{inner_indent}reserved {};\n", ranges.join(", ")));
+        }
+        if !numbered.reserved_names.is_empty() {
+            let names: Vec<String> = numbered
+                .reserved_names
+                .iter()
+                .map(|name| Value::String(name.clone()).to_string())
+                .collect();
+            reserved.push_str(&format!("{inner_indent}reserved {};\n", names.join(", ")));
+        }
+        if !reserved.is_empty() {
+            sections.push(reserved);
+        }
+
+        if nesting == ProtoNesting::Nested {
+            for nested in &struct_def.nested_structs {
+                sections.push(self.generate_message(nested, existing, nesting, &inner_indent, include_comments)?);
+            }
+        }
+
+        let mut fields = String::new();
+        for (field, number) in struct_def.fields.iter().zip(&numbered.numbers) {
+            fields.push_str(&self.generate_field(field, *number, &inner_indent, include_comments));
+        }
+        if !fields.is_empty() {
+            sections.push(fields);
+        }
+
+        result.push_str(&sections.join("\n"));
+        result.push_str(&format!("{indent}}}\n"));
+        Ok(result)
+    }
+}
+
+impl Default for ProtoGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for ProtoGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        let nesting = ProtoNesting::from_options(options)?;
+
+        // Determine message name
+        let struct_name = options.get_struct_name("GeneratedMessage");
+        let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "proto");
+
+        // Convert JSON to intermediate representation
+        let struct_def = ir.struct_for(IrSource::Document, "proto", &sanitized_struct_name)?;
+
+        // Read field numbers from an existing schema so they stay stable
+        let existing = match options.get_language_option(EXISTING_PROTO_OPTION) {
+            Some(path) => {
+                let content = std::fs::read_to_string(path).map_err(|e| {
+                    J2sError::file_error(format!("Failed to read existing proto file '{path}': {e}"))
+                })?;
+                parse_existing_numbers(&content)
+            }
+            None => HashMap::new(),
+        };
+
+        // Generate proto code
+        let mut result = String::new();
+
+        // Add file header comment if comments are enabled
+        if options.include_comments {
+            result.push_str(&self.generate_file_header());
+        }
+
+        result.push_str("syntax = \"proto3\";\n");
+
+        if let Some(package_name) = options.get_language_option(PACKAGE_OPTION) {
+            result.push_str(&format!("\npackage {package_name};\n"));
+        }
+
+        if uses_any(&struct_def) {
+            result.push_str("\nimport \"google/protobuf/struct.proto\";\n");
+        }
+
+        let messages = match nesting {
+            ProtoNesting::TopLevel => collect_messages(&struct_def),
+            ProtoNesting::Nested => vec![&struct_def],
+        };
+        for message in messages {
+            result.push('\n');
+            result.push_str(&self.generate_message(message, &existing, nesting, "", options.include_comments)?);
+        }
+
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "proto"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "Protocol Buffers"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        ProtoNesting::from_options(options)?;
+
+        if let Some(package_name) = options.get_language_option(PACKAGE_OPTION) {
+            let valid = package_name.split('.').all(|segment| {
+                segment
+                    .chars()
+                    .next()
+                    .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+                    && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            });
+            if !valid {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid proto package name: '{package_name}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Field numbers chosen for one generated message
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NumberedFields {
+    /// The number of each field, in field order
+    numbers: Vec<u32>,
+    /// Reserved number ranges to declare, inclusive at both ends
+    reserved_ranges: Vec<(u32, u32)>,
+    /// Reserved field names to declare
+    reserved_names: Vec<String>,
+}
+
+/// Assign field numbers to the fields of a message
+///
+/// Fields found in the existing message keep their numbers. New fields are
+/// numbered after the highest number the existing message used or reserved,
+/// skipping reserved ranges. Fields that disappeared have their number and name
+/// reserved so they are never reused with a different meaning.
+fn number_fields(message_name: &str, field_names: &[&str], existing: Option<&MessageNumbers>) -> Result<NumberedFields> {
+    let existing = existing.cloned().unwrap_or_default();
+    let existing_numbers: HashMap<&str, u32> = existing
+        .fields
+        .iter()
+        .map(|(name, number)| (name.as_str(), *number))
+        .collect();
+
+    let mut next = existing
+        .fields
+        .iter()
+        .map(|(_, number)| *number)
+        .chain(
+            existing
+                .reserved_ranges
+                .iter()
+                .map(|(_, end)| *end)
+                .filter(|end| *end < MAX_FIELD_NUMBER),
+        )
+        .max()
+        .unwrap_or(0)
+        + 1;
+
+    let mut numbers = Vec::with_capacity(field_names.len());
+    for name in field_names {
+        if let Some(number) = existing_numbers.get(name) {
+            numbers.push(*number);
+            continue;
+        }
+
+        // Skip numbers reserved by the existing message or by protobuf itself
+        loop {
+            if let Some((_, end)) = existing.reserved_range_containing(next) {
+                next = end.saturating_add(1);
+            } else if IMPLEMENTATION_RESERVED.contains(&next) {
+                next = IMPLEMENTATION_RESERVED.end() + 1;
+            } else {
+                break;
+            }
+        }
+        if next > MAX_FIELD_NUMBER {
+            return Err(J2sError::codegen_error(format!(
+                "No field numbers left for field '{name}' in message {message_name}"
+            )));
+        }
+        numbers.push(next);
+        next += 1;
+    }
+
+    let current: HashSet<&str> = field_names.iter().copied().collect();
+    let mut reserved_ranges = existing.reserved_ranges.clone();
+    let mut reserved_names = existing.reserved_names.clone();
+    for (name, number) in &existing.fields {
+        if current.contains(name.as_str()) {
+            continue;
+        }
+        if existing.reserved_range_containing(*number).is_none() {
+            reserved_ranges.push((*number, *number));
+        }
+        if !reserved_names.contains(name) {
+            reserved_names.push(name.clone());
+        }
+    }
+    reserved_ranges.sort_unstable();
+
+    Ok(NumberedFields {
+        numbers,
+        reserved_ranges,
+        reserved_names,
+    })
+}
+
+/// Read the field numbers of every message in an existing .proto file
+///
+/// This is not a full protobuf parser: it only tracks `message` blocks, their
+/// field declarations and `reserved` statements, which is all that is needed to
+/// keep field numbers stable. Messages are keyed by their simple name.
+fn parse_existing_numbers(content: &str) -> HashMap<String, MessageNumbers> {
+    /// A block opened by `{`
+    enum Block {
+        Message(String),
+        Oneof,
+        Other,
+    }
+
+    let mut messages: HashMap<String, MessageNumbers> = HashMap::new();
+    let mut stack: Vec<Block> = Vec::new();
+    let mut statement: Vec<String> = Vec::new();
+
+    for token in tokenize_proto(content) {
+        match token.as_str() {
+            "{" => {
+                let block = match statement.as_slice() {
+                    [keyword, name] if keyword == "message" => Block::Message(name.clone()),
+                    [keyword, ..] if keyword == "oneof" => Block::Oneof,
+                    _ => Block::Other,
+                };
+                stack.push(block);
+                statement.clear();
+            }
+            "}" => {
+                stack.pop();
+                statement.clear();
+            }
+            ";" => {
+                // Fields of a oneof belong to the enclosing message
+                let message = stack
+                    .iter()
+                    .rev()
+                    .find(|block| !matches!(block, Block::Oneof))
+                    .and_then(|block| match block {
+                        Block::Message(name) => Some(name.clone()),
+                        _ => None,
+                    });
+                if let Some(message) = message {
+                    let numbers = messages.entry(message).or_default();
+                    record_statement(&statement, numbers);
+                }
+                statement.clear();
+            }
+            _ => statement.push(token),
+        }
+    }
+
+    messages
+}
+
+/// Record a field declaration or `reserved` statement of a message
+fn record_statement(statement: &[String], numbers: &mut MessageNumbers) {
+    match statement.first().map(String::as_str) {
+        Some("reserved") => {
+            let mut tokens = statement[1..].iter().filter(|token| *token != ",").peekable();
+            while let Some(token) = tokens.next() {
+                if let Some(name) = token.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+                    numbers.reserved_names.push(name.to_string());
+                } else if let Ok(start) = token.parse::<u32>() {
+                    let mut end = start;
+                    if tokens.peek().is_some_and(|t| *t == "to") {
+                        tokens.next();
+                        end = match tokens.next().map(String::as_str) {
+                            Some("max") => MAX_FIELD_NUMBER,
+                            Some(value) => value.parse().unwrap_or(start),
+                            None => start,
+                        };
+                    }
+                    numbers.reserved_ranges.push((start, end));
+                }
+            }
+        }
+        Some("option" | "extensions" | "extend" | "syntax" | "package" | "import") | None => {}
+        Some(_) => {
+            // `[repeated|optional] type name = number [options]`
+            if let Some(position) = statement.iter().position(|token| token == "=")
+                && position > 0
+                && let Some(Ok(number)) = statement.get(position + 1).map(|t| t.parse::<u32>())
+            {
+                numbers.fields.push((statement[position - 1].clone(), number));
+            }
+        }
+    }
+}
+
+/// Split .proto source into identifiers, numbers, string literals and punctuation
+///
+/// Comments are skipped. String literals keep their surrounding double quotes.
+fn tokenize_proto(content: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '/' if chars.peek() == Some(&'/') => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            '"' | '\'' => {
+                let mut literal = String::new();
+                while let Some(next) = chars.next() {
+                    match next {
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                literal.push(escaped);
+                            }
+                        }
+                        next if next == c => break,
+                        next => literal.push(next),
+                    }
+                }
+                tokens.push(format!("\"{literal}\""));
+            }
+            c if c.is_ascii_alphanumeric() || c == '_' || c == '.' => {
+                let mut word = c.to_string();
+                while let Some(&next) = chars.peek() {
+                    if next.is_ascii_alphanumeric() || next == '_' || next == '.' {
+                        word.push(next);
+                        chars.next();
+                    } else {
+                        break;
+                    }
+                }
+                tokens.push(word);
+            }
+            c => tokens.push(c.to_string()),
+        }
+    }
+
+    tokens
+}
+
+/// Get the JSON name protobuf derives from a field name (lowerCamelCase)
+fn default_json_name(field_name: &str) -> String {
+    let mut result = String::new();
+    let mut capitalize_next = false;
+    for c in field_name.chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            result.extend(c.to_uppercase());
+            capitalize_next = false;
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// Check whether a message or any nested message has a field of unknown type
+fn uses_any(struct_def: &StructDefinition) -> bool {
+    struct_def
+        .fields
+        .iter()
+        .any(|field| matches!(field.field_type, FieldType::Any))
+        || struct_def.nested_structs.iter().any(uses_any)
+}
+
+/// Flatten a message and all of its nested messages, parents first
+fn collect_messages(struct_def: &StructDefinition) -> Vec<&StructDefinition> {
+    let mut messages = vec![struct_def];
+    for nested in &struct_def.nested_structs {
+        messages.extend(collect_messages(nested));
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Check that a .proto file's braces are balanced outside comments and strings
+    fn braces_balanced(content: &str) -> bool {
+        let mut depth: i64 = 0;
+        for token in tokenize_proto(content) {
+            match token.as_str() {
+                "{" => depth += 1,
+                "}" => {
+                    depth -= 1;
+                    if depth < 0 {
+                        return false;
+                    }
+                }
+                _ => {}
+            }
+        }
+        depth == 0
+    }
+
+    #[test]
+    fn test_proto_generator_creation() {
+        let generator = ProtoGenerator::new();
+        assert_eq!(generator.language_name(), "Protocol Buffers");
+        assert_eq!(generator.file_extension(), "proto");
+    }
+
+    #[test]
+    fn test_map_field_type() {
+        let generator = ProtoGenerator::new();
+
+        assert_eq!(generator.map_field_type(&FieldType::String), "string");
+        assert_eq!(generator.map_field_type(&FieldType::Integer), "int64");
+        assert_eq!(generator.map_field_type(&FieldType::Number), "double");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean), "bool");
+        assert_eq!(generator.map_field_type(&FieldType::Any), "google.protobuf.Value");
+        assert_eq!(
+            generator.map_field_type(&FieldType::Custom("user_profile".to_string())),
+            "UserProfile"
+        );
+    }
+
+    #[test]
+    fn test_generate_field_labels_and_json_name() {
+        let generator = ProtoGenerator::new();
+
+        let field = FieldDefinition::new("userId", "user_id", FieldType::Integer);
+        assert_eq!(generator.generate_field(&field, 1, "  ", false), "  int64 user_id = 1;\n");
+
+        let field = FieldDefinition::new("tags", "tags", FieldType::String).array(true).optional(true);
+        assert_eq!(generator.generate_field(&field, 2, "  ", false), "  repeated string tags = 2;\n");
+
+        let field = FieldDefinition::new("nickname", "nickname", FieldType::String).optional(true);
+        assert_eq!(generator.generate_field(&field, 3, "  ", false), "  optional string nickname = 3;\n");
+
+        let field = FieldDefinition::new("extra", "extra", FieldType::Any).optional(true);
+        assert_eq!(
+            generator.generate_field(&field, 4, "  ", false),
+            "  google.protobuf.Value extra = 4;\n"
+        );
+
+        let field = FieldDefinition::new("first-name", "first_name", FieldType::String);
+        assert_eq!(
+            generator.generate_field(&field, 5, "  ", false),
+            "  string first_name = 5 [json_name = \"first-name\"];\n"
+        );
+    }
+
+    #[test]
+    fn test_default_json_name() {
+        assert_eq!(default_json_name("user_id"), "userId");
+        assert_eq!(default_json_name("name"), "name");
+        assert_eq!(default_json_name("a_b_c"), "aBC");
+    }
+
+    #[test]
+    fn test_number_fields_without_existing() {
+        let numbered = number_fields("User", &["a", "b", "c"], None).unwrap();
+        assert_eq!(numbered.numbers, vec![1, 2, 3]);
+        assert!(numbered.reserved_ranges.is_empty());
+        assert!(numbered.reserved_names.is_empty());
+    }
+
+    #[test]
+    fn test_number_fields_with_existing() {
+        let existing = MessageNumbers {
+            fields: vec![("id".to_string(), 1), ("email".to_string(), 2), ("old".to_string(), 3)],
+            reserved_ranges: vec![(4, 6)],
+            reserved_names: vec!["legacy".to_string()],
+        };
+
+        let numbered = number_fields("User", &["age", "email", "id"], Some(&existing)).unwrap();
+        assert_eq!(numbered.numbers, vec![7, 2, 1]);
+        assert_eq!(numbered.reserved_ranges, vec![(3, 3), (4, 6)]);
+        assert_eq!(numbered.reserved_names, vec!["legacy".to_string(), "old".to_string()]);
+    }
+
+    #[test]
+    fn test_number_fields_skips_reserved_ranges() {
+        let existing = MessageNumbers {
+            fields: vec![("id".to_string(), 18999)],
+            reserved_ranges: vec![(20000, 20001)],
+            reserved_names: Vec::new(),
+        };
+
+        let numbered = number_fields("User", &["id", "name"], Some(&existing)).unwrap();
+        assert_eq!(numbered.numbers, vec![18999, 20002]);
+
+        let full = MessageNumbers {
+            fields: Vec::new(),
+            reserved_ranges: vec![(1, MAX_FIELD_NUMBER)],
+            reserved_names: Vec::new(),
+        };
+        assert!(number_fields("User", &["id"], Some(&full)).is_err());
+    }
+
+    #[test]
+    fn test_parse_existing_numbers() {
+        let content = r#"
+syntax = "proto3";
+
+package shop;
+
+// A customer order
+message Order {
+  reserved 4, 8 to 10;
+  reserved "legacy_id";
+
+  message Item {
+    string sku = 1;
+    int64 quantity = 2; /* inline comment = 99 */
+  }
+
+  enum Status {
+    STATUS_UNKNOWN = 0;
+  }
+
+  string id = 1;
+  repeated Item items = 3 [json_name = "lineItems"];
+  map<string, string> labels = 5;
+  oneof payment {
+    string card = 6;
+    string voucher = 7;
+  }
+}
+"#;
+
+        let messages = parse_existing_numbers(content);
+        let order = &messages["Order"];
+        assert_eq!(
+            order.fields,
+            vec![
+                ("id".to_string(), 1),
+                ("items".to_string(), 3),
+                ("labels".to_string(), 5),
+                ("card".to_string(), 6),
+                ("voucher".to_string(), 7),
+            ]
+        );
+        assert_eq!(order.reserved_ranges, vec![(4, 4), (8, 10)]);
+        assert_eq!(order.reserved_names, vec!["legacy_id".to_string()]);
+
+        let item = &messages["Item"];
+        assert_eq!(item.fields, vec![("sku".to_string(), 1), ("quantity".to_string(), 2)]);
+        assert!(!messages.contains_key("Status"));
+    }
+
+    #[test]
+    fn test_braces_balanced() {
+        assert!(braces_balanced("message A { message B { } }"));
+        assert!(braces_balanced("message A { string s = 1 [json_name = \"}\"]; } // }"));
+        assert!(!braces_balanced("message A {"));
+        assert!(!braces_balanced("} message A {"));
+    }
+
+    #[test]
+    fn test_generate_top_level_messages() {
+        let generator = ProtoGenerator::new();
+        let json_data = json!({
+            "user_id": 42,
+            "tags": ["a", "b"],
+            "nickname": null,
+            "score": 9.5,
+            "profile": {
+                "bio": "Developer",
+                "active": true
+            }
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("package", "example.users");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("syntax = \"proto3\";\n\npackage example.users;\n"));
+        assert!(code.contains("import \"google/protobuf/struct.proto\";\n"));
+        assert!(code.contains("message User {\n"));
+        assert!(code.contains("  google.protobuf.Value nickname = 1;\n"));
+        assert!(code.contains("  double score = 3;\n"));
+        assert!(code.contains("  repeated string tags = 4;\n"));
+        assert!(code.contains("  int64 user_id = 5 [json_name = \"user_id\"];\n"));
+
+        // The nested message is declared next to the root message
+        let profile_start = code.find("\nmessage Profile").unwrap();
+        assert!(code[profile_start..].contains("  bool active = 1;\n"));
+        assert!(code.contains(" profile = 2;\n"));
+        assert!(braces_balanced(&code));
+    }
+
+    #[test]
+    fn test_generate_nested_messages() {
+        let generator = ProtoGenerator::new();
+        let json_data = json!({"id": 1, "address": {"city": "Paris"}});
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("proto_nesting", "nested");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(!code.contains("import"));
+        assert!(code.contains("message User {\n  message Address"));
+        assert!(code.contains("    string city = 1;\n  }\n"));
+        assert_eq!(code.matches("\nmessage ").count(), 1);
+        assert!(braces_balanced(&code));
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = ProtoGenerator::new();
+
+        let options = GenerationOptions::default().with_language_option("proto_nesting", "inline");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("package", "my-package");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("package", "acme.v1");
+        assert!(generator.validate_options(&options).is_ok());
+    }
+
+    #[test]
+    fn test_missing_existing_proto_file() {
+        let generator = ProtoGenerator::new();
+        let options = GenerationOptions::default()
+            .with_language_option("existing_proto", "/nonexistent/schema.proto");
+
+        let result = generator.generate(&json!({"id": 1}), &options);
+        assert!(result.unwrap_err().is_file_error());
+    }
+}
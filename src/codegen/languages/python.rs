@@ -1,16 +1,58 @@
 //! # Python Language Code Generator
 //!
 //! This module implements the code generator for the Python programming language.
-//! It generates Python dataclasses with type annotations, appropriate type mappings,
-//! and follows Python naming conventions and best practices.
+//! It generates Python dataclasses (or pydantic v2 models, or TypedDicts) with type annotations,
+//! appropriate type mappings, and follows Python naming conventions and best practices.
 
 use crate::codegen::generator::{CodeGenerator, GenerationOptions};
-use crate::codegen::types::{FieldDefinition, FieldType, StructDefinition};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
 use crate::codegen::utils::NameConverter;
-use crate::error::Result;
+use crate::error::{J2sError, Result};
 use serde_json::Value;
 use std::collections::HashSet;
 
+/// Language option selecting the generated class style
+const STYLE_OPTION: &str = "python_style";
+
+/// Language option setting pydantic's handling of unknown keys ("ignore", "forbid" or "allow")
+const EXTRA_OPTION: &str = "pydantic_extra";
+
+/// Language option choosing `total=False` TypedDicts with `Required[...]` fields
+const TOTAL_OPTION: &str = "typeddict_total";
+
+/// Python keywords that can never be used as identifiers, e.g. as TypedDict class keys
+const HARD_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+    "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+    "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+    "try", "while", "with", "yield",
+];
+
+/// The kind of Python class generated for each JSON object
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PythonStyle {
+    /// `@dataclass` classes from the standard library
+    Dataclass,
+    /// pydantic v2 `BaseModel` subclasses that validate and convert nested data
+    Pydantic,
+    /// `TypedDict` definitions describing plain dicts, keyed by the original JSON keys
+    TypedDict,
+}
+
+impl PythonStyle {
+    /// Read the style from the generation options, defaulting to dataclasses
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        match options.get_language_option(STYLE_OPTION).map(String::as_str) {
+            None | Some("dataclass") => Ok(Self::Dataclass),
+            Some("pydantic") => Ok(Self::Pydantic),
+            Some("typeddict") => Ok(Self::TypedDict),
+            Some(other) => Err(J2sError::codegen_error(format!(
+                "Invalid Python style: '{other}'. Supported styles: dataclass, pydantic, typeddict"
+            ))),
+        }
+    }
+}
+
 /// Python language code generator
 ///
 /// This generator creates Python dataclass definitions from JSON data, including:
@@ -202,15 +244,17 @@ impl PythonGenerator {
     }
 
     /// Generate file header with generation information and module docstring
-    fn generate_file_header(&self, struct_name: &str) -> String {
+    ///
+    /// `kind` names what the module contains, e.g. "dataclasses" or "pydantic models".
+    fn generate_file_header(&self, struct_name: &str, kind: &str) -> String {
         use crate::codegen::utils::generate_timestamp;
         
         let timestamp = generate_timestamp();
         format!(
             "\"\"\"
-Generated Python dataclasses from JSON data.
+Generated Python {kind} from JSON data.
 
-This module contains dataclass definitions automatically generated from JSON data
+This module contains {kind} automatically generated from JSON data
 using the j2s (JSON to Struct) tool.
 
 Generated at: {timestamp}
@@ -287,6 +331,267 @@ DO NOT EDIT - This file was automatically generated.
         
         false
     }
+
+    /// Map a FieldType to a modern annotation using builtin generics and `T | None`
+    ///
+    /// Used by the pydantic and TypedDict styles, which target Python 3.10 and later.
+    fn map_annotation_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool) -> String {
+        let base_type = match field_type {
+            FieldType::String => "str",
+            FieldType::Integer => "int",
+            FieldType::Number => "float",
+            FieldType::Boolean => "bool",
+            FieldType::Custom(name) => name,
+            FieldType::Any => "Any",
+        };
+
+        let mut result = base_type.to_string();
+
+        // Handle arrays
+        if is_array {
+            result = format!("list[{result}]");
+        }
+
+        // Handle optional fields with a union; Any already admits None
+        if is_optional && (is_array || !matches!(field_type, FieldType::Any)) {
+            result = format!("{result} | None");
+        }
+
+        result
+    }
+
+    /// Get the attribute name of a pydantic field
+    ///
+    /// pydantic treats names with a leading underscore as private attributes rather
+    /// than fields, so such names get a public prefix.
+    fn pydantic_field_name(&self, field: &FieldDefinition) -> String {
+        let name = NameConverter::sanitize_identifier(&field.code_name, &self.keywords);
+        if name.starts_with('_') {
+            format!("field{name}")
+        } else {
+            name
+        }
+    }
+
+    /// Generate a pydantic model field with its alias and default
+    fn generate_pydantic_field(&self, field: &FieldDefinition) -> String {
+        let field_name = self.pydantic_field_name(field);
+        let field_type = self.map_annotation_type(&field.field_type, field.is_optional, field.is_array);
+
+        let mut result = String::new();
+
+        // Add inline comments if present
+        for comment in &field.comments {
+            result.push_str(&format!("    # {comment}\n"));
+        }
+
+        // Keep the original JSON key as the alias when the attribute name differs from it
+        let alias = (field.json_name != field_name).then(|| Value::String(field.json_name.clone()).to_string());
+        match (field.is_optional, alias) {
+            (false, None) => result.push_str(&format!("    {field_name}: {field_type}")),
+            (false, Some(alias)) => {
+                result.push_str(&format!("    {field_name}: {field_type} = Field(alias={alias})"))
+            }
+            (true, None) => result.push_str(&format!("    {field_name}: {field_type} = None")),
+            (true, Some(alias)) => result.push_str(&format!(
+                "    {field_name}: {field_type} = Field(default=None, alias={alias})"
+            )),
+        }
+
+        result
+    }
+
+    /// Check whether any field of a model needs an alias
+    fn has_aliases(&self, struct_def: &StructDefinition) -> bool {
+        struct_def
+            .fields
+            .iter()
+            .any(|field| field.json_name != self.pydantic_field_name(field))
+    }
+
+    /// Generate a complete pydantic model definition
+    fn generate_pydantic_model(&self, struct_def: &StructDefinition, extra: Option<&str>) -> String {
+        let class_name = NameConverter::to_pascal_case(&struct_def.name);
+        let sanitized_name = NameConverter::sanitize_identifier(&class_name, &self.keywords);
+
+        let mut result = format!("class {sanitized_name}(BaseModel):\n");
+
+        // Add class docstring, which also serves as the body of empty models
+        result.push_str("    \"\"\"");
+        if !struct_def.comments.is_empty() {
+            result.push_str(&struct_def.comments.join("\n    "));
+        } else {
+            result.push_str(&format!("{sanitized_name} model.\n\n    Auto-generated from JSON data.\n    "));
+        }
+        result.push_str("\"\"\"\n");
+
+        // Aliased fields can also be populated by attribute name
+        let mut settings = Vec::new();
+        if self.has_aliases(struct_def) {
+            settings.push("populate_by_name=True".to_string());
+        }
+        if let Some(extra) = extra {
+            settings.push(format!("extra=\"{extra}\""));
+        }
+        if !settings.is_empty() {
+            result.push_str(&format!("\n    model_config = ConfigDict({})\n", settings.join(", ")));
+        }
+
+        // Add fields
+        if !struct_def.fields.is_empty() {
+            result.push('\n');
+            for field in &struct_def.fields {
+                result.push_str(&self.generate_pydantic_field(field));
+                result.push('\n');
+            }
+        }
+
+        result
+    }
+
+    /// Generate the import statements for pydantic models
+    fn generate_pydantic_imports(&self, models: &[&StructDefinition], extra: Option<&str>) -> String {
+        let fields = || models.iter().flat_map(|model| model.fields.iter());
+
+        let mut result = String::new();
+        if fields().any(|field| matches!(field.field_type, FieldType::Any)) {
+            result.push_str("from typing import Any\n\n");
+        }
+
+        let mut pydantic_imports = vec!["BaseModel"];
+        if extra.is_some() || models.iter().any(|model| self.has_aliases(model)) {
+            pydantic_imports.push("ConfigDict");
+        }
+        if fields().any(|field| field.json_name != self.pydantic_field_name(field)) {
+            pydantic_imports.push("Field");
+        }
+        result.push_str(&format!("from pydantic import {}\n\n\n", pydantic_imports.join(", ")));
+        result
+    }
+
+    /// Generate a module of pydantic models, nested models first
+    fn generate_pydantic_module(&self, struct_def: &StructDefinition, options: &GenerationOptions) -> String {
+        let extra = options.get_language_option(EXTRA_OPTION).map(String::as_str);
+        let models = collect_classes(struct_def);
+
+        let mut result = self.generate_pydantic_imports(&models, extra);
+        let definitions: Vec<String> = models
+            .iter()
+            .map(|model| self.generate_pydantic_model(model, extra))
+            .collect();
+        result.push_str(&definitions.join("\n\n"));
+        result
+    }
+
+    /// Generate a TypedDict definition
+    ///
+    /// Class syntax is used when every key is a valid identifier; otherwise the
+    /// functional syntax keeps the JSON keys exactly as they are.
+    fn generate_typed_dict(&self, struct_def: &StructDefinition, total: bool) -> String {
+        let class_name = NameConverter::to_pascal_case(&struct_def.name);
+        let sanitized_name = NameConverter::sanitize_identifier(&class_name, &self.keywords);
+
+        // In a total TypedDict optional keys are marked, otherwise required ones are
+        let annotation = |field: &FieldDefinition| {
+            let field_type = self.map_annotation_type(&field.field_type, field.is_optional, field.is_array);
+            match (total, field.is_optional) {
+                (true, true) => format!("NotRequired[{field_type}]"),
+                (false, false) => format!("Required[{field_type}]"),
+                _ => field_type,
+            }
+        };
+
+        let mut result = String::new();
+        if struct_def.fields.iter().all(|field| is_identifier(&field.json_name)) {
+            let total_argument = if total { "" } else { ", total=False" };
+            result.push_str(&format!("class {sanitized_name}(TypedDict{total_argument}):\n"));
+            result.push_str("    \"\"\"");
+            if !struct_def.comments.is_empty() {
+                result.push_str(&struct_def.comments.join("\n    "));
+            } else {
+                result.push_str(&format!("{sanitized_name} dictionary.\n\n    Auto-generated from JSON data.\n    "));
+            }
+            result.push_str("\"\"\"\n");
+
+            if !struct_def.fields.is_empty() {
+                result.push('\n');
+            }
+            for field in &struct_def.fields {
+                for comment in &field.comments {
+                    result.push_str(&format!("    # {comment}\n"));
+                }
+                result.push_str(&format!("    {}: {}\n", field.json_name, annotation(field)));
+            }
+        } else {
+            for comment in &struct_def.comments {
+                result.push_str(&format!("# {comment}\n"));
+            }
+            result.push_str(&format!("{sanitized_name} = TypedDict(\n    \"{sanitized_name}\",\n    {{\n"));
+            for field in &struct_def.fields {
+                for comment in &field.comments {
+                    result.push_str(&format!("        # {comment}\n"));
+                }
+                let key = Value::String(field.json_name.clone()).to_string();
+                result.push_str(&format!("        {key}: {},\n", annotation(field)));
+            }
+            result.push_str("    },\n");
+            if !total {
+                result.push_str("    total=False,\n");
+            }
+            result.push_str(")\n");
+        }
+
+        result
+    }
+
+    /// Generate a module of TypedDict definitions, nested ones first
+    fn generate_typed_dict_module(&self, struct_def: &StructDefinition, options: &GenerationOptions) -> String {
+        let total = options
+            .get_language_option(TOTAL_OPTION)
+            .is_none_or(|total| total != "false");
+        let classes = collect_classes(struct_def);
+        let fields = || classes.iter().flat_map(|class| class.fields.iter());
+
+        let mut typing_imports = Vec::new();
+        if fields().any(|field| matches!(field.field_type, FieldType::Any)) {
+            typing_imports.push("Any");
+        }
+        if total && fields().any(|field| field.is_optional) {
+            typing_imports.push("NotRequired");
+        }
+        if !total && fields().any(|field| !field.is_optional) {
+            typing_imports.push("Required");
+        }
+        typing_imports.push("TypedDict");
+
+        let mut result = format!("from typing import {}\n\n\n", typing_imports.join(", "));
+        let definitions: Vec<String> = classes
+            .iter()
+            .map(|class| self.generate_typed_dict(class, total))
+            .collect();
+        result.push_str(&definitions.join("\n\n"));
+        result
+    }
+}
+
+/// Check whether a JSON key can be used as an attribute name in class syntax
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || first == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+        && !HARD_KEYWORDS.contains(&key)
+}
+
+/// Flatten a class and all of its nested classes, dependencies first
+fn collect_classes(struct_def: &StructDefinition) -> Vec<&StructDefinition> {
+    let mut classes = Vec::new();
+    for nested in &struct_def.nested_structs {
+        classes.extend(collect_classes(nested));
+    }
+    classes.push(struct_def);
+    classes
 }
 
 impl Default for PythonGenerator {
@@ -297,24 +602,41 @@ impl Default for PythonGenerator {
 
 impl CodeGenerator for PythonGenerator {
     fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
-        use crate::codegen::types::JsonToIrConverter;
-        
-        // Create converter for Python language
-        let mut converter = JsonToIrConverter::new("python");
-        
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
         // Determine struct name
         let struct_name = options.get_struct_name("GeneratedClass");
         let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "python");
         
         // Convert JSON to intermediate representation
-        let struct_def = converter.convert_to_struct(json_value, &sanitized_struct_name)?;
+        let struct_def = ir.struct_for(IrSource::Document, "python", &sanitized_struct_name)?;
         
         // Generate Python code
+        let style = PythonStyle::from_options(options)?;
         let mut result = String::new();
         
         // Add file header comment if comments are enabled
         if options.include_comments {
-            result.push_str(&self.generate_file_header(&sanitized_struct_name));
+            let kind = match style {
+                PythonStyle::Dataclass => "dataclasses",
+                PythonStyle::Pydantic => "pydantic models",
+                PythonStyle::TypedDict => "TypedDicts",
+            };
+            result.push_str(&self.generate_file_header(&sanitized_struct_name, kind));
+        }
+
+        match style {
+            PythonStyle::Pydantic => {
+                result.push_str(&self.generate_pydantic_module(&struct_def, options));
+                return Ok(result);
+            }
+            PythonStyle::TypedDict => {
+                result.push_str(&self.generate_typed_dict_module(&struct_def, options));
+                return Ok(result);
+            }
+            PythonStyle::Dataclass => {}
         }
         
         // Generate imports
@@ -340,8 +662,24 @@ impl CodeGenerator for PythonGenerator {
         "Python"
     }
 
-    fn validate_options(&self, _options: &GenerationOptions) -> Result<()> {
-        // Basic validation - can be extended later
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        PythonStyle::from_options(options)?;
+
+        if let Some(extra) = options.get_language_option(EXTRA_OPTION)
+            && !matches!(extra.as_str(), "ignore" | "forbid" | "allow")
+        {
+            return Err(J2sError::codegen_error(format!(
+                "Invalid pydantic extra setting: '{extra}'. Supported values: ignore, forbid, allow"
+            )));
+        }
+
+        if let Some(total) = options.get_language_option(TOTAL_OPTION)
+            && !matches!(total.as_str(), "true" | "false")
+        {
+            return Err(J2sError::codegen_error(format!(
+                "Invalid TypedDict total setting: '{total}'. Supported values: true, false"
+            )));
+        }
         Ok(())
     }
 }
@@ -670,7 +1008,7 @@ mod tests {
     #[test]
     fn test_file_header_generation() {
         let generator = PythonGenerator::new();
-        let header = generator.generate_file_header("TestClass");
+        let header = generator.generate_file_header("TestClass", "dataclasses");
         
         assert!(header.contains("Generated Python dataclasses from JSON data"));
         assert!(header.contains("Main class: TestClass"));
@@ -748,4 +1086,161 @@ mod tests {
         // Print the generated code for manual inspection
         println!("Generated Python code:\n{}", code);
     }
+    #[test]
+    fn test_map_annotation_type() {
+        let generator = PythonGenerator::new();
+
+        assert_eq!(generator.map_annotation_type(&FieldType::String, false, false), "str");
+        assert_eq!(generator.map_annotation_type(&FieldType::Integer, true, false), "int | None");
+        assert_eq!(generator.map_annotation_type(&FieldType::Number, false, true), "list[float]");
+        assert_eq!(generator.map_annotation_type(&FieldType::Boolean, true, true), "list[bool] | None");
+        assert_eq!(generator.map_annotation_type(&FieldType::Any, true, false), "Any");
+    }
+
+    #[test]
+    fn test_generate_pydantic_field() {
+        let generator = PythonGenerator::new();
+
+        let field = FieldDefinition::new("name", "name", FieldType::String);
+        assert_eq!(generator.generate_pydantic_field(&field), "    name: str");
+
+        let field = FieldDefinition::new("email", "email", FieldType::String).optional(true);
+        assert_eq!(generator.generate_pydantic_field(&field), "    email: str | None = None");
+
+        let field = FieldDefinition::new("first-name", "first_name", FieldType::String);
+        assert_eq!(
+            generator.generate_pydantic_field(&field),
+            "    first_name: str = Field(alias=\"first-name\")"
+        );
+
+        let field = FieldDefinition::new("class", "class", FieldType::String).optional(true);
+        assert_eq!(
+            generator.generate_pydantic_field(&field),
+            "    class_: str | None = Field(default=None, alias=\"class\")"
+        );
+
+        // Leading underscores would turn the field into a private attribute
+        let field = FieldDefinition::new("_id", "_id", FieldType::Integer);
+        assert_eq!(
+            generator.generate_pydantic_field(&field),
+            "    field_id: int = Field(alias=\"_id\")"
+        );
+    }
+
+    #[test]
+    fn test_generate_pydantic_models() {
+        use serde_json::json;
+
+        let generator = PythonGenerator::new();
+        let json_data = json!({
+            "userId": 42,
+            "nickname": null,
+            "tags": ["python"],
+            "profile": {
+                "bio": "Developer",
+                "location": {
+                    "city": "Paris"
+                }
+            }
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("python_style", "pydantic")
+            .with_language_option("pydantic_extra", "forbid");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("from typing import Any\n\nfrom pydantic import BaseModel, ConfigDict, Field\n\n\n"));
+        assert!(code.contains("class User(BaseModel):\n"));
+        assert!(code.contains("    model_config = ConfigDict(populate_by_name=True, extra=\"forbid\")\n"));
+        assert!(code.contains("    user_id: int = Field(alias=\"userId\")\n"));
+        assert!(code.contains("    nickname: Any = None\n"));
+        assert!(code.contains("    tags: list[str]\n"));
+        assert!(!code.contains("@dataclass"));
+
+        // Deeply nested models are declared before the models referencing them
+        let location_pos = code.find("    city: str").unwrap();
+        let profile_pos = code.find("    bio: str").unwrap();
+        let user_pos = code.find("class User(BaseModel)").unwrap();
+        assert!(location_pos < profile_pos);
+        assert!(profile_pos < user_pos);
+    }
+
+    #[test]
+    fn test_validate_pydantic_options() {
+        let generator = PythonGenerator::new();
+
+        let options = GenerationOptions::default()
+            .with_language_option("python_style", "pydantic")
+            .with_language_option("pydantic_extra", "ignore");
+        assert!(generator.validate_options(&options).is_ok());
+
+        let options = GenerationOptions::default().with_language_option("python_style", "attrs");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("pydantic_extra", "strict");
+        assert!(generator.validate_options(&options).is_err());
+    }
+    #[test]
+    fn test_generate_typed_dict_class_syntax() {
+        let generator = PythonGenerator::new();
+        let struct_def = StructDefinition::new("User")
+            .add_field(FieldDefinition::new("userId", "user_id", FieldType::Integer))
+            .add_field(FieldDefinition::new("email", "email", FieldType::String).optional(true));
+
+        let total = generator.generate_typed_dict(&struct_def, true);
+        assert!(total.starts_with("class User(TypedDict):\n"));
+        assert!(total.contains("    userId: int\n"));
+        assert!(total.contains("    email: NotRequired[str | None]\n"));
+
+        let partial = generator.generate_typed_dict(&struct_def, false);
+        assert!(partial.starts_with("class User(TypedDict, total=False):\n"));
+        assert!(partial.contains("    userId: Required[int]\n"));
+        assert!(partial.contains("    email: str | None\n"));
+    }
+
+    #[test]
+    fn test_generate_typed_dict_functional_syntax() {
+        let generator = PythonGenerator::new();
+        let struct_def = StructDefinition::new("Headers")
+            .add_field(FieldDefinition::new("content-type", "content_type", FieldType::String))
+            .add_field(FieldDefinition::new("class", "class_", FieldType::String));
+
+        // Keys that are not identifiers are kept exactly
+        assert_eq!(
+            generator.generate_typed_dict(&struct_def, false),
+            "Headers = TypedDict(\n    \"Headers\",\n    {\n        \"content-type\": Required[str],\n        \"class\": Required[str],\n    },\n    total=False,\n)\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_typed_dict_module() {
+        use serde_json::json;
+
+        let generator = PythonGenerator::new();
+        let json_data = json!({
+            "id": 1,
+            "meta": null,
+            "profile": {
+                "display-name": "Alice"
+            }
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false)
+            .with_language_option("python_style", "typeddict");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("from typing import Any, NotRequired, TypedDict\n\n\n"));
+        assert!(code.contains("\"display-name\": str,"));
+        assert!(code.contains("class User(TypedDict):\n"));
+        assert!(code.contains("    id: int\n"));
+        assert!(code.contains("    meta: NotRequired[Any]\n"));
+        assert!(code.find(" = TypedDict(").unwrap() < code.find("class User").unwrap());
+
+        let options = options.with_language_option("typeddict_total", "maybe");
+        assert!(generator.validate_options(&options).is_err());
+    }
 }
\ No newline at end of file
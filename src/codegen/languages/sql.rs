@@ -0,0 +1,533 @@
+//! # SQL DDL Generator
+//!
+//! This module implements a generator for `CREATE TABLE` statements from JSON
+//! records. The input is an array of records (or a single record); each key
+//! becomes a column. Nested objects are stored either as JSON columns or as child
+//! tables with foreign keys back to their parent, and the column types follow the
+//! selected dialect (PostgreSQL or SQLite).
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, collect_structs, generate_timestamp};
+use crate::error::{J2sError, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Language option selecting the SQL dialect ("postgres" or "sqlite")
+const DIALECT_OPTION: &str = "sql_dialect";
+
+/// Language option selecting how nested objects are stored ("jsonb" or "table")
+const NESTED_OPTION: &str = "sql_nested";
+
+/// Words that must be quoted when used as table or column names
+///
+/// This is the union of the reserved words of PostgreSQL and SQLite.
+const SQL_KEYWORDS: &[&str] = &[
+    "abort", "action", "add", "after", "all", "alter", "analyse", "analyze", "and", "any",
+    "array", "as", "asc", "asymmetric", "attach", "autoincrement", "before", "begin", "between",
+    "both", "by", "cascade", "case", "cast", "check", "collate", "column", "commit", "conflict",
+    "constraint", "create", "cross", "current_catalog", "current_date", "current_role",
+    "current_time", "current_timestamp", "current_user", "database", "default", "deferrable",
+    "deferred", "delete", "desc", "detach", "distinct", "do", "drop", "each", "else", "end",
+    "escape", "except", "exclusive", "exists", "explain", "fail", "false", "fetch", "for",
+    "foreign", "from", "full", "glob", "grant", "group", "having", "if", "ignore", "immediate",
+    "in", "index", "indexed", "initially", "inner", "insert", "instead", "intersect", "into",
+    "is", "isnull", "join", "key", "lateral", "leading", "left", "like", "limit", "localtime",
+    "localtimestamp", "match", "natural", "no", "not", "notnull", "null", "of", "offset", "on",
+    "only", "or", "order", "outer", "placing", "plan", "pragma", "primary", "query", "raise",
+    "recursive", "references", "regexp", "reindex", "release", "rename", "replace", "restrict",
+    "returning", "right", "rollback", "row", "savepoint", "select", "session_user", "set",
+    "some", "symmetric", "table", "temp", "temporary", "then", "to", "trailing", "transaction",
+    "trigger", "true", "union", "unique", "update", "user", "using", "vacuum", "values",
+    "variadic", "view", "virtual", "when", "where", "window", "with",
+];
+
+/// The SQL dialect to generate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SqlDialect {
+    /// PostgreSQL, with `JSONB` and native array columns
+    Postgres,
+    /// SQLite, with JSON stored as `TEXT`
+    Sqlite,
+}
+
+impl SqlDialect {
+    /// Read the dialect from the generation options, defaulting to PostgreSQL
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        match options.get_language_option(DIALECT_OPTION).map(String::as_str) {
+            None | Some("postgres") => Ok(Self::Postgres),
+            Some("sqlite") => Ok(Self::Sqlite),
+            Some(other) => Err(J2sError::codegen_error(format!(
+                "Invalid SQL dialect: '{other}'. Supported dialects: postgres, sqlite"
+            ))),
+        }
+    }
+
+    /// Column type for a single scalar value
+    fn scalar_type(self, field_type: &FieldType) -> &'static str {
+        match (self, field_type) {
+            (Self::Postgres, FieldType::String) => "TEXT",
+            (Self::Postgres, FieldType::Integer) => "BIGINT",
+            (Self::Postgres, FieldType::Number) => "DOUBLE PRECISION",
+            (Self::Postgres, FieldType::Boolean) => "BOOLEAN",
+            (Self::Sqlite, FieldType::String) => "TEXT",
+            (Self::Sqlite, FieldType::Integer) => "INTEGER",
+            (Self::Sqlite, FieldType::Number) => "REAL",
+            (Self::Sqlite, FieldType::Boolean) => "INTEGER",
+            (_, FieldType::Custom(_) | FieldType::Any) => self.json_type(),
+        }
+    }
+
+    /// Column type for JSON documents
+    fn json_type(self) -> &'static str {
+        match self {
+            Self::Postgres => "JSONB",
+            Self::Sqlite => "TEXT",
+        }
+    }
+
+    /// Column definition for a generated primary key
+    fn surrogate_key(self) -> &'static str {
+        match self {
+            Self::Postgres => "BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY",
+            Self::Sqlite => "INTEGER PRIMARY KEY",
+        }
+    }
+
+    /// Column type of a generated primary key, as referenced by foreign keys
+    fn surrogate_key_type(self) -> &'static str {
+        match self {
+            Self::Postgres => "BIGINT",
+            Self::Sqlite => "INTEGER",
+        }
+    }
+}
+
+/// How nested objects are stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NestedStorage {
+    /// In a JSON column of the parent table
+    Json,
+    /// In a child table with a foreign key to the parent table
+    Table,
+}
+
+impl NestedStorage {
+    /// Read the nested storage from the generation options, defaulting to JSON columns
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        match options.get_language_option(NESTED_OPTION).map(String::as_str) {
+            None | Some("jsonb") => Ok(Self::Json),
+            Some("table") => Ok(Self::Table),
+            Some(other) => Err(J2sError::codegen_error(format!(
+                "Invalid SQL nested storage: '{other}'. Supported values: jsonb, table"
+            ))),
+        }
+    }
+}
+
+/// The parent of a child table
+struct ParentTable<'a> {
+    /// Name of the parent table
+    name: &'a str,
+    /// Primary key column of the parent table
+    key_column: &'a str,
+    /// Column type of the parent's primary key
+    key_type: &'a str,
+    /// Whether each parent row has at most one child row
+    one_to_one: bool,
+}
+
+/// Settings shared by all tables generated from one input
+struct TableContext<'a> {
+    /// Every struct of the input, indexed by name
+    structs: HashMap<&'a str, &'a StructDefinition>,
+    /// The SQL dialect to generate
+    dialect: SqlDialect,
+    /// How nested objects are stored
+    nested: NestedStorage,
+    /// Whether to include comments
+    include_comments: bool,
+}
+
+/// SQL DDL generator
+///
+/// This generator creates `CREATE TABLE` statements from JSON records, including:
+/// - snake_case table and column names, quoted when they are reserved words
+/// - `NOT NULL` on columns present and non-null in every record
+/// - An `id` column promoted to `PRIMARY KEY`
+/// - Nested objects as `JSONB`/`TEXT` columns or as child tables with foreign keys
+pub struct SqlGenerator;
+
+impl SqlGenerator {
+    /// Create a new SQL DDL generator
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Map a field to its column type
+    fn map_column_type(&self, field: &FieldDefinition, dialect: SqlDialect) -> String {
+        let element_type = dialect.scalar_type(&field.field_type);
+        if !field.is_array {
+            return element_type.to_string();
+        }
+
+        match (dialect, &field.field_type) {
+            // PostgreSQL has native arrays of scalars
+            (SqlDialect::Postgres, FieldType::String | FieldType::Integer | FieldType::Number | FieldType::Boolean) => {
+                format!("{element_type}[]")
+            }
+            _ => dialect.json_type().to_string(),
+        }
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "-- Code generated by j2s (JSON to Struct) tool\n-- Generated at: {timestamp}\n-- DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+
+    /// Generate the `CREATE TABLE` statement for a table and, in table mode, its child tables
+    fn generate_tables(
+        &self,
+        struct_def: &StructDefinition,
+        table_name: &str,
+        parent: Option<ParentTable<'_>>,
+        rows: &[&Value],
+        context: &TableContext<'_>,
+        statements: &mut Vec<String>,
+    ) {
+        let dialect = context.dialect;
+        let nested = context.nested;
+        let mut lines = Vec::new();
+        let mut children = Vec::new();
+
+        // A required scalar `id` column is the natural primary key
+        let natural_key = struct_def.fields.iter().find(|field| {
+            field.code_name == "id"
+                && !field.is_optional
+                && !field.is_array
+                && matches!(field.field_type, FieldType::Integer | FieldType::String)
+        });
+
+        // Child tables need a key to reference, so generate one if the records have none
+        let mut key_column = natural_key.map(|field| field.code_name.clone());
+        let mut key_type = natural_key.map(|field| dialect.scalar_type(&field.field_type).to_string());
+        if key_column.is_none() && nested == NestedStorage::Table {
+            let column = if struct_def.fields.iter().any(|field| field.code_name == "id") {
+                "row_id"
+            } else {
+                "id"
+            };
+            lines.push(format!("    {column} {}", dialect.surrogate_key()));
+            key_column = Some(column.to_string());
+            key_type = Some(dialect.surrogate_key_type().to_string());
+        }
+
+        if let Some(parent) = &parent {
+            let unique = if parent.one_to_one { " UNIQUE" } else { "" };
+            lines.push(format!(
+                "    {} {} NOT NULL{unique} REFERENCES {} ({}) ON DELETE CASCADE",
+                quote_identifier(&format!("{}_id", parent.name)),
+                parent.key_type,
+                quote_identifier(parent.name),
+                quote_identifier(parent.key_column)
+            ));
+        }
+
+        for field in &struct_def.fields {
+            if nested == NestedStorage::Table
+                && let FieldType::Custom(name) = &field.field_type
+                && let Some(child) = context.structs.get(name.as_str())
+            {
+                children.push((field, *child));
+                continue;
+            }
+
+            let mut line = String::new();
+            if context.include_comments {
+                for comment in &field.comments {
+                    line.push_str(&format!("    -- {comment}\n"));
+                }
+            }
+            line.push_str(&format!(
+                "    {} {}",
+                quote_identifier(&field.code_name),
+                self.map_column_type(field, dialect)
+            ));
+            if natural_key.is_some_and(|key| std::ptr::eq(key, field)) {
+                line.push_str(" PRIMARY KEY");
+            } else if !field.is_optional
+                && rows.iter().all(|row| row.get(&field.json_name).is_some_and(|value| !value.is_null()))
+            {
+                // A key missing from some rows makes its column nullable
+                line.push_str(" NOT NULL");
+            }
+            lines.push(line);
+        }
+
+        let mut statement = String::new();
+        if context.include_comments {
+            for comment in &struct_def.comments {
+                statement.push_str(&format!("-- {comment}\n"));
+            }
+        }
+        statement.push_str(&format!("CREATE TABLE {} (\n", quote_identifier(table_name)));
+        statement.push_str(&lines.join(",\n"));
+        statement.push_str("\n);\n");
+        statements.push(statement);
+
+        // Child tables are created after the table their foreign keys reference
+        if let (Some(key_column), Some(key_type)) = (&key_column, &key_type) {
+            for (field, child) in children {
+                let child_table = format!("{table_name}_{}", field.code_name);
+                let parent = ParentTable {
+                    name: table_name,
+                    key_column,
+                    key_type,
+                    one_to_one: !field.is_array,
+                };
+                let child_rows: Vec<&Value> = rows
+                    .iter()
+                    .filter_map(|row| row.get(&field.json_name))
+                    .flat_map(|value| match value {
+                        Value::Array(elements) => elements.iter().collect(),
+                        value => vec![value],
+                    })
+                    .filter(|value| value.is_object())
+                    .collect();
+                self.generate_tables(child, &child_table, Some(parent), &child_rows, context, statements);
+            }
+        }
+    }
+}
+
+impl Default for SqlGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for SqlGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        let json_value = ir.json_value();
+        let dialect = SqlDialect::from_options(options)?;
+        let nested = NestedStorage::from_options(options)?;
+
+        // Determine table name
+        let struct_name = options.get_struct_name("GeneratedTable");
+        let table_name = NameConverter::to_snake_case(&NameConverter::convert_type_name(&struct_name, "sql"));
+
+        let records: Vec<&Value> = match json_value {
+            Value::Array(records) => records.iter().collect(),
+            record @ Value::Object(_) => vec![record],
+            _ => Vec::new(),
+        };
+        if records.is_empty() || !records.iter().all(|record| record.is_object()) {
+            return Err(J2sError::codegen_error(
+                "SQL output requires a JSON object or a non-empty array of JSON objects",
+            ));
+        }
+
+        // Convert one merged record so that every record contributes to the column types
+        let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "sql");
+        let struct_def = ir.struct_for(IrSource::Merged, "sql", &sanitized_struct_name)?;
+
+        let mut structs = HashMap::new();
+        collect_structs(&struct_def, &mut structs);
+
+        // Generate DDL
+        let mut result = String::new();
+
+        // Add file header comment if comments are enabled
+        if options.include_comments {
+            result.push_str(&self.generate_file_header());
+        }
+
+        let context = TableContext {
+            structs,
+            dialect,
+            nested,
+            include_comments: options.include_comments,
+        };
+        let mut statements = Vec::new();
+        self.generate_tables(&struct_def, &table_name, None, &records, &context, &mut statements);
+        result.push_str(&statements.join("\n"));
+
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "sql"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "SQL"
+    }
+
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        SqlDialect::from_options(options)?;
+        NestedStorage::from_options(options)?;
+        Ok(())
+    }
+}
+
+/// Quote an identifier when it is a reserved word or not a plain lowercase name
+fn quote_identifier(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_plain = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_lowercase() || first == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if is_plain && !SQL_KEYWORDS.contains(&name) {
+        name.to_string()
+    } else {
+        format!("\"{}\"", name.replace('"', "\"\""))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_sql_generator_creation() {
+        let generator = SqlGenerator::new();
+        assert_eq!(generator.language_name(), "SQL");
+        assert_eq!(generator.file_extension(), "sql");
+    }
+
+    #[test]
+    fn test_map_column_type() {
+        let generator = SqlGenerator::new();
+        let field = |field_type: FieldType, is_array: bool| {
+            FieldDefinition::new("value", "value", field_type).array(is_array)
+        };
+
+        let postgres = SqlDialect::Postgres;
+        assert_eq!(generator.map_column_type(&field(FieldType::String, false), postgres), "TEXT");
+        assert_eq!(generator.map_column_type(&field(FieldType::Integer, false), postgres), "BIGINT");
+        assert_eq!(generator.map_column_type(&field(FieldType::Number, false), postgres), "DOUBLE PRECISION");
+        assert_eq!(generator.map_column_type(&field(FieldType::Boolean, false), postgres), "BOOLEAN");
+        assert_eq!(generator.map_column_type(&field(FieldType::Any, false), postgres), "JSONB");
+        assert_eq!(generator.map_column_type(&field(FieldType::Integer, true), postgres), "BIGINT[]");
+        assert_eq!(generator.map_column_type(&field(FieldType::Any, true), postgres), "JSONB");
+
+        let sqlite = SqlDialect::Sqlite;
+        assert_eq!(generator.map_column_type(&field(FieldType::Integer, false), sqlite), "INTEGER");
+        assert_eq!(generator.map_column_type(&field(FieldType::Number, false), sqlite), "REAL");
+        assert_eq!(generator.map_column_type(&field(FieldType::Boolean, false), sqlite), "INTEGER");
+        assert_eq!(generator.map_column_type(&field(FieldType::String, true), sqlite), "TEXT");
+        assert_eq!(
+            generator.map_column_type(&field(FieldType::Custom("Profile".to_string()), false), sqlite),
+            "TEXT"
+        );
+    }
+
+    #[test]
+    fn test_quote_identifier() {
+        assert_eq!(quote_identifier("user_id"), "user_id");
+        assert_eq!(quote_identifier("user"), "\"user\"");
+        assert_eq!(quote_identifier("order"), "\"order\"");
+        assert_eq!(quote_identifier("2fa"), "\"2fa\"");
+        assert_eq!(quote_identifier("say\"hi"), "\"say\"\"hi\"");
+    }
+
+    #[test]
+    fn test_generate_records_with_json_columns() {
+        let generator = SqlGenerator::new();
+        let json_data = json!([
+            {"id": 1, "email": "a@example.com", "nickname": "al", "tags": ["x"], "profile": {"bio": "Hi"}},
+            {"id": 2, "email": "b@example.com", "nickname": null, "tags": [], "profile": {"bio": "Yo"}},
+            {"id": 3, "email": "c@example.com", "tags": ["z"], "profile": {"bio": "Hey"}}
+        ]);
+
+        let options = GenerationOptions::default()
+            .with_struct_name("Customer")
+            .with_comments(false);
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("CREATE TABLE customer (\n"));
+        assert!(code.contains("    id BIGINT PRIMARY KEY,\n"));
+        assert!(code.contains("    email TEXT NOT NULL,\n"));
+        assert!(code.contains("    nickname TEXT,\n"));
+        assert!(code.contains("    profile JSONB NOT NULL,\n"));
+        assert!(code.contains("    tags TEXT[] NOT NULL\n"));
+        assert!(code.ends_with(");\n"));
+        assert_eq!(code.matches("CREATE TABLE").count(), 1);
+    }
+
+    #[test]
+    fn test_generate_child_tables() {
+        let generator = SqlGenerator::new();
+        let json_data = json!({
+            "name": "Order 1",
+            "shipping": {"city": "Paris"},
+            "items": [{"sku": "a", "quantity": 2}]
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("Order")
+            .with_comments(false)
+            .with_language_option("sql_nested", "table");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with(
+            "CREATE TABLE \"order\" (\n    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,\n    name TEXT NOT NULL\n);\n"
+        ));
+        assert!(code.contains("CREATE TABLE order_items (\n    id BIGINT GENERATED ALWAYS AS IDENTITY PRIMARY KEY,\n    order_id BIGINT NOT NULL REFERENCES \"order\" (id) ON DELETE CASCADE,\n"));
+        assert!(code.contains("    quantity BIGINT NOT NULL,\n"));
+        assert!(code.contains("CREATE TABLE order_shipping (\n"));
+        assert!(code.contains("    order_id BIGINT NOT NULL UNIQUE REFERENCES \"order\" (id) ON DELETE CASCADE,\n    city TEXT NOT NULL\n"));
+    }
+
+    #[test]
+    fn test_generate_sqlite_dialect() {
+        let generator = SqlGenerator::new();
+        let json_data = json!([{"id": "u1", "active": true, "address": {"zip": "75001"}}]);
+
+        let options = GenerationOptions::default()
+            .with_struct_name("Account")
+            .with_comments(false)
+            .with_language_option("sql_dialect", "sqlite")
+            .with_language_option("sql_nested", "table");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.contains("    active INTEGER NOT NULL,\n"));
+        assert!(code.contains("    id TEXT PRIMARY KEY\n"));
+        assert!(code.contains("    account_id TEXT NOT NULL UNIQUE REFERENCES account (id) ON DELETE CASCADE,\n"));
+        assert!(code.contains("CREATE TABLE account_address (\n    id INTEGER PRIMARY KEY,\n"));
+    }
+
+    #[test]
+    fn test_generate_rejects_non_records() {
+        let generator = SqlGenerator::new();
+        let options = GenerationOptions::default();
+
+        assert!(generator.generate(&json!([]), &options).is_err());
+        assert!(generator.generate(&json!([1, 2]), &options).is_err());
+        assert!(generator.generate(&json!("text"), &options).is_err());
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = SqlGenerator::new();
+
+        let options = GenerationOptions::default().with_language_option("sql_dialect", "mysql");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default().with_language_option("sql_nested", "inline");
+        assert!(generator.validate_options(&options).is_err());
+
+        let options = GenerationOptions::default()
+            .with_language_option("sql_dialect", "sqlite")
+            .with_language_option("sql_nested", "table");
+        assert!(generator.validate_options(&options).is_ok());
+    }
+}
@@ -0,0 +1,437 @@
+//! # Swift Language Code Generator
+//!
+//! This module implements the code generator for the Swift programming language.
+//! It generates `Codable` structs with `CodingKeys` for renamed JSON keys, optionals
+//! for nullable fields, and follows Swift naming conventions.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, escape_comment_string, flatten_structs, generate_timestamp};
+use crate::error::Result;
+use serde_json::Value;
+
+/// Name of the helper enum emitted for values whose type could not be inferred
+const JSON_VALUE_TYPE: &str = "JSONValue";
+
+/// Swift language code generator
+///
+/// This generator creates Swift struct definitions from JSON data, including:
+/// - Proper Swift naming conventions (PascalCase for types, camelCase for properties)
+/// - `Codable` conformance with a `CodingKeys` enum when JSON keys differ from property names
+/// - Optionals (`T?`) for nullable fields and `[T]` for arrays
+/// - Backtick-escaped identifiers for Swift keywords such as `default`
+pub struct SwiftGenerator;
+
+impl SwiftGenerator {
+    /// Create a new Swift code generator
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Get the Swift type name for a struct, used both to declare and to reference it
+    fn type_name(&self, name: &str) -> String {
+        NameConverter::sanitize_identifier_for_language(&NameConverter::to_pascal_case(name), "swift")
+    }
+
+    /// Map a FieldType to the appropriate Swift type string
+    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool) -> String {
+        let mut result = match field_type {
+            FieldType::String => "String".to_string(),
+            FieldType::Integer => "Int".to_string(),
+            FieldType::Number => "Double".to_string(),
+            FieldType::Boolean => "Bool".to_string(),
+            FieldType::Custom(name) => self.type_name(name),
+            // `Any` is not Codable, so unknown values decode into the JSONValue helper
+            FieldType::Any => JSON_VALUE_TYPE.to_string(),
+        };
+
+        // Handle arrays
+        if is_array {
+            result = format!("[{result}]");
+        }
+
+        // Handle optional fields
+        if is_optional {
+            result.push('?');
+        }
+
+        result
+    }
+
+    /// Get the Swift property name for a field, escaping keywords with backticks
+    fn property_name(&self, field: &FieldDefinition) -> String {
+        // The code_name may already be escaped by JsonToIrConverter
+        NameConverter::sanitize_identifier_for_language(field.code_name.trim_matches('`'), "swift")
+    }
+
+    /// Check whether a struct needs a CodingKeys enum to map its JSON keys
+    fn needs_coding_keys(&self, struct_def: &StructDefinition) -> bool {
+        struct_def
+            .fields
+            .iter()
+            .any(|field| field.json_name != self.property_name(field).trim_matches('`'))
+    }
+
+    /// Generate a Swift property declaration
+    fn generate_field(&self, field: &FieldDefinition) -> String {
+        let property_name = self.property_name(field);
+        let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
+
+        // Add comments if present
+        let mut result = String::new();
+        for comment in &field.comments {
+            let escaped_comment = escape_comment_string(comment);
+            result.push_str(&format!("    /// {escaped_comment}\n"));
+        }
+
+        result.push_str(&format!("    let {property_name}: {field_type}"));
+        result
+    }
+
+    /// Generate the CodingKeys enum listing every property and its JSON key
+    fn generate_coding_keys(&self, struct_def: &StructDefinition) -> String {
+        let mut result = String::from("    enum CodingKeys: String, CodingKey {\n");
+
+        for field in &struct_def.fields {
+            let property_name = self.property_name(field);
+            if field.json_name == property_name.trim_matches('`') {
+                result.push_str(&format!("        case {property_name}\n"));
+            } else {
+                result.push_str(&format!(
+                    "        case {property_name} = \"{}\"\n",
+                    escape_string_literal(&field.json_name)
+                ));
+            }
+        }
+
+        result.push_str("    }\n");
+        result
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "// Code generated by j2s (JSON to Struct) tool\n// Generated at: {timestamp}\n// DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+
+    /// Generate a complete Swift struct definition
+    fn generate_struct(&self, struct_def: &StructDefinition, include_comments: bool) -> String {
+        let sanitized_name = self.type_name(&struct_def.name);
+
+        let mut result = String::new();
+
+        // Add struct comments if enabled
+        if include_comments {
+            if !struct_def.comments.is_empty() {
+                for comment in &struct_def.comments {
+                    let escaped_comment = escape_comment_string(comment);
+                    result.push_str(&format!("/// {escaped_comment}\n"));
+                }
+            } else {
+                result.push_str(&format!(
+                    "/// {sanitized_name} represents the structure of the JSON data\n"
+                ));
+            }
+        }
+
+        result.push_str(&format!("struct {sanitized_name}: Codable {{\n"));
+
+        for field in &struct_def.fields {
+            result.push_str(&self.generate_field(field));
+            result.push('\n');
+        }
+
+        // Swift only synthesizes the key mapping it can derive from property names
+        if self.needs_coding_keys(struct_def) {
+            result.push('\n');
+            result.push_str(&self.generate_coding_keys(struct_def));
+        }
+
+        result.push_str("}\n");
+        result
+    }
+
+    /// Generate the JSONValue helper enum used for values of unknown type
+    fn generate_json_value_type(&self) -> String {
+        format!(
+            r#"/// A JSON value whose type could not be inferred from the input data
+enum {JSON_VALUE_TYPE}: Codable {{
+    case string(String)
+    case number(Double)
+    case bool(Bool)
+    case object([String: {JSON_VALUE_TYPE}])
+    case array([{JSON_VALUE_TYPE}])
+    case null
+
+    init(from decoder: Decoder) throws {{
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {{
+            self = .null
+        }} else if let value = try? container.decode(Bool.self) {{
+            self = .bool(value)
+        }} else if let value = try? container.decode(Double.self) {{
+            self = .number(value)
+        }} else if let value = try? container.decode(String.self) {{
+            self = .string(value)
+        }} else if let value = try? container.decode([{JSON_VALUE_TYPE}].self) {{
+            self = .array(value)
+        }} else {{
+            self = .object(try container.decode([String: {JSON_VALUE_TYPE}].self))
+        }}
+    }}
+
+    func encode(to encoder: Encoder) throws {{
+        var container = encoder.singleValueContainer()
+        switch self {{
+        case .string(let value): try container.encode(value)
+        case .number(let value): try container.encode(value)
+        case .bool(let value): try container.encode(value)
+        case .object(let value): try container.encode(value)
+        case .array(let value): try container.encode(value)
+        case .null: try container.encodeNil()
+        }}
+    }}
+}}
+"#
+        )
+    }
+}
+
+impl Default for SwiftGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for SwiftGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        // Determine struct name
+        let struct_name = options.get_struct_name("GeneratedStruct");
+        let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "swift");
+
+        // Convert JSON to intermediate representation
+        let struct_def = ir.struct_for(IrSource::Document, "swift", &sanitized_struct_name)?;
+
+        // Generate Swift code
+        let mut result = String::new();
+
+        // Add file header comment if comments are enabled
+        if options.include_comments {
+            result.push_str(&self.generate_file_header());
+        }
+
+        result.push_str("import Foundation\n\n");
+
+        // Generate nested structs first, at every depth, then the main struct
+        let structs = flatten_structs(&struct_def);
+        for nested_struct in &structs[..structs.len() - 1] {
+            result.push_str(&self.generate_struct(nested_struct, options.include_comments));
+            result.push('\n');
+        }
+        result.push_str(&self.generate_struct(&struct_def, options.include_comments));
+
+        // Add the helper type when some values could not be inferred
+        if uses_json_value(&struct_def) {
+            result.push('\n');
+            result.push_str(&self.generate_json_value_type());
+        }
+
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "swift"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "Swift"
+    }
+
+    fn validate_options(&self, _options: &GenerationOptions) -> Result<()> {
+        // Basic validation - can be extended later
+        Ok(())
+    }
+}
+
+/// Recursively check whether any field falls back to the JSONValue helper
+fn uses_json_value(struct_def: &StructDefinition) -> bool {
+    struct_def
+        .fields
+        .iter()
+        .any(|field| matches!(field.field_type, FieldType::Any))
+        || struct_def.nested_structs.iter().any(uses_json_value)
+}
+
+/// Escape a JSON key for use inside a Swift string literal
+fn escape_string_literal(input: &str) -> String {
+    input.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_swift_generator_creation() {
+        let generator = SwiftGenerator::new();
+        assert_eq!(generator.language_name(), "Swift");
+        assert_eq!(generator.file_extension(), "swift");
+    }
+
+    #[test]
+    fn test_map_field_type() {
+        let generator = SwiftGenerator::new();
+
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false), "String");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false), "Int");
+        assert_eq!(generator.map_field_type(&FieldType::Number, false, false), "Double");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false), "Bool");
+        assert_eq!(generator.map_field_type(&FieldType::Any, false, false), "JSONValue");
+
+        // Test optional and array types
+        assert_eq!(generator.map_field_type(&FieldType::String, true, false), "String?");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true), "[Int]");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, true), "[String]?");
+    }
+
+    #[test]
+    fn test_generate_field() {
+        let generator = SwiftGenerator::new();
+
+        let field = FieldDefinition::new("name", "name", FieldType::String)
+            .add_comment("The user's name");
+        let result = generator.generate_field(&field);
+        assert_eq!(result, "    /// The user's name\n    let name: String");
+
+        let field = FieldDefinition::new("email", "email", FieldType::String).optional(true);
+        assert_eq!(generator.generate_field(&field), "    let email: String?");
+    }
+
+    #[test]
+    fn test_keyword_escaping() {
+        let generator = SwiftGenerator::new();
+
+        let field = FieldDefinition::new("default", "default", FieldType::Boolean);
+        assert_eq!(generator.generate_field(&field), "    let `default`: Bool");
+
+        // Already escaped by the converter
+        let field = FieldDefinition::new("protocol", "`protocol`", FieldType::String);
+        assert_eq!(generator.generate_field(&field), "    let `protocol`: String");
+
+        // Escaped keywords still match their JSON key, so no CodingKeys are needed
+        let struct_def = StructDefinition::new("Settings").add_field(field);
+        assert!(!generator.needs_coding_keys(&struct_def));
+    }
+
+    #[test]
+    fn test_generate_struct_with_coding_keys() {
+        let generator = SwiftGenerator::new();
+
+        let struct_def = StructDefinition::new("User")
+            .add_field(FieldDefinition::new("user_id", "userId", FieldType::Integer))
+            .add_field(FieldDefinition::new("name", "name", FieldType::String))
+            .add_field(FieldDefinition::new("default", "`default`", FieldType::Boolean));
+
+        let result = generator.generate_struct(&struct_def, false);
+        assert!(result.starts_with("struct User: Codable {\n"));
+        assert!(result.contains("    enum CodingKeys: String, CodingKey {\n"));
+        assert!(result.contains("        case userId = \"user_id\"\n"));
+        assert!(result.contains("        case name\n"));
+        assert!(result.contains("        case `default`\n"));
+        assert!(result.ends_with("    }\n}\n"));
+    }
+
+    #[test]
+    fn test_generate_struct_without_coding_keys() {
+        let generator = SwiftGenerator::new();
+
+        let struct_def = StructDefinition::new("Point")
+            .add_field(FieldDefinition::new("x", "x", FieldType::Number))
+            .add_field(FieldDefinition::new("y", "y", FieldType::Number));
+
+        let result = generator.generate_struct(&struct_def, true);
+        assert_eq!(
+            result,
+            "/// Point represents the structure of the JSON data\nstruct Point: Codable {\n    let x: Double\n    let y: Double\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_simple_struct() {
+        let generator = SwiftGenerator::new();
+        let json_data = json!({
+            "user_id": 42,
+            "name": "John Doe",
+            "is_active": true,
+            "nickname": null,
+            "tags": ["swift", "ios"]
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false);
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("import Foundation\n\n"));
+        assert!(code.contains("struct User: Codable {\n"));
+        assert!(code.contains("    let userId: Int\n"));
+        assert!(code.contains("    let isActive: Bool\n"));
+        assert!(code.contains("    let nickname: JSONValue?\n"));
+        assert!(code.contains("    let tags: [String]\n"));
+        assert!(code.contains("        case isActive = \"is_active\"\n"));
+        assert!(code.contains("enum JSONValue: Codable {"));
+    }
+
+    #[test]
+    fn test_generate_nested_structs() {
+        let generator = SwiftGenerator::new();
+        let json_data = json!({
+            "profile": {
+                "bio": "Developer",
+                "default": true
+            }
+        });
+
+        let options = GenerationOptions::default().with_struct_name("Account");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("// Code generated by j2s"));
+        assert!(code.contains("    let `default`: Bool\n"));
+        assert!(code.contains("let profile: Profile"));
+        assert!(!code.contains("JSONValue"));
+        assert!(!code.contains("CodingKeys"));
+
+        // Nested structs are declared before the root struct
+        let profile_pos = code.find("struct Profile").unwrap();
+        let account_pos = code.find("struct Account").unwrap();
+        assert!(profile_pos < account_pos);
+    }
+
+    #[test]
+    fn test_nested_struct_references_match_declarations() {
+        let generator = SwiftGenerator::new();
+        let json_data = json!({"p": {"n": "x", "q": {"m": 1}}});
+
+        let code = generator.generate(&json_data, &GenerationOptions::default()).unwrap();
+        for key in ["p", "q"] {
+            let property = format!("let {key}: ");
+            let type_name = code.lines().find_map(|line| line.trim().strip_prefix(property.as_str())).unwrap();
+            assert!(code.contains(&format!("struct {type_name}: Codable")), "{code}");
+        }
+    }
+
+    #[test]
+    fn test_validate_options() {
+        let generator = SwiftGenerator::new();
+        let options = GenerationOptions::default();
+
+        assert!(generator.validate_options(&options).is_ok());
+    }
+}
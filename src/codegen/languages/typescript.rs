@@ -5,11 +5,133 @@
 //! and follows TypeScript naming conventions and best practices.
 
 use crate::codegen::generator::{CodeGenerator, GenerationOptions};
-use crate::codegen::types::{FieldDefinition, FieldType, StructDefinition};
-use crate::codegen::utils::{NameConverter, generate_timestamp, escape_comment_string};
-use crate::error::Result;
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, generate_timestamp, escape_comment_string, detect_enum_values, collect_structs, record_samples};
+use crate::error::{J2sError, Result};
 use serde_json::Value;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+
+/// Language option choosing the declaration keyword ("interface" or "type")
+const DECLARATION_OPTION: &str = "ts_declaration";
+
+/// Language option marking properties `readonly` and arrays `ReadonlyArray` ("true" or "false")
+const READONLY_OPTION: &str = "ts_readonly";
+
+/// Language option choosing how optional properties are declared ("both", "optional" or "nullable")
+const OPTIONAL_OPTION: &str = "ts_optional";
+
+/// Language option choosing the type of values of unknown type ("any" or "unknown")
+const ANY_OPTION: &str = "ts_any";
+
+/// Language option typing detected enums as string literal unions ("true" or "false")
+const ENUMS_OPTION: &str = "ts_enums";
+
+/// Language option emitting an `isX(v: unknown): v is X` type guard per declaration ("true" or "false")
+const GUARDS_OPTION: &str = "ts_guards";
+
+/// Largest number of distinct strings typed as a literal union
+const MAX_ENUM_VALUES: usize = 8;
+
+/// How optional properties are declared
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OptionalMode {
+    /// `name?: T | null`, accepting both a missing key and null
+    Both,
+    /// `name?: T`, as `exactOptionalPropertyTypes` expects for keys that may be left out
+    Optional,
+    /// `name: T | null`, for keys that are always present but may be null
+    Nullable,
+}
+
+/// Declaration style chosen through the language options
+#[derive(Debug, Clone)]
+struct TsStyle {
+    /// Whether declarations are exported
+    export: bool,
+    /// Whether objects are declared as `type X = {...}` rather than `interface X {...}`
+    type_alias: bool,
+    /// Whether properties are `readonly` and arrays `ReadonlyArray<T>`
+    readonly: bool,
+    /// How optional properties are declared
+    optional: OptionalMode,
+    /// Whether values of unknown type are `unknown` rather than `any`
+    unknown: bool,
+    /// Whether detected enums are typed as string literal unions
+    enums: bool,
+    /// Whether type guards are emitted after the declarations
+    guards: bool,
+}
+
+impl Default for TsStyle {
+    fn default() -> Self {
+        Self {
+            export: true,
+            type_alias: false,
+            readonly: false,
+            optional: OptionalMode::Both,
+            unknown: false,
+            enums: false,
+            guards: false,
+        }
+    }
+}
+
+impl TsStyle {
+    /// Read the declaration style from the language options
+    fn from_options(options: &GenerationOptions) -> Result<Self> {
+        let flag = |option: &str, default: bool| -> Result<bool> {
+            match options.get_language_option(option).map(String::as_str) {
+                None => Ok(default),
+                Some("true") => Ok(true),
+                Some("false") => Ok(false),
+                Some(other) => Err(J2sError::codegen_error(format!(
+                    "Invalid value for {option}: '{other}'. Supported values: true, false"
+                ))),
+            }
+        };
+
+        let type_alias = match options.get_language_option(DECLARATION_OPTION).map(String::as_str) {
+            None | Some("interface") => false,
+            Some("type") => true,
+            Some(other) => {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid TypeScript declaration: '{other}'. Supported declarations: interface, type"
+                )));
+            }
+        };
+
+        let optional = match options.get_language_option(OPTIONAL_OPTION).map(String::as_str) {
+            None | Some("both") => OptionalMode::Both,
+            Some("optional") => OptionalMode::Optional,
+            Some("nullable") => OptionalMode::Nullable,
+            Some(other) => {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid TypeScript optional mode: '{other}'. Supported modes: both, optional, nullable"
+                )));
+            }
+        };
+
+        let unknown = match options.get_language_option(ANY_OPTION).map(String::as_str) {
+            None | Some("any") => false,
+            Some("unknown") => true,
+            Some(other) => {
+                return Err(J2sError::codegen_error(format!(
+                    "Invalid TypeScript any type: '{other}'. Supported types: any, unknown"
+                )));
+            }
+        };
+
+        Ok(Self {
+            export: flag("export", true)?,
+            type_alias,
+            readonly: flag(READONLY_OPTION, false)?,
+            optional,
+            unknown,
+            enums: flag(ENUMS_OPTION, false)?,
+            guards: flag(GUARDS_OPTION, false)?,
+        })
+    }
+}
 
 /// TypeScript language code generator
 ///
@@ -49,34 +171,39 @@ impl TypeScriptGenerator {
     }
 
     /// Map a FieldType to the appropriate TypeScript type string
-    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool) -> String {
+    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool, style: &TsStyle) -> String {
         let base_type = match field_type {
             FieldType::String => "string",
             FieldType::Integer => "number",
             FieldType::Number => "number",
             FieldType::Boolean => "boolean",
             FieldType::Custom(name) => name,
-            FieldType::Any => {
-                if is_array {
-                    // For arrays with mixed types, use any as element type
-                    // Could be enhanced to use union types like (string | number | boolean)[]
-                    "any"
-                } else {
-                    // For single Any fields, use any
-                    "any"
-                }
-            }
+            // Mixed arrays use the same element type; could be enhanced to use
+            // union types like (string | number | boolean)[]
+            FieldType::Any if style.unknown => "unknown",
+            FieldType::Any => "any",
         };
 
-        let mut result = base_type.to_string();
+        self.wrap_type(base_type.to_string(), is_optional, is_array, style)
+    }
+
+    /// Wrap an element type into the array and null unions a property needs
+    fn wrap_type(&self, base_type: String, is_optional: bool, is_array: bool, style: &TsStyle) -> String {
+        let mut result = base_type;
 
-        // Handle arrays
+        // Handle arrays; unions need parentheses before `[]`
         if is_array {
-            result = format!("{}[]", result);
+            result = if style.readonly {
+                format!("ReadonlyArray<{}>", result)
+            } else if result.contains(' ') {
+                format!("({})[]", result)
+            } else {
+                format!("{}[]", result)
+            };
         }
 
         // Handle optional fields with union types
-        if is_optional {
+        if is_optional && style.optional != OptionalMode::Optional {
             result = format!("{} | null", result);
         }
 
@@ -84,14 +211,23 @@ impl TypeScriptGenerator {
     }
 
     /// Generate a TypeScript interface property declaration
-    fn generate_property(&self, field: &FieldDefinition) -> String {
+    ///
+    /// `enum_values` types a string field as the union of its detected values.
+    fn generate_property(&self, field: &FieldDefinition, style: &TsStyle, enum_values: Option<&[String]>) -> String {
         // The code_name is already converted to the proper case by JsonToIrConverter
         let sanitized_name = NameConverter::sanitize_identifier(&field.code_name, &self.keywords);
         
-        let field_type = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
+        let field_type = match enum_values {
+            Some(values) => {
+                let literals: Vec<String> = values.iter().map(|value| format!("\"{}\"", value)).collect();
+                self.wrap_type(literals.join(" | "), field.is_optional, field.is_array, style)
+            }
+            None => self.map_field_type(&field.field_type, field.is_optional, field.is_array, style),
+        };
         
         // Determine if property should be optional (using ? syntax)
-        let optional_marker = if field.is_optional { "?" } else { "" };
+        let optional_marker = if field.is_optional && style.optional != OptionalMode::Nullable { "?" } else { "" };
+        let readonly_keyword = if style.readonly { "readonly " } else { "" };
 
         // Add JSDoc comments if present
         let mut result = String::new();
@@ -104,7 +240,7 @@ impl TypeScriptGenerator {
             result.push_str("   */\n");
         }
 
-        result.push_str(&format!("  {}{}: {};", sanitized_name, optional_marker, field_type));
+        result.push_str(&format!("  {}{}{}: {};", readonly_keyword, sanitized_name, optional_marker, field_type));
         result
     }
 
@@ -117,10 +253,128 @@ impl TypeScriptGenerator {
         )
     }
 
-    /// Generate a complete TypeScript interface definition
-    fn generate_interface(&self, struct_def: &StructDefinition, export: bool) -> String {
-        let interface_name = NameConverter::to_pascal_case(&struct_def.name);
-        let sanitized_name = NameConverter::sanitize_identifier(&interface_name, &self.keywords);
+    /// Get the declared name of an interface
+    fn interface_name(&self, name: &str) -> String {
+        NameConverter::sanitize_identifier(&NameConverter::to_pascal_case(name), &self.keywords)
+    }
+
+    /// Build the check that a value matches a field's element type
+    ///
+    /// Objects are checked with their interface's guard when one is generated, listed
+    /// in `guarded`, and only for being an object otherwise. Returns None for values of
+    /// unknown type, which need no check.
+    fn value_check(
+        &self,
+        field_type: &FieldType,
+        value: &str,
+        enum_values: Option<&[String]>,
+        guarded: &HashSet<&str>,
+    ) -> Option<String> {
+        if let Some(values) = enum_values {
+            let comparisons: Vec<String> = values.iter().map(|v| format!("{} === \"{}\"", value, v)).collect();
+            return Some(if comparisons.len() > 1 {
+                format!("({})", comparisons.join(" || "))
+            } else {
+                comparisons.join("")
+            });
+        }
+
+        match field_type {
+            FieldType::String => Some(format!("typeof {} === \"string\"", value)),
+            FieldType::Integer => Some(format!("Number.isInteger({})", value)),
+            FieldType::Number => Some(format!("typeof {} === \"number\"", value)),
+            FieldType::Boolean => Some(format!("typeof {} === \"boolean\"", value)),
+            FieldType::Custom(name) if guarded.contains(name.as_str()) => {
+                Some(format!("is{}({})", self.interface_name(name), value))
+            }
+            FieldType::Custom(_) => Some(format!("(typeof {} === \"object\" && {} !== null)", value, value)),
+            FieldType::Any => None,
+        }
+    }
+
+    /// Build the check that a property matches its declared type and optionality
+    ///
+    /// The property is looked up by its key in the JSON data, not its TypeScript name.
+    fn property_check(
+        &self,
+        field: &FieldDefinition,
+        style: &TsStyle,
+        enum_values: Option<&[String]>,
+        guarded: &HashSet<&str>,
+    ) -> Option<String> {
+        let key = Value::String(field.json_name.clone()).to_string();
+        let value = format!("o[{}]", key);
+
+        let check = if field.is_array {
+            Some(match self.value_check(&field.field_type, "item", enum_values, guarded) {
+                Some(item_check) => format!("Array.isArray({value}) && {value}.every((item) => {item_check})"),
+                None => format!("Array.isArray({value})"),
+            })
+        } else {
+            self.value_check(&field.field_type, &value, enum_values, guarded)
+        };
+
+        // Keys of unknown type must still be present unless they may be left out
+        let presence = format!("{} in o", key);
+        if !field.is_optional {
+            return Some(check.unwrap_or(presence));
+        }
+
+        let absent = match style.optional {
+            OptionalMode::Both => format!("{value} === undefined || {value} === null"),
+            OptionalMode::Optional => format!("{value} === undefined"),
+            OptionalMode::Nullable => format!("{value} === null"),
+        };
+        match check {
+            Some(check) => Some(format!("({} || {})", absent, check)),
+            None if style.optional == OptionalMode::Nullable => Some(presence),
+            None => None,
+        }
+    }
+
+    /// Generate a type guard checking every property of an interface at runtime
+    ///
+    /// Nested interfaces listed in `guarded` are checked by calling their own guards.
+    fn generate_guard(
+        &self,
+        struct_def: &StructDefinition,
+        style: &TsStyle,
+        enums: &EnumValues,
+        guarded: &HashSet<&str>,
+    ) -> String {
+        let interface_name = self.interface_name(&struct_def.name);
+        let export_keyword = if style.export { "export " } else { "" };
+
+        let checks: Vec<String> = struct_def
+            .fields
+            .iter()
+            .filter_map(|field| {
+                let key = (struct_def.name.clone(), field.json_name.clone());
+                self.property_check(field, style, enums.get(&key).map(Vec::as_slice), guarded)
+            })
+            .collect();
+
+        let mut result = format!(
+            "{}function is{}(v: unknown): v is {} {{\n",
+            export_keyword, interface_name, interface_name
+        );
+        result.push_str("  if (typeof v !== \"object\" || v === null) {\n    return false;\n  }\n");
+        if checks.is_empty() {
+            result.push_str("  return true;\n");
+        } else {
+            result.push_str("  const o = v as Record<string, unknown>;\n");
+            result.push_str("  return (\n");
+            result.push_str(&format!("    {}\n", checks.join(" &&\n    ")));
+            result.push_str("  );\n");
+        }
+        result.push_str("}\n");
+
+        result
+    }
+
+    /// Generate a complete TypeScript interface or object type definition
+    fn generate_interface(&self, struct_def: &StructDefinition, style: &TsStyle, enums: &EnumValues) -> String {
+        let sanitized_name = self.interface_name(&struct_def.name);
 
         let mut result = String::new();
 
@@ -135,17 +389,23 @@ impl TypeScriptGenerator {
         }
 
         // Start interface definition
-        let export_keyword = if export { "export " } else { "" };
-        result.push_str(&format!("{}interface {} {{\n", export_keyword, sanitized_name));
+        let export_keyword = if style.export { "export " } else { "" };
+        if style.type_alias {
+            result.push_str(&format!("{}type {} = {{\n", export_keyword, sanitized_name));
+        } else {
+            result.push_str(&format!("{}interface {} {{\n", export_keyword, sanitized_name));
+        }
 
         // Add properties
         for field in &struct_def.fields {
-            result.push_str(&self.generate_property(field));
+            let key = (struct_def.name.clone(), field.json_name.clone());
+            let enum_values = enums.get(&key).map(Vec::as_slice);
+            result.push_str(&self.generate_property(field, style, enum_values));
             result.push('\n');
         }
 
         // Close interface definition
-        result.push_str("}\n");
+        result.push_str(if style.type_alias { "};\n" } else { "}\n" });
 
         result
     }
@@ -159,17 +419,37 @@ impl Default for TypeScriptGenerator {
 
 impl CodeGenerator for TypeScriptGenerator {
     fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
-        use crate::codegen::types::JsonToIrConverter;
-        
-        // Create converter for TypeScript language
-        let mut converter = JsonToIrConverter::new("typescript");
-        
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        let json_value = ir.json_value();
+
         // Determine interface name
         let struct_name = options.get_struct_name("GeneratedInterface");
         let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "typescript");
         
+        // An array of records is converted as one merged record
+        let samples = record_samples(json_value);
+
         // Convert JSON to intermediate representation
-        let struct_def = converter.convert_to_struct(json_value, &sanitized_struct_name)?;
+        let mut struct_def = ir.struct_for(IrSource::Records, "typescript", &sanitized_struct_name)?;
+
+        let style = TsStyle::from_options(options)?;
+
+        let mut structs = HashMap::new();
+        collect_structs(&struct_def, &mut structs);
+
+        // Merged records hide the keys some records leave out or set to null
+        let mut sparse = HashSet::new();
+        collect_sparse_fields(&struct_def, &samples, &structs, &mut sparse);
+
+        // Find the string fields whose samples repeat a few values
+        let mut enums = EnumValues::new();
+        if style.enums {
+            collect_enum_values(&struct_def, &samples, &structs, &mut enums);
+        }
+        mark_optional(&mut struct_def, &sparse);
         
         // Generate TypeScript code
         let mut result = String::new();
@@ -179,19 +459,30 @@ impl CodeGenerator for TypeScriptGenerator {
             result.push_str(&self.generate_file_header());
         }
         
-        // Check if interfaces should be exported
-        let export_interfaces = options.get_language_option("export")
-            .map(|v| v == "true")
-            .unwrap_or(true); // Default to true for TypeScript
-        
         // Generate nested interfaces first
         for nested_struct in &struct_def.nested_structs {
-            result.push_str(&self.generate_interface(nested_struct, export_interfaces));
+            result.push_str(&self.generate_interface(nested_struct, &style, &enums));
             result.push('\n');
         }
         
         // Generate main interface
-        result.push_str(&self.generate_interface(&struct_def, export_interfaces));
+        result.push_str(&self.generate_interface(&struct_def, &style, &enums));
+
+        // Generate type guards for the same interfaces, in the same order
+        if style.guards {
+            let guarded: HashSet<&str> = struct_def
+                .nested_structs
+                .iter()
+                .chain([&struct_def])
+                .map(|declared| declared.name.as_str())
+                .collect();
+            for nested_struct in &struct_def.nested_structs {
+                result.push('\n');
+                result.push_str(&self.generate_guard(nested_struct, &style, &enums, &guarded));
+            }
+            result.push('\n');
+            result.push_str(&self.generate_guard(&struct_def, &style, &enums, &guarded));
+        }
         
         Ok(result)
     }
@@ -204,12 +495,83 @@ impl CodeGenerator for TypeScriptGenerator {
         "TypeScript"
     }
 
-    fn validate_options(&self, _options: &GenerationOptions) -> Result<()> {
-        // Basic validation - can be extended later
+    fn validate_options(&self, options: &GenerationOptions) -> Result<()> {
+        TsStyle::from_options(options)?;
         Ok(())
     }
 }
 
+/// Detected enum values keyed by (struct name, JSON name)
+type EnumValues = HashMap<(String, String), Vec<String>>;
+
+/// Find the fields of a struct and its nested structs that some samples leave out or set to null
+fn collect_sparse_fields(
+    struct_def: &StructDefinition,
+    samples: &[&Value],
+    structs: &HashMap<&str, &StructDefinition>,
+    sparse: &mut HashSet<(String, String)>,
+) {
+    let objects: Vec<&Value> = samples.iter().copied().filter(|sample| sample.is_object()).collect();
+    for field in &struct_def.fields {
+        let values: Vec<&Value> = objects.iter().filter_map(|object| object.get(&field.json_name)).collect();
+        if values.len() < objects.len() || values.iter().any(|value| value.is_null()) {
+            sparse.insert((struct_def.name.clone(), field.json_name.clone()));
+        }
+
+        // Nested objects are checked against the values found at this field
+        if let Some(nested) = field.field_type.custom_type_name().and_then(|name| structs.get(name)) {
+            let elements: Vec<&Value> = if field.is_array {
+                values.iter().filter_map(|value| value.as_array()).flatten().collect()
+            } else {
+                values
+            };
+            collect_sparse_fields(nested, &elements, structs, sparse);
+        }
+    }
+}
+
+/// Mark the given (struct name, JSON name) fields optional throughout a struct tree
+fn mark_optional(struct_def: &mut StructDefinition, sparse: &HashSet<(String, String)>) {
+    for field in &mut struct_def.fields {
+        if sparse.contains(&(struct_def.name.clone(), field.json_name.clone())) {
+            field.is_optional = true;
+        }
+    }
+    for nested in &mut struct_def.nested_structs {
+        mark_optional(nested, sparse);
+    }
+}
+
+/// Detect the string fields of a struct and its nested structs that hold enum values
+fn collect_enum_values(
+    struct_def: &StructDefinition,
+    samples: &[&Value],
+    structs: &HashMap<&str, &StructDefinition>,
+    enums: &mut EnumValues,
+) {
+    let objects: Vec<&Value> = samples.iter().copied().filter(|sample| sample.is_object()).collect();
+    for field in &struct_def.fields {
+        let values: Vec<&Value> = objects.iter().filter_map(|object| object.get(&field.json_name)).collect();
+        let elements: Vec<&Value> = if field.is_array {
+            values.iter().filter_map(|value| value.as_array()).flatten().collect()
+        } else {
+            values
+        };
+
+        if field.field_type == FieldType::String {
+            let strings: Vec<&str> = elements.iter().filter_map(|value| value.as_str()).collect();
+            if let Some(values) = detect_enum_values(&strings, MAX_ENUM_VALUES) {
+                enums.insert((struct_def.name.clone(), field.json_name.clone()), values);
+            }
+        }
+
+        // Nested objects are checked against the values found at this field
+        if let Some(nested) = field.field_type.custom_type_name().and_then(|name| structs.get(name)) {
+            collect_enum_values(nested, &elements, structs, enums);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,23 +587,24 @@ mod tests {
     #[test]
     fn test_map_field_type() {
         let generator = TypeScriptGenerator::new();
+        let style = TsStyle::default();
         
-        assert_eq!(generator.map_field_type(&FieldType::String, false, false), "string");
-        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false), "number");
-        assert_eq!(generator.map_field_type(&FieldType::Number, false, false), "number");
-        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false), "boolean");
-        assert_eq!(generator.map_field_type(&FieldType::Any, false, false), "any");
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false, &style), "string");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false, &style), "number");
+        assert_eq!(generator.map_field_type(&FieldType::Number, false, false, &style), "number");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false, &style), "boolean");
+        assert_eq!(generator.map_field_type(&FieldType::Any, false, false, &style), "any");
         
         // Test optional types
-        assert_eq!(generator.map_field_type(&FieldType::String, true, false), "string | null");
-        assert_eq!(generator.map_field_type(&FieldType::Integer, true, false), "number | null");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, false, &style), "string | null");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, true, false, &style), "number | null");
         
         // Test array types
-        assert_eq!(generator.map_field_type(&FieldType::String, false, true), "string[]");
-        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true), "number[]");
+        assert_eq!(generator.map_field_type(&FieldType::String, false, true, &style), "string[]");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true, &style), "number[]");
         
         // Test optional array types
-        assert_eq!(generator.map_field_type(&FieldType::String, true, true), "string[] | null");
+        assert_eq!(generator.map_field_type(&FieldType::String, true, true, &style), "string[] | null");
     }
 
     #[test]
@@ -252,7 +615,7 @@ mod tests {
         let field = FieldDefinition::new("user_name", "userName", FieldType::String)
             .add_comment("The user's name");
         
-        let result = generator.generate_property(&field);
+        let result = generator.generate_property(&field, &TsStyle::default(), None);
         assert!(result.contains("userName"));
         assert!(result.contains("string"));
         assert!(result.contains("* The user's name"));
@@ -267,7 +630,7 @@ mod tests {
         let field = FieldDefinition::new("email", "email", FieldType::String)
             .optional(true);
         
-        let result = generator.generate_property(&field);
+        let result = generator.generate_property(&field, &TsStyle::default(), None);
         assert!(result.contains("email?"));
         assert!(result.contains("string | null"));
     }
@@ -280,7 +643,7 @@ mod tests {
         let field = FieldDefinition::new("tags", "tags", FieldType::String)
             .array(true);
         
-        let result = generator.generate_property(&field);
+        let result = generator.generate_property(&field, &TsStyle::default(), None);
         assert!(result.contains("tags"));
         assert!(result.contains("string[]"));
         assert!(!result.contains("?"));
@@ -299,7 +662,7 @@ mod tests {
             .add_field(field2)
             .add_comment("User represents a user in the system");
         
-        let result = generator.generate_interface(&struct_def, true);
+        let result = generator.generate_interface(&struct_def, &TsStyle::default(), &EnumValues::new());
         assert!(result.contains("export interface User {"));
         assert!(result.contains("id: number"));
         assert!(result.contains("name: string"));
@@ -314,7 +677,7 @@ mod tests {
         let field = FieldDefinition::new("id", "id", FieldType::Integer);
         let struct_def = StructDefinition::new("User").add_field(field);
         
-        let result = generator.generate_interface(&struct_def, false);
+        let result = generator.generate_interface(&struct_def, &TsStyle { export: false, ..TsStyle::default() }, &EnumValues::new());
         assert!(result.contains("interface User {"));
         assert!(!result.contains("export"));
     }
@@ -325,7 +688,7 @@ mod tests {
         
         // The code_name should already be converted and sanitized
         let field = FieldDefinition::new("type", "type_", FieldType::String);
-        let result = generator.generate_property(&field);
+        let result = generator.generate_property(&field, &TsStyle::default(), None);
         
         // Should be sanitized to avoid TypeScript keyword conflict
         assert!(result.contains("type_"));
@@ -590,4 +953,256 @@ mod tests {
         assert!(code.contains("numbers: number[]"));
         assert!(code.contains("strings: string[]"));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_optional_modes() {
+        let generator = TypeScriptGenerator::new();
+        let field = FieldDefinition::new("email", "email", FieldType::String).optional(true);
+
+        let optional = TsStyle { optional: OptionalMode::Optional, ..TsStyle::default() };
+        assert_eq!(generator.generate_property(&field, &optional, None), "  email?: string;");
+
+        let nullable = TsStyle { optional: OptionalMode::Nullable, ..TsStyle::default() };
+        assert_eq!(generator.generate_property(&field, &nullable, None), "  email: string | null;");
+
+        assert_eq!(generator.generate_property(&field, &TsStyle::default(), None), "  email?: string | null;");
+    }
+
+    #[test]
+    fn test_optional_fields_from_differing_records() {
+        use serde_json::json;
+
+        let generator = TypeScriptGenerator::new();
+        let json_data = json!([
+            {"id": 1, "displayName": "Ada", "note": null, "address": {"city": "Paris"}},
+            {"id": 2, "note": "vip", "address": {"city": null}}
+        ]);
+
+        let code = generator.generate(&json_data, &GenerationOptions::default().with_struct_name("User")).unwrap();
+        assert!(code.contains("  id: number;\n"));
+        assert!(code.contains("  displayName?: string | null;\n"));
+        assert!(code.contains("  note?: string | null;\n"));
+        assert!(code.contains("  city?: string | null;\n"));
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_language_option("ts_optional", "optional");
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.contains("  displayName?: string;\n"));
+        assert!(code.contains("  note?: string;\n"));
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_language_option("ts_optional", "nullable");
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.contains("  displayName: string | null;\n"));
+        assert!(code.contains("  note: string | null;\n"));
+        assert!(code.contains("  id: number;\n"));
+    }
+
+    #[test]
+    fn test_readonly_and_unknown() {
+        let generator = TypeScriptGenerator::new();
+        let style = TsStyle { readonly: true, unknown: true, ..TsStyle::default() };
+
+        let tags = FieldDefinition::new("tags", "tags", FieldType::String).array(true);
+        assert_eq!(generator.generate_property(&tags, &style, None), "  readonly tags: ReadonlyArray<string>;");
+
+        let extra = FieldDefinition::new("extra", "extra", FieldType::Any).optional(true);
+        assert_eq!(generator.generate_property(&extra, &style, None), "  readonly extra?: unknown | null;");
+    }
+
+    #[test]
+    fn test_enum_literal_unions() {
+        let generator = TypeScriptGenerator::new();
+        let values = vec!["admin".to_string(), "user".to_string()];
+
+        let role = FieldDefinition::new("role", "role", FieldType::String);
+        assert_eq!(
+            generator.generate_property(&role, &TsStyle::default(), Some(&values)),
+            "  role: \"admin\" | \"user\";"
+        );
+
+        let roles = FieldDefinition::new("roles", "roles", FieldType::String).array(true);
+        assert_eq!(
+            generator.generate_property(&roles, &TsStyle::default(), Some(&values)),
+            "  roles: (\"admin\" | \"user\")[];"
+        );
+    }
+
+    #[test]
+    fn test_generate_type_alias_with_enums() {
+        use serde_json::json;
+
+        let generator = TypeScriptGenerator::new();
+        let json_data = json!([
+            {"name": "Ada", "status": "active", "address": {"kind": "home"}},
+            {"name": "Bob", "status": "inactive", "address": {"kind": "work"}},
+            {"name": "Cy", "status": "active", "address": {"kind": "home"}},
+            {"name": "Di", "status": "inactive", "address": {"kind": "work"}}
+        ]);
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_language_option("ts_declaration", "type")
+            .with_language_option("ts_enums", "true");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.contains("export type User = {"));
+        assert_eq!(code.matches("export type ").count(), 2);
+        assert!(code.contains("status: \"active\" | \"inactive\";"));
+        assert!(code.contains("kind: \"home\" | \"work\";"));
+        // Names do not repeat, so they stay strings
+        assert!(code.contains("name: string;"));
+        assert!(code.contains("};\n"));
+        assert!(!code.contains("interface"));
+    }
+
+    #[test]
+    fn test_validate_invalid_options() {
+        let generator = TypeScriptGenerator::new();
+
+        for (option, value) in [
+            ("ts_declaration", "class"),
+            ("ts_optional", "maybe"),
+            ("ts_any", "never"),
+            ("ts_readonly", "yes"),
+            ("ts_enums", "1"),
+        ] {
+            let options = GenerationOptions::default().with_language_option(option, value);
+            assert!(generator.validate_options(&options).is_err(), "{option}={value} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_generate_guard() {
+        let generator = TypeScriptGenerator::new();
+        let struct_def = StructDefinition::new("User")
+            .add_field(FieldDefinition::new("id", "id", FieldType::Integer))
+            .add_field(FieldDefinition::new("tags", "tags", FieldType::String).array(true))
+            .add_field(FieldDefinition::new("address", "address", FieldType::Custom("Address".to_string())))
+            .add_field(FieldDefinition::new("email", "email", FieldType::String).optional(true))
+            .add_field(FieldDefinition::new("extra", "extra", FieldType::Any));
+
+        let guarded = HashSet::from(["User", "Address"]);
+        let result = generator.generate_guard(&struct_def, &TsStyle::default(), &EnumValues::new(), &guarded);
+        assert!(result.starts_with("export function isUser(v: unknown): v is User {\n"));
+        assert!(result.contains("  const o = v as Record<string, unknown>;\n"));
+        assert!(result.contains("    Number.isInteger(o[\"id\"]) &&\n"));
+        assert!(result.contains("    Array.isArray(o[\"tags\"]) && o[\"tags\"].every((item) => typeof item === \"string\") &&\n"));
+        assert!(result.contains("    isAddress(o[\"address\"]) &&\n"));
+        assert!(result.contains("    (o[\"email\"] === undefined || o[\"email\"] === null || typeof o[\"email\"] === \"string\") &&\n"));
+        assert!(result.contains("    \"extra\" in o\n  );\n}\n"));
+    }
+
+    #[test]
+    fn test_guard_follows_optional_mode_and_enums() {
+        let generator = TypeScriptGenerator::new();
+        let style = TsStyle { optional: OptionalMode::Optional, export: false, ..TsStyle::default() };
+        let struct_def = StructDefinition::new("Order")
+            .add_field(FieldDefinition::new("state", "state", FieldType::String))
+            .add_field(FieldDefinition::new("note", "note", FieldType::Any).optional(true));
+        let mut enums = EnumValues::new();
+        enums.insert(("Order".to_string(), "state".to_string()), vec!["closed".to_string(), "open".to_string()]);
+
+        let result = generator.generate_guard(&struct_def, &style, &enums, &HashSet::from(["Order"]));
+        assert!(result.starts_with("function isOrder(v: unknown): v is Order {"));
+        assert!(result.contains("    (o[\"state\"] === \"closed\" || o[\"state\"] === \"open\")\n"));
+        // An optional value of unknown type needs no check
+        assert!(!result.contains("note"));
+    }
+
+    #[test]
+    fn test_generate_with_guards() {
+        use serde_json::json;
+
+        let generator = TypeScriptGenerator::new();
+        let json_data = json!({"name": "John Doe", "profile": {"age": 30}});
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_language_option("ts_guards", "true");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.contains("export function isUser(v: unknown): v is User {"));
+        assert_eq!(code.matches("export function is").count(), 2);
+        // Guards follow the declarations they check
+        assert!(code.find("export function isUser").unwrap() > code.find("export interface User").unwrap());
+
+        let without = generator.generate(&json_data, &GenerationOptions::default().with_struct_name("User")).unwrap();
+        assert!(!without.contains("function"));
+    }
+
+    #[test]
+    fn test_guards_accept_every_record() {
+        use serde_json::json;
+
+        let generator = TypeScriptGenerator::new();
+        let json_data = json!([
+            {"id": 1, "displayName": "Ada", "note": null, "tags": ["a"], "address": {"city": "Paris"}},
+            {"id": 2, "note": "vip", "tags": [], "address": {"city": null}}
+        ]);
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_language_option("ts_guards", "true");
+        let code = generator.generate(&json_data, &options).unwrap();
+
+        // Present with a value in every record
+        assert!(code.contains("    Number.isInteger(o[\"id\"]) &&\n"));
+        assert!(code.contains("Array.isArray(o[\"tags\"]) && o[\"tags\"].every((item) => typeof item === \"string\")"));
+        assert!(code.lines().any(|line| line.starts_with("    is") && line.ends_with("(o[\"address\"]) &&")));
+        // Missing from the second record
+        assert!(code.contains("(o[\"displayName\"] === undefined || o[\"displayName\"] === null || typeof o[\"displayName\"] === \"string\")"));
+        // Null in one record
+        assert!(code.contains("(o[\"note\"] === undefined || o[\"note\"] === null || typeof o[\"note\"] === \"string\")"));
+        assert!(code.contains("(o[\"city\"] === undefined || o[\"city\"] === null || typeof o[\"city\"] === \"string\")"));
+    }
+
+    #[test]
+    fn test_guards_check_json_keys() {
+        use serde_json::json;
+
+        let generator = TypeScriptGenerator::new();
+        let json_data = json!({"user_name": "ada", "first-name": "Ada", "delete": true, "say \"hi\"": "x"});
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_language_option("ts_guards", "true");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.contains("typeof o[\"user_name\"] === \"string\""));
+        assert!(code.contains("typeof o[\"first-name\"] === \"string\""));
+        assert!(code.contains("typeof o[\"delete\"] === \"boolean\""));
+        assert!(code.contains("typeof o[\"say \\\"hi\\\"\"] === \"string\""));
+        assert!(!code.contains("o[\"userName\"]"));
+        assert!(!code.contains("o[\"delete_\"]"));
+    }
+
+    #[test]
+    fn test_guards_only_call_generated_guards() {
+        use serde_json::json;
+
+        let generator = TypeScriptGenerator::new();
+        let json_data = json!({"p": {"n": "x", "q": {"m": 1}}});
+
+        let options = GenerationOptions::default()
+            .with_struct_name("Root")
+            .with_language_option("ts_guards", "true");
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        let defined: Vec<&str> = code
+            .lines()
+            .filter_map(|line| line.strip_prefix("export function ")?.split('(').next())
+            .collect();
+        assert_eq!(defined.len(), 2);
+
+        // Every guard that is called is defined, and deeper objects get a structural check
+        for call in code.split(|c: char| !c.is_alphanumeric() && c != '_').filter(|word| word.starts_with("is") && word.len() > 2) {
+            if call.chars().nth(2).is_some_and(char::is_uppercase) {
+                assert!(defined.contains(&call), "{call} is not defined in:\n{code}");
+            }
+        }
+        assert!(code.contains("(typeof o[\"q\"] === \"object\" && o[\"q\"] !== null)"));
+    }
+}
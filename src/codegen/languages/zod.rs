@@ -0,0 +1,352 @@
+//! # Zod Schema Code Generator
+//!
+//! This module implements a generator for [zod](https://zod.dev) schemas, giving
+//! TypeScript code runtime validation of JSON data. Each object becomes a
+//! `z.object({...})` schema plus a `z.infer` type export, with nested schemas
+//! declared before the schemas that reference them.
+
+use crate::codegen::generator::{CodeGenerator, GenerationOptions};
+use crate::codegen::types::{FieldDefinition, FieldType, IrSource, SharedIr, StructDefinition};
+use crate::codegen::utils::{NameConverter, escape_comment_string, generate_timestamp};
+use crate::error::Result;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+/// Zod schema generator
+///
+/// This generator creates zod schema definitions from JSON data, including:
+/// - One exported `<Name>Schema` constant and `<Name>` type per object
+/// - Object keys kept exactly as they appear in the JSON, since zod validates raw data
+/// - `.nullable().optional()` for optional fields and `z.array(...)` for arrays
+/// - Dependency ordering, so every schema is declared before it is referenced
+pub struct ZodGenerator {
+    /// TypeScript reserved keywords that need to be avoided in generated identifiers
+    keywords: HashSet<String>,
+}
+
+impl ZodGenerator {
+    /// Create a new zod schema generator
+    pub fn new() -> Self {
+        Self {
+            keywords: NameConverter::get_reserved_keywords("typescript"),
+        }
+    }
+
+    /// Get the TypeScript type name for an object
+    fn type_name(&self, name: &str) -> String {
+        NameConverter::sanitize_identifier(&NameConverter::to_pascal_case(name), &self.keywords)
+    }
+
+    /// Get the name of the schema constant for an object
+    fn schema_name(&self, name: &str) -> String {
+        format!("{}Schema", self.type_name(name))
+    }
+
+    /// Map a FieldType to the appropriate zod schema expression
+    fn map_field_type(&self, field_type: &FieldType, is_optional: bool, is_array: bool) -> String {
+        let mut result = match field_type {
+            FieldType::String => "z.string()".to_string(),
+            FieldType::Integer => "z.number().int()".to_string(),
+            FieldType::Number => "z.number()".to_string(),
+            FieldType::Boolean => "z.boolean()".to_string(),
+            FieldType::Custom(name) => self.schema_name(name),
+            FieldType::Any => "z.unknown()".to_string(),
+        };
+
+        // Handle arrays
+        if is_array {
+            result = format!("z.array({result})");
+        }
+
+        // z.unknown() already accepts null and undefined
+        if is_optional && (is_array || !matches!(field_type, FieldType::Any)) {
+            result.push_str(".nullable().optional()");
+        }
+
+        result
+    }
+
+    /// Generate a single object property of a `z.object` schema
+    fn generate_property(&self, field: &FieldDefinition) -> String {
+        let mut result = String::new();
+
+        // Add JSDoc comments if present
+        if !field.comments.is_empty() {
+            result.push_str("  /**\n");
+            for comment in &field.comments {
+                let escaped_comment = escape_comment_string(comment);
+                result.push_str(&format!("   * {escaped_comment}\n"));
+            }
+            result.push_str("   */\n");
+        }
+
+        let schema = self.map_field_type(&field.field_type, field.is_optional, field.is_array);
+        result.push_str(&format!("  {}: {schema},", property_key(&field.json_name)));
+        result
+    }
+
+    /// Generate file header with generation information
+    fn generate_file_header(&self) -> String {
+        let timestamp = generate_timestamp();
+        format!(
+            "// Code generated by j2s (JSON to Struct) tool\n// Generated at: {timestamp}\n// DO NOT EDIT - This file was automatically generated\n\n"
+        )
+    }
+
+    /// Generate the schema constant and inferred type for one object
+    fn generate_schema(&self, struct_def: &StructDefinition, include_comments: bool) -> String {
+        let type_name = self.type_name(&struct_def.name);
+        let schema_name = self.schema_name(&struct_def.name);
+
+        let mut result = String::new();
+
+        // Add schema comments as JSDoc if enabled
+        if include_comments {
+            result.push_str("/**\n");
+            if struct_def.comments.is_empty() {
+                result.push_str(&format!(" * Runtime schema for {type_name}\n"));
+            } else {
+                for comment in &struct_def.comments {
+                    let escaped_comment = escape_comment_string(comment);
+                    result.push_str(&format!(" * {escaped_comment}\n"));
+                }
+            }
+            result.push_str(" */\n");
+        }
+
+        if struct_def.fields.is_empty() {
+            result.push_str(&format!("export const {schema_name} = z.object({{}});\n"));
+        } else {
+            result.push_str(&format!("export const {schema_name} = z.object({{\n"));
+            for field in &struct_def.fields {
+                result.push_str(&self.generate_property(field));
+                result.push('\n');
+            }
+            result.push_str("});\n");
+        }
+
+        result.push_str(&format!(
+            "export type {type_name} = z.infer<typeof {schema_name}>;\n"
+        ));
+        result
+    }
+}
+
+impl Default for ZodGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeGenerator for ZodGenerator {
+    fn generate(&self, json_value: &Value, options: &GenerationOptions) -> Result<String> {
+        self.generate_with_ir(&SharedIr::new(json_value, options.sampling.clone()), options)
+    }
+
+    fn generate_with_ir(&self, ir: &SharedIr, options: &GenerationOptions) -> Result<String> {
+        // Determine type name
+        let struct_name = options.get_struct_name("GeneratedSchema");
+        let sanitized_struct_name = NameConverter::convert_type_name(&struct_name, "typescript");
+
+        // Convert JSON to intermediate representation; zod schemas live in TypeScript
+        // code, so reuse its naming rules
+        let struct_def = ir.struct_for(IrSource::Document, "typescript", &sanitized_struct_name)?;
+
+        // Generate zod code
+        let mut result = String::new();
+
+        // Add file header comment if comments are enabled
+        if options.include_comments {
+            result.push_str(&self.generate_file_header());
+        }
+
+        result.push_str("import { z } from \"zod\";\n");
+
+        // Schemas are plain constants, so each must be declared before it is used
+        for schema in order_by_dependency(&struct_def) {
+            result.push('\n');
+            result.push_str(&self.generate_schema(schema, options.include_comments));
+        }
+
+        Ok(result)
+    }
+
+    fn file_extension(&self) -> &'static str {
+        "zod.ts"
+    }
+
+    fn language_name(&self) -> &'static str {
+        "Zod"
+    }
+
+    fn validate_options(&self, _options: &GenerationOptions) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Order an object and all of its nested objects so dependencies come first
+///
+/// Objects are visited depth-first along their `Custom` field references; an
+/// object is emitted after everything it references, and only once per name.
+fn order_by_dependency(root: &StructDefinition) -> Vec<&StructDefinition> {
+    fn collect<'a>(struct_def: &'a StructDefinition, by_name: &mut HashMap<&'a str, &'a StructDefinition>) {
+        by_name.entry(struct_def.name.as_str()).or_insert(struct_def);
+        for nested in &struct_def.nested_structs {
+            collect(nested, by_name);
+        }
+    }
+
+    fn visit<'a>(
+        struct_def: &'a StructDefinition,
+        by_name: &HashMap<&'a str, &'a StructDefinition>,
+        visited: &mut HashSet<&'a str>,
+        ordered: &mut Vec<&'a StructDefinition>,
+    ) {
+        if !visited.insert(struct_def.name.as_str()) {
+            return;
+        }
+        for field in &struct_def.fields {
+            if let FieldType::Custom(name) = &field.field_type
+                && let Some(dependency) = by_name.get(name.as_str())
+            {
+                visit(dependency, by_name, visited, ordered);
+            }
+        }
+        ordered.push(struct_def);
+    }
+
+    let mut by_name = HashMap::new();
+    collect(root, &mut by_name);
+
+    let mut visited = HashSet::new();
+    let mut ordered = Vec::new();
+    visit(root, &by_name, &mut visited, &mut ordered);
+    ordered
+}
+
+/// Format a JSON key as an object literal key, quoting it when it is not a valid identifier
+fn property_key(json_name: &str) -> String {
+    let mut chars = json_name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        json_name.to_string()
+    } else {
+        Value::String(json_name.to_string()).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_zod_generator_creation() {
+        let generator = ZodGenerator::new();
+        assert_eq!(generator.language_name(), "Zod");
+        assert_eq!(generator.file_extension(), "zod.ts");
+    }
+
+    #[test]
+    fn test_map_field_type() {
+        let generator = ZodGenerator::new();
+
+        assert_eq!(generator.map_field_type(&FieldType::String, false, false), "z.string()");
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, false), "z.number().int()");
+        assert_eq!(generator.map_field_type(&FieldType::Number, false, false), "z.number()");
+        assert_eq!(generator.map_field_type(&FieldType::Boolean, false, false), "z.boolean()");
+        assert_eq!(generator.map_field_type(&FieldType::Any, true, false), "z.unknown()");
+
+        // Test optional and array types
+        assert_eq!(
+            generator.map_field_type(&FieldType::String, true, false),
+            "z.string().nullable().optional()"
+        );
+        assert_eq!(generator.map_field_type(&FieldType::Integer, false, true), "z.array(z.number().int())");
+        assert_eq!(
+            generator.map_field_type(&FieldType::Any, true, true),
+            "z.array(z.unknown()).nullable().optional()"
+        );
+
+        let custom_type = FieldType::Custom("Profile".to_string());
+        assert_eq!(generator.map_field_type(&custom_type, false, false), "ProfileSchema");
+    }
+
+    #[test]
+    fn test_property_key_quoting() {
+        assert_eq!(property_key("user_id"), "user_id");
+        assert_eq!(property_key("$ref"), "$ref");
+        assert_eq!(property_key("first-name"), "\"first-name\"");
+        assert_eq!(property_key("1st"), "\"1st\"");
+        assert_eq!(property_key("say \"hi\""), "\"say \\\"hi\\\"\"");
+    }
+
+    #[test]
+    fn test_generate_schema() {
+        let generator = ZodGenerator::new();
+        let struct_def = StructDefinition::new("User")
+            .add_field(FieldDefinition::new("user_id", "userId", FieldType::Integer))
+            .add_field(FieldDefinition::new("email", "email", FieldType::String).optional(true));
+
+        assert_eq!(
+            generator.generate_schema(&struct_def, false),
+            "export const UserSchema = z.object({\n  user_id: z.number().int(),\n  email: z.string().nullable().optional(),\n});\n\
+             export type User = z.infer<typeof UserSchema>;\n"
+        );
+
+        let empty = generator.generate_schema(&StructDefinition::new("Empty"), false);
+        assert!(empty.starts_with("export const EmptySchema = z.object({});\n"));
+    }
+
+    #[test]
+    fn test_order_by_dependency() {
+        let geo = StructDefinition::new("Geo").add_field(FieldDefinition::new("lat", "lat", FieldType::Number));
+        let address = StructDefinition::new("Address")
+            .add_field(FieldDefinition::new("geo", "geo", FieldType::Custom("Geo".to_string())))
+            .add_nested_struct(geo);
+        let root = StructDefinition::new("User")
+            .add_field(FieldDefinition::new("address", "address", FieldType::Custom("Address".to_string())))
+            .add_field(FieldDefinition::new("billing", "billing", FieldType::Custom("Address".to_string())))
+            .add_nested_struct(address);
+
+        let names: Vec<&str> = order_by_dependency(&root).iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Geo", "Address", "User"]);
+    }
+
+    #[test]
+    fn test_generate_nested_schemas() {
+        let generator = ZodGenerator::new();
+        let json_data = json!({
+            "user_id": 42,
+            "tags": ["a", "b"],
+            "nickname": null,
+            "profile": {
+                "bio": "Developer",
+                "location": {
+                    "city": "Paris"
+                }
+            }
+        });
+
+        let options = GenerationOptions::default()
+            .with_struct_name("User")
+            .with_comments(false);
+
+        let code = generator.generate(&json_data, &options).unwrap();
+        assert!(code.starts_with("import { z } from \"zod\";\n"));
+        assert!(code.contains("  user_id: z.number().int(),\n"));
+        assert!(code.contains("  tags: z.array(z.string()),\n"));
+        assert!(code.contains("  nickname: z.unknown(),\n"));
+        assert!(code.ends_with("export type User = z.infer<typeof UserSchema>;\n"));
+
+        // Every schema is declared before the schema referencing it
+        let location_pos = code.find("  city: z.string(),").unwrap();
+        let profile_pos = code.find("  bio: z.string(),").unwrap();
+        let user_pos = code.find("export const UserSchema").unwrap();
+        assert!(location_pos < profile_pos);
+        assert!(profile_pos < user_pos);
+    }
+}
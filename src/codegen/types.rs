@@ -4,8 +4,13 @@
 //! generation process. These types provide a language-agnostic way to represent data
 //! structures that can then be translated into language-specific code.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock};
 use serde_json::Value;
+use crate::codegen::utils::{NameConverter, merge_values};
+use crate::error::Result;
+use crate::performance::{PARALLEL_ARRAY_THRESHOLD, SamplingStrategy};
+use rayon::prelude::*;
 
 /// Statistics about JSON structure complexity
 #[derive(Debug, Clone, Default)]
@@ -261,6 +266,12 @@ pub struct JsonToIrConverter {
     generated_names: std::collections::HashSet<String>,
     /// Track the current path for better naming
     current_path: Vec<String>,
+    /// Strategy used to select elements of large arrays during inference
+    sampling: SamplingStrategy,
+    /// Base and final name of every struct name allocated, recorded by parallel workers
+    name_log: Option<Vec<(String, String)>>,
+    /// Whether struct and field names are kept as found in the JSON, for `SharedIr`
+    neutral_names: bool,
 }
 
 impl JsonToIrConverter {
@@ -268,10 +279,13 @@ impl JsonToIrConverter {
     pub fn new(language: &str) -> Self {
         Self {
             type_mapper: TypeMapper::new(language),
-            max_depth: 20, // Increased default to handle deeper nesting
+            max_depth: DEFAULT_MAX_DEPTH, // Increased default to handle deeper nesting
             current_depth: 0,
             generated_names: std::collections::HashSet::new(),
             current_path: Vec::new(),
+            sampling: SamplingStrategy::default(),
+            name_log: None,
+            neutral_names: false,
         }
     }
 
@@ -283,6 +297,9 @@ impl JsonToIrConverter {
             current_depth: 0,
             generated_names: std::collections::HashSet::new(),
             current_path: Vec::new(),
+            sampling: SamplingStrategy::default(),
+            name_log: None,
+            neutral_names: false,
         }
     }
 
@@ -301,6 +318,16 @@ impl JsonToIrConverter {
         self.max_depth
     }
 
+    /// Set the strategy used to select elements of large arrays
+    pub fn set_sampling_strategy(&mut self, sampling: SamplingStrategy) {
+        self.sampling = sampling;
+    }
+
+    /// Get the strategy used to select elements of large arrays
+    pub fn sampling_strategy(&self) -> &SamplingStrategy {
+        &self.sampling
+    }
+
     /// Convert JSON value to StructDefinition
     pub fn convert_to_struct(&mut self, json_value: &Value, struct_name: &str) -> crate::error::Result<StructDefinition> {
         self.current_depth = 0;
@@ -309,6 +336,21 @@ impl JsonToIrConverter {
         self.convert_object_to_struct(json_value, struct_name)
     }
 
+    /// Convert a JSON value to a StructDefinition that keeps the names found in the JSON
+    ///
+    /// Struct names are allocated from the unconverted base names and every allocation
+    /// is returned in order, so `NeutralIr::localize` can derive the names any language
+    /// would have produced.
+    fn convert_to_neutral_struct(&mut self, json_value: &Value) -> crate::error::Result<NeutralIr> {
+        self.neutral_names = true;
+        self.name_log = Some(Vec::new());
+        let root = self.convert_to_struct(json_value, "")?;
+        Ok(NeutralIr {
+            root,
+            allocations: self.name_log.take().unwrap_or_default(),
+        })
+    }
+
     /// Convert a JSON object to a StructDefinition
     fn convert_object_to_struct(&mut self, json_value: &Value, struct_name: &str) -> crate::error::Result<StructDefinition> {
         if self.current_depth >= self.max_depth {
@@ -388,6 +430,15 @@ impl JsonToIrConverter {
             .optional(is_optional)
             .array(is_array);
 
+        // Record how the element type was inferred when only part of the array was inspected
+        if let Value::Array(arr) = value
+            && self.sampling.should_sample(arr.len())
+        {
+            field = field
+                .add_comment(self.sampling.describe_sample(arr.len()))
+                .add_metadata("sampling", self.sampling.to_string());
+        }
+
         // Add metadata for JSON serialization
         field = field.add_metadata("json_name".to_string(), field_name.to_string());
 
@@ -479,15 +530,18 @@ impl JsonToIrConverter {
             return Ok(FieldType::Any);
         }
 
-        // Analyze all elements to determine if we have mixed types
+        // Analyze the sampled elements to determine if we have mixed types
+        let indices = self.sampling.select_indices(arr.len());
+        let sample: Vec<&Value> = indices.iter().map(|&index| &arr[index]).collect();
+        let elements: Vec<(&Value, String)> = indices
+            .iter()
+            .map(|&index| (&arr[index], format!("{}_{}", field_name, index)))
+            .collect();
         let mut element_types = std::collections::HashMap::new();
         let mut has_objects = false;
         let mut has_primitives = false;
 
-        for (index, element) in arr.iter().enumerate() {
-            let element_field_name = format!("{}_{}", field_name, index);
-            let (element_type, _) = self.process_json_type_with_value(element, &element_field_name, nested_structs)?;
-            
+        for (element_type, _) in self.process_values(&elements, nested_structs)? {
             match &element_type {
                 FieldType::Custom(_) => has_objects = true,
                 _ => has_primitives = true,
@@ -505,17 +559,68 @@ impl JsonToIrConverter {
             Ok(FieldType::Any)
         } else if has_objects {
             // Multiple object types - try to find a common structure or use Any
-            self.analyze_mixed_object_types(arr, field_name, nested_structs)
+            self.analyze_mixed_object_types(&sample, field_name, nested_structs)
         } else {
             // Multiple primitive types - determine the most general type
             self.determine_common_primitive_type(&element_types)
         }
     }
 
+    /// Process a batch of values in order, spreading large batches across threads
+    ///
+    /// Each worker converts its values with its own set of struct names. The names are
+    /// then reserved again here in the original order and the results renamed to match,
+    /// so the output is identical to processing the values one after another.
+    fn process_values(
+        &mut self,
+        values: &[(&Value, String)],
+        nested_structs: &mut Vec<StructDefinition>,
+    ) -> crate::error::Result<Vec<(FieldType, bool)>> {
+        if values.len() < PARALLEL_ARRAY_THRESHOLD {
+            return values
+                .iter()
+                .map(|(value, name)| self.process_json_type_with_value(value, name, nested_structs))
+                .collect();
+        }
+
+        let worker = JsonToIrConverter {
+            generated_names: std::collections::HashSet::new(),
+            name_log: None,
+            ..self.clone()
+        };
+        let converted: Vec<_> = values
+            .par_iter()
+            .map_init(
+                || worker.clone(),
+                |worker, (value, name)| {
+                    worker.generated_names.clear();
+                    worker.name_log = Some(Vec::new());
+                    let mut structs = Vec::new();
+                    let result = worker.process_json_type_with_value(value, name, &mut structs);
+                    let names = worker.name_log.take().unwrap_or_default();
+                    result.map(|(field_type, is_array)| (field_type, is_array, structs, names))
+                },
+            )
+            .collect();
+
+        let mut results = Vec::with_capacity(converted.len());
+        for item in converted {
+            let (field_type, is_array, structs, names) = item?;
+            let renames: HashMap<String, String> = names
+                .into_iter()
+                .map(|(base_name, local_name)| (local_name, self.allocate_struct_name(&base_name)))
+                .collect();
+            nested_structs.extend(structs.into_iter().map(|s| rename_struct_types(s, &renames)));
+            results.push((rename_field_type(field_type, &renames), is_array));
+        }
+
+        Ok(results)
+    }
+
     /// Analyze mixed object types in an array to find common structure
     fn analyze_mixed_object_types(
         &mut self,
-        arr: &[Value],
+        arr: &[&Value],
         field_name: &str,
         nested_structs: &mut Vec<StructDefinition>,
     ) -> crate::error::Result<FieldType> {
@@ -558,32 +663,34 @@ impl JsonToIrConverter {
     /// Create a unified struct definition from an array of similar objects
     fn create_unified_struct_from_array(
         &mut self,
-        arr: &[Value],
+        arr: &[&Value],
         struct_name: &str,
     ) -> crate::error::Result<StructDefinition> {
         let mut unified_fields: std::collections::HashMap<String, (FieldType, bool, bool)> = std::collections::HashMap::new();
         let mut nested_structs = Vec::new();
         
         // Analyze all objects to determine field types and optionality
-        for element in arr {
-            if let Value::Object(obj) = element {
-                for (key, value) in obj {
-                    let (field_type, is_array) = self.process_json_type_with_value(value, key, &mut nested_structs)?;
-                    let is_optional = value.is_null();
-                    
-                    match unified_fields.get_mut(key) {
-                        Some((existing_type, existing_optional, existing_array)) => {
-                            // If types differ, make it optional and use Any
-                            if *existing_type != field_type {
-                                *existing_type = FieldType::Any;
-                            }
-                            *existing_optional = *existing_optional || is_optional;
-                            *existing_array = *existing_array || is_array;
-                        }
-                        None => {
-                            unified_fields.insert(key.clone(), (field_type, is_optional, is_array));
-                        }
+        let values: Vec<(&Value, String)> = arr
+            .iter()
+            .filter_map(|element| element.as_object())
+            .flat_map(|obj| obj.iter().map(|(key, value)| (value, key.clone())))
+            .collect();
+        let processed = self.process_values(&values, &mut nested_structs)?;
+
+        for ((value, key), (field_type, is_array)) in values.iter().zip(processed) {
+            let is_optional = value.is_null();
+
+            match unified_fields.get_mut(key) {
+                Some((existing_type, existing_optional, existing_array)) => {
+                    // If types differ, make it optional and use Any
+                    if *existing_type != field_type {
+                        *existing_type = FieldType::Any;
                     }
+                    *existing_optional = *existing_optional || is_optional;
+                    *existing_array = *existing_array || is_array;
+                }
+                None => {
+                    unified_fields.insert(key.clone(), (field_type, is_optional, is_array));
                 }
             }
         }
@@ -656,8 +763,6 @@ impl JsonToIrConverter {
 
     /// Generate a name for a nested struct based on the field name and current path
     fn generate_nested_struct_name(&mut self, field_name: &str) -> String {
-        use crate::codegen::utils::NameConverter;
-        
         // Build a hierarchical name based on the current path
         let mut name_parts = self.current_path.clone();
         name_parts.push(field_name.to_string());
@@ -671,24 +776,28 @@ impl JsonToIrConverter {
             name_parts.join("_")
         };
         
-        let converted_name = NameConverter::convert_type_name(&base_name, self.type_mapper.language());
-        
-        // Ensure uniqueness by adding a suffix if needed
-        let mut final_name = converted_name.clone();
-        let mut counter = 1;
-        
-        while self.generated_names.contains(&final_name) {
-            final_name = format!("{converted_name}{counter}");
-            counter += 1;
+        let converted_name = if self.neutral_names {
+            base_name
+        } else {
+            NameConverter::convert_type_name(&base_name, self.type_mapper.language())
+        };
+        self.allocate_struct_name(&converted_name)
+    }
+
+    /// Reserve a unique struct name, adding a numeric suffix if the name is taken
+    fn allocate_struct_name(&mut self, converted_name: &str) -> String {
+        let final_name = allocate_name(&mut self.generated_names, converted_name);
+        if let Some(log) = &mut self.name_log {
+            log.push((converted_name.to_string(), final_name.clone()));
         }
-        
-        self.generated_names.insert(final_name.clone());
         final_name
     }
 
     /// Convert field name to appropriate code name based on language conventions
     fn convert_field_name(&self, field_name: &str) -> String {
-        use crate::codegen::utils::NameConverter;
+        if self.neutral_names {
+            return field_name.to_string();
+        }
         NameConverter::convert_field_name(field_name, self.type_mapper.language())
     }
 
@@ -798,6 +907,197 @@ impl JsonToIrConverter {
     }
 }
 
+/// Reserve a unique name among `taken`, adding a numeric suffix if the name is taken
+fn allocate_name(taken: &mut HashSet<String>, name: &str) -> String {
+    let mut final_name = name.to_string();
+    let mut counter = 1;
+
+    while taken.contains(&final_name) {
+        final_name = format!("{name}{counter}");
+        counter += 1;
+    }
+
+    taken.insert(final_name.clone());
+    final_name
+}
+
+/// Default maximum nesting depth of the structs inferred by `JsonToIrConverter`
+pub const DEFAULT_MAX_DEPTH: usize = 20;
+
+/// Value the struct definitions of a generator are inferred from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IrSource {
+    /// The JSON document as given; its root must be an object
+    Document,
+    /// The records of an array root merged into one record, or the document as given
+    Records,
+    /// The records of an array root, or the root object, merged with `merge_values`
+    Merged,
+}
+
+/// Struct definitions inferred with the names found in the JSON
+#[derive(Debug, Clone)]
+struct NeutralIr {
+    /// Root struct, with every name left unconverted
+    root: StructDefinition,
+    /// Base name and allocated name of every nested struct, in allocation order
+    allocations: Vec<(String, String)>,
+}
+
+impl NeutralIr {
+    /// Apply a language's naming conventions, as if the IR had been inferred for it
+    ///
+    /// Nested struct names are allocated again from their converted base names in the
+    /// original order, so suffixes for names that only collide once converted match.
+    fn localize(&self, language: &str, root_name: &str) -> StructDefinition {
+        let mut taken = HashSet::new();
+        let renames: HashMap<String, String> = self
+            .allocations
+            .iter()
+            .map(|(base_name, neutral_name)| {
+                let converted = NameConverter::convert_type_name(base_name, language);
+                (neutral_name.clone(), allocate_name(&mut taken, &converted))
+            })
+            .collect();
+
+        let mut root = rename_struct_types(self.root.clone(), &renames);
+        root.name = root_name.to_string();
+        localize_field_names(&mut root, language);
+        root
+    }
+}
+
+/// Convert the code names of a struct's fields, and those of its nested structs
+fn localize_field_names(struct_def: &mut StructDefinition, language: &str) {
+    for field in &mut struct_def.fields {
+        field.code_name = NameConverter::convert_field_name(&field.json_name, language);
+    }
+    for nested in &mut struct_def.nested_structs {
+        localize_field_names(nested, language);
+    }
+}
+
+/// Outcome of one inference pass, shared by every generator that needs it
+type SharedInference = Arc<OnceLock<std::result::Result<NeutralIr, crate::error::J2sError>>>;
+
+/// Struct definitions inferred once from a JSON document and shared by several generators
+///
+/// Inference is the expensive part of code generation. `SharedIr` runs it at most once
+/// per source value and nesting limit, keeping the names found in the JSON, and hands
+/// each generator a copy renamed to its language's conventions. Generating several
+/// formats from one `SharedIr` therefore walks the data once.
+pub struct SharedIr<'a> {
+    /// The parsed input document
+    json_value: &'a Value,
+    /// Strategy used to select elements of large arrays
+    sampling: SamplingStrategy,
+    /// Inference results by resolved source and maximum depth
+    inferred: Mutex<HashMap<(IrSource, usize), SharedInference>>,
+}
+
+impl<'a> SharedIr<'a> {
+    /// Create a shared IR for a document; nothing is inferred until a generator asks
+    pub fn new(json_value: &'a Value, sampling: SamplingStrategy) -> Self {
+        Self {
+            json_value,
+            sampling,
+            inferred: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Get the parsed input document
+    pub fn json_value(&self) -> &'a Value {
+        self.json_value
+    }
+
+    /// Get the struct definitions for a language, with the default nesting limit
+    ///
+    /// # Arguments
+    /// * `source` - The value the structs are inferred from
+    /// * `language` - The language whose naming conventions are applied
+    /// * `root_name` - The name of the root struct
+    ///
+    /// # Returns
+    /// * `Result<StructDefinition>` - The root struct and its nested structs, or an error
+    pub fn struct_for(&self, source: IrSource, language: &str, root_name: &str) -> Result<StructDefinition> {
+        self.struct_with_max_depth(source, language, root_name, DEFAULT_MAX_DEPTH)
+    }
+
+    /// Get the struct definitions for a language, with a custom nesting limit
+    pub fn struct_with_max_depth(
+        &self,
+        source: IrSource,
+        language: &str,
+        root_name: &str,
+        max_depth: usize,
+    ) -> Result<StructDefinition> {
+        // Documents that are not an array of records are converted as given
+        let source = match (source, self.json_value) {
+            (IrSource::Records, Value::Array(records))
+                if !records.is_empty() && records.iter().all(Value::is_object) =>
+            {
+                IrSource::Merged
+            }
+            (IrSource::Records, _) => IrSource::Document,
+            (source, _) => source,
+        };
+
+        let inference = self
+            .inferred
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .entry((source, max_depth))
+            .or_default()
+            .clone();
+        let neutral = inference.get_or_init(|| {
+            let mut converter = JsonToIrConverter::with_max_depth("", max_depth);
+            converter.set_sampling_strategy(self.sampling.clone());
+            match source {
+                IrSource::Merged => {
+                    let records: Vec<&Value> = match self.json_value {
+                        Value::Array(records) => records.iter().collect(),
+                        value => vec![value],
+                    };
+                    converter.convert_to_neutral_struct(&merge_values(&records))
+                }
+                _ => converter.convert_to_neutral_struct(self.json_value),
+            }
+        });
+
+        match neutral {
+            Ok(neutral) => Ok(neutral.localize(language, root_name)),
+            Err(err) => Err(err.clone()),
+        }
+    }
+}
+
+/// Replace struct names assigned by a parallel worker with their final names
+fn rename_field_type(field_type: FieldType, renames: &HashMap<String, String>) -> FieldType {
+    match field_type {
+        FieldType::Custom(name) => FieldType::Custom(renames.get(&name).cloned().unwrap_or(name)),
+        other => other,
+    }
+}
+
+/// Apply `rename_field_type` to a struct, its fields and its nested structs
+fn rename_struct_types(
+    mut struct_def: StructDefinition,
+    renames: &HashMap<String, String>,
+) -> StructDefinition {
+    if let Some(name) = renames.get(&struct_def.name) {
+        struct_def.name = name.clone();
+    }
+    for field in &mut struct_def.fields {
+        field.field_type = rename_field_type(field.field_type.clone(), renames);
+    }
+    struct_def.nested_structs = struct_def
+        .nested_structs
+        .into_iter()
+        .map(|nested| rename_struct_types(nested, renames))
+        .collect();
+    struct_def
+}
+
 impl std::fmt::Display for FieldType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -1004,6 +1304,7 @@ impl TypeMapper {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::performance::LARGE_ARRAY_THRESHOLD;
     use serde_json::json;
 
     #[test]
@@ -1600,4 +1901,123 @@ mod tests {
         let metadata_field = struct_def.fields.iter().find(|f| f.json_name == "metadata").unwrap();
         assert!(metadata_field.is_optional);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_sampling_strategy_controls_large_array_inference() {
+        fn has_field(def: &StructDefinition, name: &str) -> bool {
+            def.fields.iter().any(|f| f.json_name == name)
+                || def.nested_structs.iter().any(|n| has_field(n, name))
+        }
+
+        // The extra field only shows up past the first 10 items
+        let items: Vec<Value> = (0..LARGE_ARRAY_THRESHOLD + 20)
+            .map(|i| {
+                if i >= 100 {
+                    json!({"id": i, "late_field": "x"})
+                } else {
+                    json!({"id": i})
+                }
+            })
+            .collect();
+        let json_data = json!({ "items": items });
+
+        let mut converter = JsonToIrConverter::new("go");
+        converter.set_sampling_strategy(SamplingStrategy::Head { size: 10 });
+        let head = converter.convert_to_struct(&json_data, "Root").unwrap();
+        assert!(!has_field(&head, "late_field"));
+        let items_field = head.fields.iter().find(|f| f.json_name == "items").unwrap();
+        assert_eq!(
+            items_field.metadata.get("sampling"),
+            Some(&"head:10".to_string())
+        );
+
+        let mut converter = JsonToIrConverter::new("go");
+        converter.set_sampling_strategy(SamplingStrategy::Stride { size: 10 });
+        let stride = converter.convert_to_struct(&json_data, "Root").unwrap();
+        assert!(has_field(&stride, "late_field"));
+
+        let mut converter = JsonToIrConverter::new("go");
+        converter.set_sampling_strategy(SamplingStrategy::Full);
+        let full = converter.convert_to_struct(&json_data, "Root").unwrap();
+        assert!(has_field(&full, "late_field"));
+        let items_field = full.fields.iter().find(|f| f.json_name == "items").unwrap();
+        assert!(!items_field.metadata.contains_key("sampling"));
+    }
+
+    #[test]
+    fn test_parallel_array_conversion_matches_sequential_naming() {
+        // Large enough to be analyzed across threads; every element yields a nested struct
+        // whose inner struct name collides with the previous element's
+        let items: Vec<Value> = (0..PARALLEL_ARRAY_THRESHOLD + 16)
+            .map(|i| json!({"id": i, "a": {"x": i}}))
+            .collect();
+        let json_data = json!({ "items": items });
+
+        let mut converter = JsonToIrConverter::new("go");
+        let struct_def = converter.convert_to_struct(&json_data, "Root").unwrap();
+
+        let element_structs: Vec<&StructDefinition> = struct_def
+            .nested_structs
+            .iter()
+            .filter(|s| {
+                s.name
+                    .strip_prefix("ItemsItems")
+                    .is_some_and(|suffix| suffix.parse::<usize>().is_ok())
+            })
+            .collect();
+        assert_eq!(element_structs.len(), PARALLEL_ARRAY_THRESHOLD + 16);
+
+        for (index, element) in element_structs.iter().enumerate() {
+            assert_eq!(element.name, format!("ItemsItems{index}"));
+
+            // Suffixes are handed out in element order, exactly as a sequential scan would
+            let expected_inner = if index == 0 {
+                "ItemsAA".to_string()
+            } else {
+                format!("ItemsAA{index}")
+            };
+            let a_field = element.fields.iter().find(|f| f.json_name == "a").unwrap();
+            assert_eq!(a_field.field_type, FieldType::Custom(expected_inner.clone()));
+            assert_eq!(element.nested_structs[0].name, expected_inner);
+        }
+    }
+
+    #[test]
+    fn test_shared_ir_matches_per_language_conversion() {
+        // Keys that only collide once converted, plus an array analyzed across threads
+        let items: Vec<Value> = (0..PARALLEL_ARRAY_THRESHOLD + 16)
+            .map(|i| if i % 500 == 0 { json!({"item_id": i, "meta": {"tag": i}}) } else { json!({"item_id": i}) })
+            .collect();
+        let json_data = json!({
+            "user_info": {"first-name": "Ada", "address": {"zip_code": "1"}},
+            "userInfo": {"class": 1, "address": {"zipCode": "2"}},
+            "items": items,
+        });
+        let ir = SharedIr::new(&json_data, SamplingStrategy::default());
+
+        for language in ["go", "rust", "typescript", "python", "java", "kotlin", "swift", "csharp"] {
+            let root_name = NameConverter::convert_type_name("Root", language);
+            let expected = JsonToIrConverter::new(language)
+                .convert_to_struct(&json_data, &root_name)
+                .unwrap();
+            let shared = ir.struct_for(IrSource::Document, language, &root_name).unwrap();
+            assert_eq!(shared, expected, "IR differs for {language}");
+        }
+    }
+
+    #[test]
+    fn test_shared_ir_merges_array_records() {
+        let json_data = json!([{"id": 1, "tags": [{"name": "a"}]}, {"id": 2, "extra": true}]);
+        let ir = SharedIr::new(&json_data, SamplingStrategy::default());
+
+        let records: Vec<&Value> = json_data.as_array().unwrap().iter().collect();
+        let expected = JsonToIrConverter::new("rust")
+            .convert_to_struct(&merge_values(&records), "Root")
+            .unwrap();
+        assert_eq!(ir.struct_for(IrSource::Records, "rust", "Root").unwrap(), expected);
+        assert_eq!(ir.struct_for(IrSource::Merged, "rust", "Root").unwrap(), expected);
+
+        // Without records the document itself must be an object
+        assert!(ir.struct_for(IrSource::Document, "rust", "Root").is_err());
+    }
+}
@@ -4,7 +4,9 @@
 //! It includes naming convention converters, identifier sanitizers, and other helper functions
 //! that are shared between multiple generators.
 
-use std::collections::HashSet;
+use crate::codegen::types::StructDefinition;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 
 /// Utility for converting between different naming conventions
 ///
@@ -19,7 +21,7 @@ impl NameConverter {
     /// generating identifiers for the specified language.
     ///
     /// # Arguments
-    /// * `language` - The target language ("go", "rust", "typescript", "python")
+    /// * `language` - The target language ("go", "rust", "typescript", "python", "kotlin", "swift", "java", "csharp", "dart")
     ///
     /// # Returns
     /// * `HashSet<String>` - Set of reserved keywords for the language
@@ -68,6 +70,51 @@ impl NameConverter {
                 "hasattr", "getattr", "setattr", "delattr", "callable", "iter", "next",
                 "open", "print", "input", "repr", "str", "chr", "ord", "hex", "oct", "bin"
             ],
+            "kotlin" => vec![
+                "as", "break", "class", "continue", "do", "else", "false", "for", "fun",
+                "if", "in", "interface", "is", "null", "object", "package", "return",
+                "super", "this", "throw", "true", "try", "typealias", "typeof", "val",
+                "var", "when", "while"
+            ],
+            "swift" => vec![
+                "associatedtype", "class", "deinit", "enum", "extension", "fileprivate",
+                "func", "import", "init", "inout", "internal", "let", "open", "operator",
+                "private", "precedencegroup", "protocol", "public", "rethrows", "static",
+                "struct", "subscript", "typealias", "var", "break", "case", "catch",
+                "continue", "default", "defer", "do", "else", "fallthrough", "for",
+                "guard", "if", "in", "repeat", "return", "throw", "switch", "where",
+                "while", "Any", "as", "await", "false", "is", "nil", "self", "Self",
+                "super", "throws", "true", "try"
+            ],
+            "java" => vec![
+                "abstract", "assert", "boolean", "break", "byte", "case", "catch", "char",
+                "class", "const", "continue", "default", "do", "double", "else", "enum",
+                "extends", "false", "final", "finally", "float", "for", "goto", "if",
+                "implements", "import", "instanceof", "int", "interface", "long", "native",
+                "new", "null", "package", "private", "protected", "public", "return",
+                "short", "static", "strictfp", "super", "switch", "synchronized", "this",
+                "throw", "throws", "transient", "true", "try", "void", "volatile", "while",
+                "var", "record", "yield"
+            ],
+            "csharp" => vec![
+                "abstract", "as", "base", "bool", "break", "byte", "case", "catch", "char",
+                "checked", "class", "const", "continue", "decimal", "default", "delegate",
+                "do", "double", "else", "enum", "event", "explicit", "extern", "false",
+                "finally", "fixed", "float", "for", "foreach", "goto", "if", "implicit",
+                "in", "int", "interface", "internal", "is", "lock", "long", "namespace",
+                "new", "null", "object", "operator", "out", "override", "params", "private",
+                "protected", "public", "readonly", "ref", "return", "sbyte", "sealed",
+                "short", "sizeof", "stackalloc", "static", "string", "struct", "switch",
+                "this", "throw", "true", "try", "typeof", "uint", "ulong", "unchecked",
+                "unsafe", "ushort", "using", "virtual", "void", "volatile", "while"
+            ],
+            "dart" => vec![
+                "assert", "await", "break", "case", "catch", "class", "const", "continue",
+                "default", "do", "else", "enum", "extends", "false", "final", "finally",
+                "for", "if", "in", "is", "new", "null", "rethrow", "return", "super",
+                "switch", "this", "throw", "true", "try", "var", "void", "while", "with",
+                "yield"
+            ],
             _ => vec![]
         };
         
@@ -77,7 +124,9 @@ impl NameConverter {
     /// Sanitize an identifier for a specific language
     ///
     /// This method handles reserved keywords and invalid characters to ensure
-    /// the resulting identifier is valid in the target language.
+    /// the resulting identifier is valid in the target language. Swift keywords are
+    /// escaped with backticks (`` `default` ``) and C# keywords with an `@` prefix
+    /// (`@class`) rather than renamed.
     ///
     /// # Arguments
     /// * `input` - The identifier to sanitize
@@ -87,7 +136,25 @@ impl NameConverter {
     /// * `String` - A sanitized identifier that's safe to use
     pub fn sanitize_identifier_for_language(input: &str, language: &str) -> String {
         let keywords = Self::get_reserved_keywords(language);
-        Self::sanitize_identifier(input, &keywords)
+        match language {
+            "swift" => {
+                let sanitized = Self::sanitize_identifier(input, &HashSet::new());
+                if keywords.contains(&sanitized) {
+                    format!("`{sanitized}`")
+                } else {
+                    sanitized
+                }
+            }
+            "csharp" => {
+                let sanitized = Self::sanitize_identifier(input, &HashSet::new());
+                if keywords.contains(&sanitized) {
+                    format!("@{sanitized}")
+                } else {
+                    sanitized
+                }
+            }
+            _ => Self::sanitize_identifier(input, &keywords),
+        }
     }
 
     /// Convert field name to appropriate naming convention for the language
@@ -110,9 +177,9 @@ impl NameConverter {
         };
         
         let converted = match language {
-            "go" => Self::to_pascal_case(&cleaned),
-            "typescript" => Self::to_camel_case(&cleaned),
-            "rust" | "python" => Self::to_snake_case(&cleaned),
+            "go" | "csharp" => Self::to_pascal_case(&cleaned),
+            "typescript" | "kotlin" | "swift" | "java" | "dart" | "graphql" => Self::to_camel_case(&cleaned),
+            "rust" | "python" | "proto" | "sql" => Self::to_snake_case(&cleaned),
             _ => cleaned,
         };
         
@@ -139,7 +206,9 @@ impl NameConverter {
         };
         
         let converted = match language {
-            "go" | "rust" | "typescript" | "python" => Self::to_pascal_case(&cleaned),
+            "go" | "rust" | "typescript" | "python" | "kotlin" | "swift" | "java" | "csharp" | "dart" | "proto" | "graphql" | "avro" => {
+                Self::to_pascal_case(&cleaned)
+            }
             _ => cleaned,
         };
         
@@ -472,6 +541,131 @@ pub fn escape_comment_string(input: &str) -> String {
         .to_string()
 }
 
+/// Merge JSON values into one representative value
+///
+/// Objects are merged key by key and all array elements that are objects are
+/// merged into a single element, so the converter sees every key that occurs in
+/// any record. Numbers prefer a floating point sample; other scalars keep the
+/// first non-null value.
+///
+/// # Arguments
+/// * `values` - The values to merge, typically the records of a JSON array
+///
+/// # Returns
+/// * `Value` - A value with the keys and array elements of all inputs
+pub fn merge_values(values: &[&Value]) -> Value {
+    let present: Vec<&Value> = values.iter().copied().filter(|value| !value.is_null()).collect();
+    let Some(first) = present.first() else {
+        return Value::Null;
+    };
+
+    if present.iter().all(|value| value.is_object()) {
+        let mut merged = serde_json::Map::new();
+        for value in &present {
+            for key in value.as_object().into_iter().flat_map(|object| object.keys()) {
+                if merged.contains_key(key) {
+                    continue;
+                }
+                let children: Vec<&Value> = present.iter().filter_map(|value| value.get(key)).collect();
+                merged.insert(key.clone(), merge_values(&children));
+            }
+        }
+        return Value::Object(merged);
+    }
+
+    if present.iter().all(|value| value.is_array()) {
+        let elements: Vec<&Value> = present
+            .iter()
+            .filter_map(|value| value.as_array())
+            .flatten()
+            .collect();
+        if !elements.is_empty() && elements.iter().all(|element| element.is_object()) {
+            return Value::Array(vec![merge_values(&elements)]);
+        }
+        return Value::Array(elements.into_iter().cloned().collect());
+    }
+
+    if present.iter().all(|value| value.is_number()) {
+        return (*present.iter().find(|value| value.is_f64()).unwrap_or(first)).clone();
+    }
+
+    (*first).clone()
+}
+
+/// Get the samples a JSON document stands for
+///
+/// An array of records is converted as one merged record, so each record is a
+/// sample of that record; any other document is its own only sample.
+///
+/// # Arguments
+/// * `json_value` - The document being converted
+///
+/// # Returns
+/// * `Vec<&Value>` - The records of the array, or the document itself
+pub fn record_samples(json_value: &Value) -> Vec<&Value> {
+    match json_value {
+        Value::Array(records) if !records.is_empty() && records.iter().all(Value::is_object) => {
+            records.iter().collect()
+        }
+        _ => vec![json_value],
+    }
+}
+
+/// Index a struct and all of its nested structs by name
+///
+/// The first struct found under a name is kept.
+pub fn collect_structs<'a>(struct_def: &'a StructDefinition, structs: &mut HashMap<&'a str, &'a StructDefinition>) {
+    structs.entry(struct_def.name.as_str()).or_insert(struct_def);
+    for nested in &struct_def.nested_structs {
+        collect_structs(nested, structs);
+    }
+}
+
+/// Flatten a struct and all of its nested structs, dependencies first
+///
+/// Generators whose languages need every type declared at the top level use this
+/// order, so each nested struct precedes the struct referring to it and the given
+/// struct comes last.
+pub fn flatten_structs(struct_def: &StructDefinition) -> Vec<&StructDefinition> {
+    let mut structs = Vec::new();
+    for nested in &struct_def.nested_structs {
+        structs.extend(flatten_structs(nested));
+    }
+    structs.push(struct_def);
+    structs
+}
+
+/// Longest string taken as an enum value
+const MAX_ENUM_VALUE_LEN: usize = 32;
+
+/// Detect strings drawn from a small set of repeated values
+///
+/// Only short, identifier-like strings that repeat are taken as members of an
+/// enum: every distinct value must occur twice on average, so a single sample
+/// is never enough.
+///
+/// # Arguments
+/// * `strings` - Every string seen at one position of the JSON data
+/// * `max_values` - Largest number of distinct values an enum may have
+///
+/// # Returns
+/// * `Option<Vec<String>>` - The sorted distinct values, or None if the strings are not an enum
+pub fn detect_enum_values(strings: &[&str], max_values: usize) -> Option<Vec<String>> {
+    let distinct: std::collections::BTreeSet<&str> = strings.iter().copied().collect();
+    let is_enum = !distinct.is_empty()
+        && distinct.len() <= max_values
+        && strings.len() >= distinct.len() * 2
+        && distinct.iter().all(|value| {
+            !value.is_empty()
+                && value.len() <= MAX_ENUM_VALUE_LEN
+                && value
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+        });
+
+    is_enum.then(|| distinct.into_iter().map(str::to_string).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -585,6 +779,26 @@ mod tests {
         assert!(py_keywords.contains("def"));
         assert!(py_keywords.contains("import"));
 
+        let kotlin_keywords = NameConverter::get_reserved_keywords("kotlin");
+        assert!(kotlin_keywords.contains("fun"));
+        assert!(kotlin_keywords.contains("val"));
+        assert!(kotlin_keywords.contains("when"));
+
+        let java_keywords = NameConverter::get_reserved_keywords("java");
+        assert!(java_keywords.contains("class"));
+        assert!(java_keywords.contains("default"));
+        assert!(java_keywords.contains("record"));
+
+        let csharp_keywords = NameConverter::get_reserved_keywords("csharp");
+        assert!(csharp_keywords.contains("namespace"));
+        assert!(csharp_keywords.contains("string"));
+        assert!(csharp_keywords.contains("event"));
+
+        let dart_keywords = NameConverter::get_reserved_keywords("dart");
+        assert!(dart_keywords.contains("final"));
+        assert!(dart_keywords.contains("with"));
+        assert!(!dart_keywords.contains("required"));
+
         let unknown_keywords = NameConverter::get_reserved_keywords("unknown");
         assert!(unknown_keywords.is_empty());
     }
@@ -610,6 +824,16 @@ mod tests {
         assert_eq!(NameConverter::sanitize_identifier_for_language("class", "python"), "class_");
         assert_eq!(NameConverter::sanitize_identifier_for_language("def", "python"), "def_");
         assert_eq!(NameConverter::sanitize_identifier_for_language("valid", "python"), "valid");
+
+        // Test Swift keywords (escaped with backticks, case-sensitive)
+        assert_eq!(NameConverter::sanitize_identifier_for_language("default", "swift"), "`default`");
+        assert_eq!(NameConverter::sanitize_identifier_for_language("protocol", "swift"), "`protocol`");
+        assert_eq!(NameConverter::sanitize_identifier_for_language("Protocol", "swift"), "Protocol");
+        assert_eq!(NameConverter::sanitize_identifier_for_language("valid", "swift"), "valid");
+
+        // C# escapes keywords with an @ prefix, case-sensitively
+        assert_eq!(NameConverter::sanitize_identifier_for_language("class", "csharp"), "@class");
+        assert_eq!(NameConverter::sanitize_identifier_for_language("Class", "csharp"), "Class");
     }
 
     #[test]
@@ -633,6 +857,42 @@ mod tests {
         assert_eq!(NameConverter::convert_field_name("UserName", "python"), "user_name");
         assert_eq!(NameConverter::convert_field_name("APIKey", "python"), "api_key");
         assert_eq!(NameConverter::convert_field_name("class", "python"), "class_"); // Reserved keyword
+
+        // Test Kotlin (camelCase)
+        assert_eq!(NameConverter::convert_field_name("user_name", "kotlin"), "userName");
+        assert_eq!(NameConverter::convert_field_name("when", "kotlin"), "when_"); // Reserved keyword
+
+        // Test Swift (camelCase, keywords in backticks)
+        assert_eq!(NameConverter::convert_field_name("user_name", "swift"), "userName");
+        assert_eq!(NameConverter::convert_field_name("default", "swift"), "`default`");
+
+        // Test Java (camelCase)
+        assert_eq!(NameConverter::convert_field_name("user_name", "java"), "userName");
+        assert_eq!(NameConverter::convert_field_name("default", "java"), "default_"); // Reserved keyword
+
+        // Test C# (PascalCase)
+        assert_eq!(NameConverter::convert_field_name("user_name", "csharp"), "UserName");
+
+        // Test Dart (camelCase)
+        assert_eq!(NameConverter::convert_field_name("user_name", "dart"), "userName");
+        assert_eq!(NameConverter::convert_field_name("default", "dart"), "default_"); // Reserved word
+
+        // Test Protocol Buffers (snake_case, no reserved words)
+        assert_eq!(NameConverter::convert_field_name("userName", "proto"), "user_name");
+        assert_eq!(NameConverter::convert_field_name("message", "proto"), "message");
+
+        // Test GraphQL (camelCase, no reserved words)
+        assert_eq!(NameConverter::convert_field_name("user_name", "graphql"), "userName");
+        assert_eq!(NameConverter::convert_field_name("type", "graphql"), "type");
+
+        // Test SQL (snake_case; keywords are quoted by the generator instead)
+        assert_eq!(NameConverter::convert_field_name("createdAt", "sql"), "created_at");
+        assert_eq!(NameConverter::convert_field_name("order", "sql"), "order");
+
+        // Test Avro (JSON keys kept, invalid characters replaced)
+        assert_eq!(NameConverter::convert_field_name("userName", "avro"), "userName");
+        assert_eq!(NameConverter::convert_field_name("first-name", "avro"), "first_name");
+        assert_eq!(NameConverter::convert_field_name("2fa", "avro"), "_2fa");
     }
 
     #[test]
@@ -725,4 +985,71 @@ mod tests {
         assert_eq!(NameConverter::convert_field_name("user123Name", "rust"), "user123_name");
         assert_eq!(NameConverter::convert_field_name("API2Key", "rust"), "api2_key");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_merge_values() {
+        use serde_json::json;
+
+        let records = [
+            json!({"id": 1, "score": 2, "tags": ["a"], "profile": {"bio": "Hi"}, "items": [{"sku": "a"}]}),
+            json!({"id": 2, "score": 2.5, "tags": ["b"], "profile": {"city": "Paris"}, "items": [{"qty": 1}], "note": null}),
+        ];
+        let records: Vec<&Value> = records.iter().collect();
+
+        assert_eq!(
+            merge_values(&records),
+            json!({
+                "id": 1,
+                "score": 2.5,
+                "tags": ["a", "b"],
+                "profile": {"bio": "Hi", "city": "Paris"},
+                "items": [{"sku": "a", "qty": 1}],
+                "note": null
+            })
+        );
+    }
+
+    #[test]
+    fn test_record_samples() {
+        use serde_json::json;
+
+        let records = json!([{"id": 1}, {"id": 2}]);
+        assert_eq!(record_samples(&records), vec![&records[0], &records[1]]);
+
+        // Anything but a non-empty array of objects is its own only sample
+        for document in [json!({"id": 1}), json!([]), json!([1, 2]), json!([{"id": 1}, 2])] {
+            assert_eq!(record_samples(&document), vec![&document]);
+        }
+    }
+
+    #[test]
+    fn test_collect_structs() {
+        use crate::codegen::types::{FieldDefinition, FieldType};
+
+        let inner = StructDefinition::new("Inner").add_field(FieldDefinition::new("n", "n", FieldType::Integer));
+        let outer = StructDefinition::new("Outer")
+            .add_field(FieldDefinition::new("inner", "inner", FieldType::Custom("Inner".to_string())))
+            .add_nested_struct(inner);
+
+        let mut structs = HashMap::new();
+        collect_structs(&outer, &mut structs);
+        assert_eq!(structs.len(), 2);
+        assert_eq!(structs["Inner"].fields.len(), 1);
+        assert_eq!(structs["Outer"].nested_structs.len(), 1);
+    }
+
+    #[test]
+    fn test_detect_enum_values() {
+        let statuses = ["active", "inactive", "active", "inactive", "active"];
+        assert_eq!(
+            detect_enum_values(&statuses, 8),
+            Some(vec!["active".to_string(), "inactive".to_string()])
+        );
+
+        // Too many distinct values, values that do not repeat, or free text
+        assert_eq!(detect_enum_values(&statuses, 1), None);
+        assert_eq!(detect_enum_values(&["active"], 8), None);
+        assert_eq!(detect_enum_values(&["a b", "a b"], 8), None);
+        assert_eq!(detect_enum_values(&[], 8), None);
+    }
+}
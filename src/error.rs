@@ -1,7 +1,7 @@
 use thiserror::Error;
 
 /// Main error type for the j2s application
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum J2sError {
     /// File operation errors (reading, writing, path issues)
     #[error("File operation failed: {0}")]
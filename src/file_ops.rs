@@ -1,7 +1,17 @@
 use crate::error::{J2sError, Result};
+use crate::output::{self, Event};
 use std::fs;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 
+/// Path value that stands for stdin (as input) or stdout (as output)
+pub const STDIO_PATH: &str = "-";
+
+/// Check whether a path refers to stdin/stdout rather than a file
+pub fn is_stdio_path(path: &str) -> bool {
+    path == STDIO_PATH
+}
+
 /// Read JSON content from a file with enhanced error reporting and performance optimizations
 ///
 /// This function provides comprehensive error checking and optimized reading for JSON files.
@@ -66,8 +76,8 @@ pub fn read_json_file(path: &str) -> Result<String> {
 
     // Provide performance warning for large files
     if file_size > 10_000_000 {
-        eprintln!(
-            "⚠️  Warning: Large file detected ({:.1} MB). Processing may take some time.",
+        warning!(
+            "Large file detected ({:.1} MB). Processing may take some time.",
             file_size as f64 / 1_000_000.0
         );
     }
@@ -103,6 +113,96 @@ pub fn read_json_file(path: &str) -> Result<String> {
     }
 }
 
+/// Read JSON content from stdin with the same checks as `read_json_file`
+///
+/// # Returns
+/// * `Result<String>` - The content read from stdin, or a detailed error
+///
+/// # Errors
+/// * Returns `J2sError::File` if stdin cannot be read, is not valid UTF-8, is empty,
+///   or is larger than 100MB
+pub fn read_json_stdin() -> Result<String> {
+    let content = read_stdin()?;
+    if content.trim().is_empty() {
+        return Err(J2sError::file_error(
+            "No JSON data received on stdin\n   Pipe JSON into j2s or pass an input file path",
+        ));
+    }
+
+    Ok(content)
+}
+
+/// Read everything on stdin, which may be empty
+///
+/// # Returns
+/// * `Result<String>` - The content read from stdin, or a detailed error
+///
+/// # Errors
+/// * Returns `J2sError::File` if stdin cannot be read, is not valid UTF-8, or is
+///   larger than 100MB
+pub fn read_stdin() -> Result<String> {
+    let mut content = String::new();
+    if let Err(err) = std::io::stdin().lock().read_to_string(&mut content) {
+        let error_msg = match err.kind() {
+            std::io::ErrorKind::InvalidData => format!(
+                "Invalid encoding on stdin: {err}\n   Ensure the piped data is UTF-8 encoded"
+            ),
+            _ => format!("Failed to read from stdin: {err}"),
+        };
+        return Err(J2sError::file_error(error_msg));
+    }
+
+    if content.len() > 100_000_000 {
+        return Err(J2sError::file_error(format!(
+            "Input on stdin too large ({:.1} MB)\n   Inputs larger than 100MB are not supported for performance reasons",
+            content.len() as f64 / 1_000_000.0
+        )));
+    }
+
+    Ok(content)
+}
+
+/// Write a generated artifact in the given format to stdout
+///
+/// A trailing newline is added if the content does not end with one, so that piped
+/// output is a well-formed text file.
+///
+/// # Errors
+/// * Returns `J2sError::File` if stdout cannot be written (e.g., a closed pipe)
+pub fn write_to_stdout(content: &str, format: &str) -> Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    let mut result = stdout.write_all(content.as_bytes());
+    if result.is_ok() && !content.ends_with('\n') {
+        result = stdout.write_all(b"\n");
+    }
+    result
+        .and_then(|()| stdout.flush())
+        .map_err(|err| J2sError::file_error(format!("Failed to write to stdout: {err}")))?;
+
+    output::report(Event::Output {
+        format,
+        path: STDIO_PATH,
+        bytes: content.len(),
+    });
+    Ok(())
+}
+
+/// Write generated code to stdout after the same validation as `write_code_file`
+///
+/// # Errors
+/// * Returns `J2sError::File` if the content is empty, fails validation, or stdout
+///   cannot be written
+pub fn write_code_to_stdout(content: &str, format: &str) -> Result<()> {
+    if content.trim().is_empty() {
+        return Err(J2sError::file_error(
+            "Cannot write empty code content to stdout\n   Generated code content is empty, this may indicate a code generation error",
+        ));
+    }
+
+    validate_code_content(content, format, "<stdout>")?;
+    write_to_stdout(content, format)
+}
+
 /// Write schema content to a file with enhanced error reporting and safety checks
 ///
 /// This function provides comprehensive error checking and safe file writing for schema output.
@@ -135,7 +235,7 @@ pub fn write_schema_file(path: &str, content: &str) -> Result<()> {
 
     // Check if we're about to overwrite an existing file
     if file_path.exists() {
-        eprintln!("⚠️  Warning: Overwriting existing file: {path}");
+        warning!("Overwriting existing file: {path}");
     }
 
     // Write the content to the file
@@ -155,6 +255,11 @@ pub fn write_schema_file(path: &str, content: &str) -> Result<()> {
                             metadata.len()
                         )))
                     } else {
+                        output::report(Event::Output {
+                            format: "schema",
+                            path,
+                            bytes: content.len(),
+                        });
                         Ok(())
                     }
                 }
@@ -288,6 +393,7 @@ pub fn generate_output_path(input_path: &str, output_path: Option<&str>) -> Stri
             let file_stem = input_path_buf
                 .file_stem()
                 .and_then(|s| s.to_str())
+                .filter(|stem| !is_stdio_path(stem))
                 .unwrap_or("output");
 
             // Get the parent directory
@@ -317,6 +423,7 @@ pub fn generate_output_path(input_path: &str, output_path: Option<&str>) -> Stri
 /// * `input_path` - Path to the input JSON file
 /// * `output_path` - Optional custom output path
 /// * `format` - Target format/language (go, rust, typescript, python, etc.)
+/// * `language_options` - The `--lang-option` pairs, which can change the extension
 ///
 /// # Returns
 /// * `String` - The generated output path with appropriate file extension
@@ -328,10 +435,15 @@ pub fn generate_output_path(input_path: &str, output_path: Option<&str>) -> Stri
 /// * Preserves directory structure from input path
 ///
 /// # Examples
-/// * `generate_code_output_path("data.json", None, "go")` → `"data.go"`
-/// * `generate_code_output_path("path/to/data.json", None, "rust")` → `"path/to/data.rs"`
-/// * `generate_code_output_path("data.json", Some("custom.ts"), "typescript")` → `"custom.ts"`
-pub fn generate_code_output_path(input_path: &str, output_path: Option<&str>, format: &str) -> String {
+/// * `generate_code_output_path("data.json", None, "go", &[])` → `"data.go"`
+/// * `generate_code_output_path("path/to/data.json", None, "rust", &[])` → `"path/to/data.rs"`
+/// * `generate_code_output_path("data.json", Some("custom.ts"), "typescript", &[])` → `"custom.ts"`
+pub fn generate_code_output_path(
+    input_path: &str,
+    output_path: Option<&str>,
+    format: &str,
+    language_options: &[(String, String)],
+) -> String {
     match output_path {
         Some(path) => path.to_string(),
         None => {
@@ -341,13 +453,14 @@ pub fn generate_code_output_path(input_path: &str, output_path: Option<&str>, fo
             let file_stem = input_path_buf
                 .file_stem()
                 .and_then(|s| s.to_str())
+                .filter(|stem| !is_stdio_path(stem))
                 .unwrap_or("output");
 
             // Get the parent directory
             let parent = input_path_buf.parent();
 
-            // Determine file extension based on format
-            let extension = get_file_extension_for_format(format);
+            // Determine file extension based on format and its options
+            let extension = get_file_extension_with_options(format, language_options);
 
             // Create the output filename
             let output_filename = format!("{file_stem}.{extension}");
@@ -364,6 +477,69 @@ pub fn generate_code_output_path(input_path: &str, output_path: Option<&str>, fo
     }
 }
 
+/// Generate the output path for one format of a multi-format run
+///
+/// When several formats are generated in one invocation, `--output` names a directory
+/// and every artifact is written there as `<input_stem>.<format_extension>`.
+///
+/// # Arguments
+/// * `input_path` - Path to the input JSON file
+/// * `output_dir` - Optional output directory (defaults to the input file's directory)
+/// * `format` - Target format/language (go, rust, typescript, python, schema)
+/// * `language_options` - The `--lang-option` pairs, which can change the extension
+///
+/// # Returns
+/// * `String` - The output path for the given format
+///
+/// # Examples
+/// * `generate_multi_format_output_path("data.json", None, "go", &[])` → `"data.go"`
+/// * `generate_multi_format_output_path("data.json", Some("out"), "schema", &[])` → `"out/data.schema.json"`
+pub fn generate_multi_format_output_path(
+    input_path: &str,
+    output_dir: Option<&str>,
+    format: &str,
+    language_options: &[(String, String)],
+) -> String {
+    match output_dir {
+        Some(dir) => {
+            let file_name = generate_code_output_path(input_path, None, format, language_options);
+            let file_name = Path::new(&file_name)
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("output");
+            PathBuf::from(dir).join(file_name).to_string_lossy().to_string()
+        }
+        None => generate_code_output_path(input_path, None, format, language_options),
+    }
+}
+
+/// Generate the output path for one file of a split (one file per type) run
+///
+/// With `--split-files`, `--output` names a directory and each generated file keeps
+/// the name chosen by the generator (e.g. `User.java`).
+///
+/// # Arguments
+/// * `input_path` - Path to the input JSON file
+/// * `output_dir` - Optional output directory (defaults to the input file's directory)
+/// * `file_name` - File name chosen by the generator
+///
+/// # Returns
+/// * `String` - The output path for the given file
+///
+/// # Examples
+/// * `generate_split_output_path("path/to/data.json", None, "User.java")` → `"path/to/User.java"`
+/// * `generate_split_output_path("data.json", Some("out"), "User.java")` → `"out/User.java"`
+pub fn generate_split_output_path(input_path: &str, output_dir: Option<&str>, file_name: &str) -> String {
+    let dir = match output_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(input_path)
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default(),
+    };
+    dir.join(file_name).to_string_lossy().to_string()
+}
+
 /// Get the appropriate file extension for a given format/language
 ///
 /// # Arguments
@@ -377,6 +553,18 @@ pub fn generate_code_output_path(input_path: &str, output_path: Option<&str>, fo
 /// * `"rust"` → `"rs"`
 /// * `"typescript"` → `"ts"`
 /// * `"python"` → `"py"`
+/// * `"kotlin"` → `"kt"`
+/// * `"swift"` → `"swift"`
+/// * `"java"` → `"java"`
+/// * `"csharp"` → `"cs"`
+/// * `"dart"` → `"dart"`
+/// * `"zod"` → `"zod.ts"`
+/// * `"proto"` → `"proto"`
+/// * `"graphql"` → `"graphql"`
+/// * `"sql"` → `"sql"`
+/// * `"avro"` → `"avsc"`
+/// * `"openapi"` → `"openapi.yaml"`
+/// * `"jtd"` → `"jtd.json"`
 /// * `"schema"` → `"schema.json"`
 /// * Default → `"txt"`
 pub fn get_file_extension_for_format(format: &str) -> &'static str {
@@ -385,11 +573,49 @@ pub fn get_file_extension_for_format(format: &str) -> &'static str {
         "rust" => "rs",
         "typescript" => "ts",
         "python" => "py",
+        "kotlin" => "kt",
+        "swift" => "swift",
+        "java" => "java",
+        "csharp" => "cs",
+        "dart" => "dart",
+        "zod" => "zod.ts",
+        "proto" => "proto",
+        "graphql" => "graphql",
+        "sql" => "sql",
+        "avro" => "avsc",
+        "openapi" => "openapi.yaml",
+        "jtd" => "jtd.json",
         "schema" => "schema.json",
         _ => "txt", // Fallback for unknown formats
     }
 }
 
+/// Get the file extension for a format, taking its language options into account
+///
+/// OpenAPI documents written as JSON are named `.openapi.json`; every other format
+/// uses the extension from `get_file_extension_for_format`. Later occurrences of an
+/// option override earlier ones, as they do for the generators.
+///
+/// # Arguments
+/// * `format` - The target format/language identifier
+/// * `language_options` - The `--lang-option` pairs
+///
+/// # Returns
+/// * `&'static str` - The file extension (without the dot)
+pub fn get_file_extension_with_options(format: &str, language_options: &[(String, String)]) -> &'static str {
+    let option = |name: &str| {
+        language_options
+            .iter()
+            .rev()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+    match format {
+        "openapi" if option("openapi_format") == Some("json") => "openapi.json",
+        _ => get_file_extension_for_format(format),
+    }
+}
+
 /// Write generated code content to a file with enhanced error reporting and validation
 ///
 /// This function provides comprehensive error checking and safe file writing for generated code.
@@ -432,7 +658,7 @@ pub fn write_code_file(path: &str, content: &str, format: &str) -> Result<()> {
 
     // Check if we're about to overwrite an existing file
     if file_path.exists() {
-        eprintln!("⚠️  Warning: Overwriting existing {} file: {path}", format);
+        warning!("Overwriting existing {} file: {path}", format);
     }
 
     // Perform basic content validation based on format
@@ -456,7 +682,11 @@ pub fn write_code_file(path: &str, content: &str, format: &str) -> Result<()> {
                         )))
                     } else {
                         // Success - provide helpful feedback
-                        println!("✅ Generated {} code: {path} ({} bytes)", format, metadata.len());
+                        output::report(Event::Output {
+                            format,
+                            path,
+                            bytes: content.len(),
+                        });
                         Ok(())
                     }
                 }
@@ -530,8 +760,8 @@ fn validate_code_content(content: &str, format: &str, path: &str) -> Result<()>
     }
 
     if content.len() > 10_000_000 {
-        eprintln!(
-            "⚠️  Warning: Generated {} code is very large ({:.1} MB) for {path}",
+        warning!(
+            "Generated {} code is very large ({:.1} MB) for {path}",
             format,
             content.len() as f64 / 1_000_000.0
         );
@@ -543,6 +773,18 @@ fn validate_code_content(content: &str, format: &str, path: &str) -> Result<()>
         "rust" => validate_rust_content(content, path)?,
         "typescript" => validate_typescript_content(content, path)?,
         "python" => validate_python_content(content, path)?,
+        "kotlin" => validate_kotlin_content(content, path)?,
+        "swift" => validate_swift_content(content, path)?,
+        "java" => validate_java_content(content, path)?,
+        "csharp" => validate_csharp_content(content, path)?,
+        "dart" => validate_dart_content(content, path)?,
+        "zod" => validate_zod_content(content, path)?,
+        "proto" => validate_proto_content(content, path)?,
+        "graphql" => validate_graphql_content(content, path)?,
+        "sql" => validate_sql_content(content, path)?,
+        "avro" => validate_avro_content(content, path)?,
+        "openapi" => validate_openapi_content(content, path)?,
+        "jtd" => validate_jtd_content(content, path)?,
         _ => {
             // For unknown formats, just check for valid UTF-8
             if !content.is_ascii() && std::str::from_utf8(content.as_bytes()).is_err() {
@@ -560,7 +802,7 @@ fn validate_code_content(content: &str, format: &str, path: &str) -> Result<()>
 fn validate_go_content(content: &str, path: &str) -> Result<()> {
     // Check for package declaration
     if !content.contains("package ") {
-        eprintln!("⚠️  Warning: Generated Go code in {path} may be missing package declaration");
+        warning!("Generated Go code in {path} may be missing package declaration");
     }
 
     // Check for balanced braces (basic check)
@@ -650,7 +892,202 @@ fn validate_python_content(content: &str, path: &str) -> Result<()> {
 
     // Python-specific: check for reasonable indentation (should contain some spaces or tabs)
     if !content.contains("    ") && !content.contains('\t') {
-        eprintln!("⚠️  Warning: Generated Python code in {path} may have indentation issues");
+        warning!("Generated Python code in {path} may have indentation issues");
+    }
+
+    Ok(())
+}
+
+/// Validate Kotlin code content for basic syntax issues
+fn validate_kotlin_content(content: &str, path: &str) -> Result<()> {
+    // Check for balanced parentheses (data class constructors)
+    let open_parens = content.matches('(').count();
+    let close_parens = content.matches(')').count();
+    if open_parens != close_parens {
+        return Err(J2sError::file_error(format!(
+            "Generated Kotlin code has unbalanced parentheses in {path}: {} open, {} close",
+            open_parens, close_parens
+        )));
+    }
+
+    // Kotlin-specific: serializable classes need the kotlinx.serialization annotation
+    if !content.contains("@Serializable") {
+        warning!("Generated Kotlin code in {path} may be missing @Serializable annotations");
+    }
+
+    Ok(())
+}
+
+/// Validate Swift code content for basic syntax issues
+fn validate_swift_content(content: &str, path: &str) -> Result<()> {
+    // Check for balanced braces
+    let open_braces = content.matches('{').count();
+    let close_braces = content.matches('}').count();
+    if open_braces != close_braces {
+        return Err(J2sError::file_error(format!(
+            "Generated Swift code has unbalanced braces in {path}: {} open, {} close",
+            open_braces, close_braces
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate Java code content for basic syntax issues
+fn validate_java_content(content: &str, path: &str) -> Result<()> {
+    // Check for balanced braces
+    let open_braces = content.matches('{').count();
+    let close_braces = content.matches('}').count();
+    if open_braces != close_braces {
+        return Err(J2sError::file_error(format!(
+            "Generated Java code has unbalanced braces in {path}: {} open, {} close",
+            open_braces, close_braces
+        )));
+    }
+
+    // Jackson ignores unknown properties only when asked to
+    if !content.contains("@JsonIgnoreProperties") {
+        warning!("Generated Java code in {path} has no @JsonIgnoreProperties annotations");
+    }
+
+    Ok(())
+}
+
+/// Validate C# code content for basic syntax issues
+fn validate_csharp_content(content: &str, path: &str) -> Result<()> {
+    // Check for balanced braces
+    let open_braces = content.matches('{').count();
+    let close_braces = content.matches('}').count();
+    if open_braces != close_braces {
+        return Err(J2sError::file_error(format!(
+            "Generated C# code has unbalanced braces in {path}: {} open, {} close",
+            open_braces, close_braces
+        )));
+    }
+
+    // Nullable annotations produce warnings unless the nullable context is enabled
+    if content.contains("?") && !content.contains("#nullable enable") {
+        warning!("Generated C# code in {path} uses nullable types without #nullable enable");
+    }
+
+    Ok(())
+}
+
+/// Validate Dart code content for basic syntax issues
+fn validate_dart_content(content: &str, path: &str) -> Result<()> {
+    // Check for balanced braces
+    let open_braces = content.matches('{').count();
+    let close_braces = content.matches('}').count();
+    if open_braces != close_braces {
+        return Err(J2sError::file_error(format!(
+            "Generated Dart code has unbalanced braces in {path}: {} open, {} close",
+            open_braces, close_braces
+        )));
+    }
+
+    // build_runner only writes the serialization code for files with a part directive
+    if !content.contains("part '") {
+        warning!("Generated Dart code in {path} has no part directive");
+    }
+
+    Ok(())
+}
+
+/// Validate zod schema content for basic syntax issues
+fn validate_zod_content(content: &str, path: &str) -> Result<()> {
+    // The schemas cannot be used without the zod import
+    if !content.contains("from \"zod\"") {
+        return Err(J2sError::file_error(format!(
+            "Generated zod code is missing the zod import in {path}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate Protocol Buffers schema content for basic syntax issues
+fn validate_proto_content(content: &str, path: &str) -> Result<()> {
+    // Without the syntax statement protoc falls back to proto2
+    if !content.contains("syntax = \"proto3\";") {
+        return Err(J2sError::file_error(format!(
+            "Generated proto code is missing the proto3 syntax statement in {path}"
+        )));
+    }
+
+    // Check for basic syntax issues
+    let open_braces = content.matches('{').count();
+    let close_braces = content.matches('}').count();
+
+    if open_braces != close_braces {
+        return Err(J2sError::file_error(format!(
+            "Generated proto code has mismatched braces in {path}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate GraphQL SDL content for basic syntax issues
+fn validate_graphql_content(content: &str, path: &str) -> Result<()> {
+    // Check for basic syntax issues
+    let open_braces = content.matches('{').count();
+    let close_braces = content.matches('}').count();
+
+    if open_braces != close_braces {
+        return Err(J2sError::file_error(format!(
+            "Generated GraphQL code has mismatched braces in {path}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate SQL DDL content for basic syntax issues
+fn validate_sql_content(content: &str, path: &str) -> Result<()> {
+    // Check for basic syntax issues
+    let open_parens = content.matches('(').count();
+    let close_parens = content.matches(')').count();
+
+    if open_parens != close_parens {
+        return Err(J2sError::file_error(format!(
+            "Generated SQL code has mismatched parentheses in {path}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate Avro schema content for basic syntax issues
+fn validate_avro_content(content: &str, path: &str) -> Result<()> {
+    // Avro schemas are JSON documents
+    if let Err(e) = serde_json::from_str::<serde_json::Value>(content) {
+        return Err(J2sError::file_error(format!(
+            "Generated Avro schema is not valid JSON in {path}: {e}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate OpenAPI document content for basic syntax issues
+fn validate_openapi_content(content: &str, path: &str) -> Result<()> {
+    // Both syntaxes start the document with the OpenAPI version
+    if !content.contains("openapi") {
+        return Err(J2sError::file_error(format!(
+            "Generated OpenAPI document is missing the openapi version in {path}"
+        )));
+    }
+
+    Ok(())
+}
+
+/// Validate JTD schema content for basic syntax issues
+fn validate_jtd_content(content: &str, path: &str) -> Result<()> {
+    // JTD schemas are JSON documents
+    if let Err(e) = serde_json::from_str::<serde_json::Value>(content) {
+        return Err(J2sError::file_error(format!(
+            "Generated JTD schema is not valid JSON in {path}: {e}"
+        )));
     }
 
     Ok(())
@@ -859,68 +1296,147 @@ mod tests {
         assert_eq!(get_file_extension_for_format("rust"), "rs");
         assert_eq!(get_file_extension_for_format("typescript"), "ts");
         assert_eq!(get_file_extension_for_format("python"), "py");
+        assert_eq!(get_file_extension_for_format("kotlin"), "kt");
+        assert_eq!(get_file_extension_for_format("swift"), "swift");
+        assert_eq!(get_file_extension_for_format("java"), "java");
+        assert_eq!(get_file_extension_for_format("csharp"), "cs");
+        assert_eq!(get_file_extension_for_format("dart"), "dart");
+        assert_eq!(get_file_extension_for_format("zod"), "zod.ts");
+        assert_eq!(get_file_extension_for_format("proto"), "proto");
+        assert_eq!(get_file_extension_for_format("graphql"), "graphql");
+        assert_eq!(get_file_extension_for_format("sql"), "sql");
+        assert_eq!(get_file_extension_for_format("avro"), "avsc");
+        assert_eq!(get_file_extension_for_format("openapi"), "openapi.yaml");
+        assert_eq!(get_file_extension_for_format("jtd"), "jtd.json");
         assert_eq!(get_file_extension_for_format("schema"), "schema.json");
         assert_eq!(get_file_extension_for_format("unknown"), "txt");
     }
 
+    #[test]
+    fn test_get_file_extension_with_options() {
+        let json = vec![("openapi_format".to_string(), "json".to_string())];
+        assert_eq!(get_file_extension_with_options("openapi", &json), "openapi.json");
+        assert_eq!(get_file_extension_with_options("openapi", &[]), "openapi.yaml");
+        assert_eq!(get_file_extension_with_options("go", &json), "go");
+
+        // The last occurrence of an option wins
+        let overridden = vec![json[0].clone(), ("openapi_format".to_string(), "yaml".to_string())];
+        assert_eq!(get_file_extension_with_options("openapi", &overridden), "openapi.yaml");
+
+        assert_eq!(generate_code_output_path("data.json", None, "openapi", &json), "data.openapi.json");
+        assert_eq!(
+            generate_multi_format_output_path("data.json", Some("out"), "openapi", &json),
+            PathBuf::from("out").join("data.openapi.json").to_string_lossy()
+        );
+    }
+
     #[test]
     fn test_generate_code_output_path_with_custom_output() {
         let input = "input.json";
         let custom_output = "custom/path/output.go";
 
-        let result = generate_code_output_path(input, Some(custom_output), "go");
+        let result = generate_code_output_path(input, Some(custom_output), "go", &[]);
         assert_eq!(result, custom_output);
     }
 
     #[test]
     fn test_generate_code_output_path_go() {
         let input = "data.json";
-        let result = generate_code_output_path(input, None, "go");
+        let result = generate_code_output_path(input, None, "go", &[]);
         assert_eq!(result, "data.go");
     }
 
     #[test]
     fn test_generate_code_output_path_rust() {
         let input = "data.json";
-        let result = generate_code_output_path(input, None, "rust");
+        let result = generate_code_output_path(input, None, "rust", &[]);
         assert_eq!(result, "data.rs");
     }
 
     #[test]
     fn test_generate_code_output_path_typescript() {
         let input = "data.json";
-        let result = generate_code_output_path(input, None, "typescript");
+        let result = generate_code_output_path(input, None, "typescript", &[]);
         assert_eq!(result, "data.ts");
     }
 
     #[test]
     fn test_generate_code_output_path_python() {
         let input = "data.json";
-        let result = generate_code_output_path(input, None, "python");
+        let result = generate_code_output_path(input, None, "python", &[]);
         assert_eq!(result, "data.py");
     }
 
     #[test]
     fn test_generate_code_output_path_with_directory() {
         let input = "path/to/data.json";
-        let result = generate_code_output_path(input, None, "go");
+        let result = generate_code_output_path(input, None, "go", &[]);
         assert_eq!(result, "path/to/data.go");
     }
 
     #[test]
     fn test_generate_code_output_path_no_extension() {
         let input = "data";
-        let result = generate_code_output_path(input, None, "rust");
+        let result = generate_code_output_path(input, None, "rust", &[]);
         assert_eq!(result, "data.rs");
     }
 
     #[test]
     fn test_generate_code_output_path_different_extension() {
         let input = "data.txt";
-        let result = generate_code_output_path(input, None, "typescript");
+        let result = generate_code_output_path(input, None, "typescript", &[]);
         assert_eq!(result, "data.ts");
     }
 
+    #[test]
+    fn test_generate_multi_format_output_path_default_directory() {
+        assert_eq!(generate_multi_format_output_path("data.json", None, "go", &[]), "data.go");
+        assert_eq!(
+            generate_multi_format_output_path("path/to/data.json", None, "schema", &[]),
+            "path/to/data.schema.json"
+        );
+    }
+
+    #[test]
+    fn test_generate_multi_format_output_path_with_directory() {
+        let input = "path/to/data.json";
+        assert_eq!(
+            generate_multi_format_output_path(input, Some("out"), "rust", &[]),
+            "out/data.rs"
+        );
+        assert_eq!(
+            generate_multi_format_output_path(input, Some("out/models"), "schema", &[]),
+            "out/models/data.schema.json"
+        );
+    }
+
+    #[test]
+    fn test_generate_split_output_path() {
+        assert_eq!(generate_split_output_path("data.json", None, "User.java"), "User.java");
+        assert_eq!(
+            generate_split_output_path("path/to/data.json", None, "User.java"),
+            "path/to/User.java"
+        );
+        assert_eq!(
+            generate_split_output_path("path/to/data.json", Some("out"), "Profile.java"),
+            "out/Profile.java"
+        );
+    }
+
+    #[test]
+    fn test_stdio_path_output_names() {
+        assert!(is_stdio_path("-"));
+        assert!(!is_stdio_path("data.json"));
+
+        // Piped input has no file name, so derived outputs use the "output" fallback
+        assert_eq!(generate_output_path("-", None), "output.schema.json");
+        assert_eq!(generate_code_output_path("-", None, "go", &[]), "output.go");
+        assert_eq!(
+            generate_multi_format_output_path("-", Some("out"), "rust", &[]),
+            "out/output.rs"
+        );
+    }
+
     #[test]
     fn test_write_code_file_success_go() {
         let temp_dir = TempDir::new().unwrap();
@@ -1139,7 +1655,7 @@ class User:
             fs::write(&input_path, json_content).unwrap();
 
             // Generate code output path
-            let output_path = generate_code_output_path(input_path.to_str().unwrap(), None, format);
+            let output_path = generate_code_output_path(input_path.to_str().unwrap(), None, format, &[]);
             assert!(output_path.ends_with(&format!("input_{}.{}", format, get_file_extension_for_format(format))));
 
             // Write code file
@@ -3,6 +3,9 @@
 //! This library provides functionality for generating JSON schemas and code
 //! from JSON data in multiple programming languages.
 
+#[macro_use]
+pub mod output;
+
 pub mod cli;
 pub mod codegen;
 pub mod error;
@@ -28,13 +28,16 @@
 //!
 //! # Using input flag
 //! j2s --input data.json --output schema.json
+//!
+//! # Inspect large arrays evenly instead of only their first items
+//! j2s data.json --sample stride:2000
 //! ```
 //!
 //! ## Performance Characteristics
 //!
 //! - Files up to 100MB are supported
 //! - Large files (>10MB) show progress indicators  
-//! - Very large arrays (>10k items) use sampling for performance
+//! - Very large arrays (>10k items) use sampling for performance (configurable via `--sample`)
 //! - Recursion depth is limited to prevent stack overflow
 //!
 //! ## Module Organization
@@ -43,6 +46,10 @@
 //! - `file_ops`: File I/O operations with enhanced error handling
 //! - `schema_generator`: Core JSON Schema generation logic
 //! - `error`: Comprehensive error types and handling
+//! - `output`: Routing of status messages to stdout or stderr
+
+#[macro_use]
+mod output;
 
 mod cli;
 mod codegen;
@@ -52,11 +59,25 @@ mod performance;
 mod schema_generator;
 
 use cli::{parse_args, print_help, print_version};
-use codegen::{factory::GeneratorFactory, generator::GenerationOptions};
+use codegen::{
+    factory::GeneratorFactory,
+    generator::{CodeGenerator, GenerationOptions},
+};
 use error::{J2sError, Result};
-use file_ops::{generate_code_output_path, generate_output_path, read_json_file, write_code_file, write_schema_file};
-use performance::{MemoryEfficientAnalyzer, ParallelCodeGenerator, PerformanceMonitor, StreamingJsonProcessor};
-use schema_generator::{generate_schema, generate_schema_with_progress};
+use file_ops::{
+    generate_code_output_path, generate_multi_format_output_path, generate_output_path,
+    generate_split_output_path, is_stdio_path, read_json_file, read_json_stdin, read_stdin, write_code_file,
+    write_code_to_stdout, write_schema_file, write_to_stdout, STDIO_PATH,
+};
+use output::Event;
+use std::io::IsTerminal;
+use std::collections::HashMap;
+use std::path::Path;
+use performance::{
+    MemoryEfficientAnalyzer, ParallelCodeGenerator, PerformanceMonitor, StreamingJsonProcessor,
+    LARGE_ARRAY_THRESHOLD,
+};
+use schema_generator::{generate_schema, generate_schema_with_progress, generate_schema_with_sampling};
 
 /// Main entry point for the j2s application
 ///
@@ -86,6 +107,7 @@ fn main() -> Result<()> {
     
     // Parse command line arguments
     let args = parse_args();
+    output::set_reporter(args.create_reporter());
 
     // Handle special cases first
     if std::env::args().any(|arg| arg == "--help" || arg == "-h") {
@@ -98,108 +120,203 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
-    // Get input file path
+    let result = run(&args, &mut performance_monitor);
+
+    // Report the outcome; the reporter decides whether to show the performance summary
+    let final_metrics = performance_monitor.finalize();
+    output::report(Event::Phase {
+        phase: "total",
+        duration: final_metrics.total_time,
+    });
+
+    // Warn if performance is not acceptable
+    if !final_metrics.is_performance_acceptable() {
+        warning!("Processing took longer than expected or used excessive memory; consider smaller input files or --verbose for details");
+    }
+    output::report(Event::Result {
+        success: result.is_ok(),
+        metrics: &final_metrics,
+    });
+
+    result
+}
+
+/// Read the input, infer its structure and write every requested artifact
+fn run(args: &cli::CliArgs, performance_monitor: &mut PerformanceMonitor) -> Result<()> {
+    if let Err(err) = args.validate() {
+        output::report_error(&err, &["Run j2s --help to see every option"]);
+        return Err(J2sError::argument_error(err));
+    }
+
+    // Get input file path; without one, JSON is read from a pipe on stdin
+    let implicit_stdin = args.get_input_path().is_none();
     let input_path = match args.get_input_path() {
-        Some(path) => path,
-        None => {
-            eprintln!("Error: No input file specified.");
-            eprintln!("Usage: j2s <input.json> [--output <output.json>] [--format <format>]");
-            eprintln!("       j2s --input <input.json> [--output <output.json>] [--format <format>]");
-            eprintln!("       j2s --help");
-            return Err(J2sError::argument_error("No input file specified"));
-        }
+        Some(path) => path.as_str(),
+        None if !std::io::stdin().is_terminal() => STDIO_PATH,
+        None => return Err(missing_input_error()),
     };
+    let from_stdin = is_stdio_path(input_path);
+
+    // Keep stdout for the artifact alone when it is written there
+    let to_stdout = writes_to_stdout(args, input_path);
+    output::set_status_to_stderr(to_stdout);
 
-    // Get the target format (defaults to "schema" for backward compatibility)
-    let format = args.get_format();
+    // Get the target formats (defaults to "schema" for backward compatibility)
+    let formats = args.get_formats();
+    let format = formats[0].as_str();
     
-    // Provide user feedback about the selected format
-    if format != "schema" {
-        println!("🎯 Target format: {} ({})", format, 
+    // Provide user feedback about the selected format(s)
+    if formats.len() > 1 {
+        status!("🎯 Target formats: {}", formats.join(", "));
+    } else if format != "schema" {
+        status!("🎯 Target format: {} ({})", format, 
                  match format {
                      "go" => "Go language structs",
                      "rust" => "Rust structs with serde",
                      "typescript" => "TypeScript interfaces",
                      "python" => "Python dataclasses",
+                     "kotlin" => "Kotlin data classes",
+                     "swift" => "Swift Codable structs",
+                     "java" => "Java records with Jackson",
+                     "csharp" => "C# records for System.Text.Json",
+                     "dart" => "Dart json_serializable classes",
+                     "zod" => "zod runtime validation schemas",
+                     "proto" => "Protocol Buffers proto3 schemas",
+                     "graphql" => "GraphQL SDL type definitions",
+                     "sql" => "SQL CREATE TABLE statements",
+                     "avro" => "Apache Avro record schemas",
+                     "openapi" => "OpenAPI 3.1 components",
+                     "jtd" => "JSON Type Definition schemas",
                      _ => "Unknown format"
                  });
     }
 
     // Provide user feedback about what we're doing
-    println!("📖 Reading JSON file: {input_path}");
+    if from_stdin {
+        status!("📖 Reading JSON from stdin");
+    } else {
+        status!("📖 Reading JSON file: {input_path}");
+    }
 
     // Read JSON file with performance monitoring
     let io_start = performance_monitor.start_operation();
-    let json_content = match read_json_file(input_path) {
+    let read_result = match (from_stdin, implicit_stdin) {
+        // Nothing piped in without a named input means the input was forgotten
+        (true, true) => match read_stdin() {
+            Ok(content) if content.trim().is_empty() => return Err(missing_input_error()),
+            other => other,
+        },
+        (true, false) => read_json_stdin(),
+        (false, _) => read_json_file(input_path),
+    };
+    let json_content = match read_result {
         Ok(content) => {
             let file_size = content.len();
             performance_monitor.record_input_size(file_size);
             
             if file_size > 1_000_000 {
-                println!(
+                status!(
                     "📊 Processing large file ({:.1} MB)...",
                     file_size as f64 / 1_000_000.0
                 );
             }
             content
         }
+        Err(e) if from_stdin => {
+            output::report_error(
+                &format!("Error reading stdin: {e}"),
+                &["Pipe UTF-8 encoded JSON into j2s, or pass an input file path"],
+            );
+            return Err(e);
+        }
         Err(e) => {
-            eprintln!("❌ Error reading input file: {e}");
-            eprintln!("💡 Tip: Make sure the file exists and you have read permissions");
+            output::report_error(
+                &format!("Error reading input file: {e}"),
+                &["Make sure the file exists and you have read permissions"],
+            );
             return Err(e);
         }
     };
-    performance_monitor.record_io_time(io_start.elapsed());
+    performance_monitor.record_read_time(io_start.elapsed());
 
     // Parse JSON content with performance optimization
-    println!("🔍 Parsing JSON content...");
+    status!("🔍 Parsing JSON content...");
     let streaming_processor = StreamingJsonProcessor::new();
-    let json_value = match streaming_processor.process_large_json(&json_content, &mut performance_monitor) {
+    let json_value = match streaming_processor.process_large_json(&json_content, performance_monitor) {
         Ok(value) => value,
         Err(e) => {
-            eprintln!("❌ Error parsing JSON: {e}");
-            eprintln!("💡 Tip: Check that your JSON file has valid syntax");
-            eprintln!("   Common issues: missing quotes, trailing commas, unescaped characters");
+            output::report_error(
+                &format!("Error parsing JSON: {e}"),
+                &[
+                    "Check that your JSON file has valid syntax",
+                    "Common issues: missing quotes, trailing commas, unescaped characters",
+                ],
+            );
             return Err(e);
         }
     };
 
     // Analyze JSON structure for optimization decisions
+    let analysis_start = performance_monitor.start_operation();
     let analyzer = MemoryEfficientAnalyzer::new();
-    let structure_analysis = analyzer.analyze_structure(&json_value, &mut performance_monitor);
-    
+    let structure_analysis = analyzer.analyze_structure(&json_value, performance_monitor);
+    output::report(Event::Phase {
+        phase: "analyze",
+        duration: analysis_start.elapsed(),
+    });
+
     if structure_analysis.is_complex() {
-        println!("📊 Complex JSON structure detected - using optimized processing");
-        if std::env::var("J2S_VERBOSE").is_ok() {
-            structure_analysis.print_summary();
+        status!("📊 Complex JSON structure detected - using optimized processing");
+        structure_analysis.print_summary();
+    }
+
+    // Let the user know when large arrays will only be partially inspected
+    if structure_analysis.max_array_size > LARGE_ARRAY_THRESHOLD {
+        let strategy = args.get_sampling_strategy().ok().flatten().unwrap_or_default();
+        if strategy.should_sample(structure_analysis.max_array_size) {
+            status!("🎲 Sampling large arrays (>{LARGE_ARRAY_THRESHOLD} items): {strategy}");
         }
     }
 
     // Generate output based on format
-    let result = match format {
+    match format {
+        _ if formats.len() > 1 => {
+            // Generate every requested format from this single parse
+            generate_multi_format_output(&json_value, input_path, args, &formats, &json_content, performance_monitor)
+        }
         "schema" => {
             // Generate JSON Schema (backward compatibility)
-            generate_schema_output(&json_value, input_path, &args, &json_content, &mut performance_monitor)
+            generate_schema_output(&json_value, input_path, args, &json_content, performance_monitor)
         }
         _ => {
             // Generate code for the specified language
-            generate_code_output(&json_value, input_path, &args, format, &mut performance_monitor)
+            generate_code_output(&json_value, input_path, args, format, performance_monitor)
         }
-    };
-
-    // Print performance summary if verbose mode is enabled or if processing took significant time
-    let final_metrics = performance_monitor.finalize();
-    if std::env::var("J2S_VERBOSE").is_ok() || final_metrics.total_time.as_millis() > 1000 {
-        final_metrics.print_summary();
     }
+}
 
-    // Warn if performance is not acceptable
-    if !final_metrics.is_performance_acceptable() {
-        eprintln!("⚠️  Warning: Processing took longer than expected or used excessive memory");
-        eprintln!("💡 Consider using smaller input files or enabling verbose mode for details");
-    }
+/// Report that no input was given, with usage examples
+fn missing_input_error() -> J2sError {
+    output::report_error(
+        "No input file specified",
+        &[
+            "Usage: j2s <input.json> [--output <output.json>] [--format <format>]",
+            "Usage: j2s --input <input.json> [--output <output.json>] [--format <format>]",
+            "Usage: cat input.json | j2s [--format <format>] > output",
+            "Run j2s --help to see every option",
+        ],
+    );
+    J2sError::argument_error("No input file specified")
+}
 
-    result
+/// Check whether the generated artifact goes to stdout
+///
+/// This is the case for `--output -`, and for stdin input without an explicit output path.
+fn writes_to_stdout(args: &cli::CliArgs, input_path: &str) -> bool {
+    match args.output.as_deref() {
+        Some(output) => is_stdio_path(output),
+        None => is_stdio_path(input_path),
+    }
 }
 
 /// Generate JSON Schema output (maintains backward compatibility)
@@ -213,47 +330,204 @@ fn generate_schema_output(
     // Generate output path for schema
     let output_path = generate_output_path(input_path, args.output.as_deref());
 
+    // Generate and serialize the schema
+    let schema_json = render_schema_json(json_value, args, json_content, performance_monitor)?;
+
+    if writes_to_stdout(args, input_path) {
+        let io_start = performance_monitor.start_operation();
+        write_to_stdout(&schema_json, "schema")?;
+        performance_monitor.record_output_size(schema_json.len());
+        performance_monitor.record_write_time(io_start.elapsed());
+        return Ok(());
+    }
+
+    // Write schema file
+    status!("💾 Writing schema to: {output_path}");
+    let io_start = performance_monitor.start_operation();
+    match write_schema_file(&output_path, &schema_json) {
+        Ok(()) => {
+            performance_monitor.record_output_size(schema_json.len());
+            performance_monitor.record_write_time(io_start.elapsed());
+            status!("✅ Successfully generated schema file: {output_path}");
+            let schema_size = schema_json.len();
+            status!("📈 Schema size: {:.1} KB", schema_size as f64 / 1000.0);
+        }
+        Err(e) => {
+            output::report_error(
+                &format!("Error writing schema file: {e}"),
+                &["Check that you have write permissions to the output directory"],
+            );
+            return Err(e);
+        }
+    }
+
+    Ok(())
+}
+
+/// Generate the JSON Schema for the input and serialize it to pretty-printed JSON
+fn render_schema_json(
+    json_value: &serde_json::Value,
+    args: &cli::CliArgs,
+    json_content: &str,
+    performance_monitor: &mut PerformanceMonitor,
+) -> Result<String> {
     // Generate schema with progress indication for large files
-    println!("⚙️  Generating JSON Schema...");
+    status!("⚙️  Generating JSON Schema...");
     let generation_start = performance_monitor.start_operation();
-    let schema = if json_content.len() > 100_000 {
+    let show_progress = json_content.len() > 100_000;
+    let schema = match args.get_sampling_strategy().ok().flatten() {
+        Some(sampling) => generate_schema_with_sampling(json_value, &sampling, show_progress),
         // Use progress indication for large files
-        generate_schema_with_progress(json_value, true)
-    } else {
-        generate_schema(json_value)
+        None if show_progress => generate_schema_with_progress(json_value, true),
+        None => generate_schema(json_value),
     };
     performance_monitor.record_generation_time(generation_start.elapsed());
 
     // Serialize schema to JSON
-    let schema_json = match serde_json::to_string_pretty(&schema) {
-        Ok(json) => json,
+    match serde_json::to_string_pretty(&schema) {
+        Ok(json) => Ok(json),
         Err(e) => {
-            eprintln!("❌ Error serializing schema: {e}");
-            eprintln!("💡 Tip: This is likely an internal error. Please report this issue.");
-            return Err(J2sError::schema_error(format!(
+            output::report_error(
+                &format!("Error serializing schema: {e}"),
+                &["This is likely an internal error. Please report this issue."],
+            );
+            Err(J2sError::schema_error(format!(
                 "Failed to serialize schema: {e}"
+            )))
+        }
+    }
+}
+
+/// Build the code generation options requested on the command line
+///
+/// `output_path` is the file the code is written to; its stem becomes the `file_stem`
+/// language option for generators that refer to their own file (e.g. Dart `part` directives).
+fn build_generation_options(args: &cli::CliArgs, output_path: Option<&str>) -> GenerationOptions {
+    let mut options = GenerationOptions::new()
+        .with_struct_name(args.get_struct_name())
+        .with_comments(true)
+        .with_optional_fields(true);
+    if let Ok(Some(sampling)) = args.get_sampling_strategy() {
+        options = options.with_sampling(sampling);
+    }
+    for (key, value) in args.get_language_options().unwrap_or_default() {
+        options = options.with_language_option(key, value);
+    }
+    if let Some(stem) = output_path.and_then(|path| Path::new(path).file_stem()).and_then(|stem| stem.to_str())
+        && !options.has_language_option("file_stem")
+    {
+        options = options.with_language_option("file_stem", stem);
+    }
+    options
+}
+
+/// Generate every requested format from a single parse-and-infer pass
+///
+/// When several formats are requested, `--output` names a directory and each artifact
+/// is written there as `<input_name>.<format_extension>`. Code for all languages is
+/// generated in parallel.
+fn generate_multi_format_output(
+    json_value: &serde_json::Value,
+    input_path: &str,
+    args: &cli::CliArgs,
+    formats: &[String],
+    json_content: &str,
+    performance_monitor: &mut PerformanceMonitor,
+) -> Result<()> {
+    if writes_to_stdout(args, input_path) {
+        output::report_error(
+            "Cannot write several formats to stdout",
+            &["Use --output <directory> to write one file per format"],
+        );
+        return Err(J2sError::argument_error(
+            "Multiple formats cannot be written to stdout",
+        ));
+    }
+
+    if args.split_files {
+        output::report_error(
+            "--split-files can only be used with a single format",
+            &["Run j2s once per format to split each into one file per type"],
+        );
+        return Err(J2sError::argument_error(
+            "--split-files cannot be combined with multiple formats",
+        ));
+    }
+
+    let output_dir = args.output.as_deref();
+    if let Some(dir) = output_dir {
+        if Path::new(dir).is_file() {
+            output::report_error(
+                &format!("Output path is an existing file: {dir}"),
+                &["When generating several formats, --output must name a directory"],
+            );
+            return Err(J2sError::argument_error(format!(
+                "Output path '{dir}' must be a directory when generating multiple formats"
             )));
         }
+        status!("📁 Writing outputs to directory: {dir}");
+    }
+
+    // Generate the schema once if requested
+    let schema_json = if formats.iter().any(|f| f == "schema") {
+        Some(render_schema_json(json_value, args, json_content, performance_monitor)?)
+    } else {
+        None
     };
 
-    // Write schema file
-    println!("💾 Writing schema to: {output_path}");
-    let io_start = performance_monitor.start_operation();
-    match write_schema_file(&output_path, &schema_json) {
-        Ok(()) => {
-            performance_monitor.record_output_size(schema_json.len());
-            performance_monitor.record_io_time(io_start.elapsed());
-            println!("✅ Successfully generated schema file: {output_path}");
-            let schema_size = schema_json.len();
-            println!("📈 Schema size: {:.1} KB", schema_size as f64 / 1000.0);
-        }
+    // Generate code for every language in parallel
+    let code_formats: Vec<String> = formats.iter().filter(|f| *f != "schema").cloned().collect();
+    let language_options = args.get_language_options().unwrap_or_default();
+    let first_code_path = code_formats
+        .first()
+        .map(|format| generate_multi_format_output_path(input_path, output_dir, format, &language_options));
+    let options = build_generation_options(args, first_code_path.as_deref());
+    let mut generated: HashMap<String, String> = match ParallelCodeGenerator::generate_parallel(json_value, &code_formats, &options, performance_monitor) {
+        Ok(generated) => generated.into_iter().collect(),
         Err(e) => {
-            eprintln!("❌ Error writing schema file: {e}");
-            eprintln!("💡 Tip: Check that you have write permissions to the output directory");
+            let mut hints = vec![
+                "Check that your JSON structure is valid",
+                "Try generating each format on its own to find the failing one",
+            ];
+            if args.struct_name.is_some() {
+                hints.push("Verify your --struct-name parameter is valid");
+            }
+            output::report_error(&format!("Error generating code: {e}"), &hints);
+            return Err(e);
+        }
+    };
+
+    // Write every artifact in the order the formats were requested
+    let io_start = performance_monitor.start_operation();
+    for format in formats {
+        let output_path = generate_multi_format_output_path(input_path, output_dir, format, &language_options);
+        let (content, written) = if format == "schema" {
+            let content = schema_json.clone().unwrap_or_default();
+            let written = write_schema_file(&output_path, &content);
+            (content, written)
+        } else {
+            let Some(content) = generated.remove(format) else {
+                output::report_error(&format!("No {format} code was generated"), &[]);
+                return Err(J2sError::codegen_error(format!(
+                    "Code generation produced no output for format '{format}'"
+                )));
+            };
+            let written = write_code_file(&output_path, &content, format);
+            (content, written)
+        };
+
+        if let Err(e) = written {
+            output::report_error(
+                &format!("Error writing {format} file: {e}"),
+                &["Check that you have write permissions to the output directory"],
+            );
             return Err(e);
         }
+        performance_monitor.record_output_size(content.len());
     }
+    performance_monitor.record_write_time(io_start.elapsed());
 
+    status!("✅ Successfully generated {} files from {input_path}", formats.len());
     Ok(())
 }
 
@@ -266,106 +540,139 @@ fn generate_code_output(
     performance_monitor: &mut PerformanceMonitor,
 ) -> Result<()> {
     // Generate output path for code
-    let output_path = generate_code_output_path(input_path, args.output.as_deref(), format);
+    let language_options = args.get_language_options().unwrap_or_default();
+    let output_path = generate_code_output_path(input_path, args.output.as_deref(), format, &language_options);
 
     // Create code generator for the target language
-    println!("🔧 Creating {format} code generator...");
+    status!("🔧 Creating {format} code generator...");
     let generator = match GeneratorFactory::create_generator(format) {
         Ok(generator) => generator,
         Err(e) => {
-            eprintln!("❌ Error creating code generator: {e}");
-            eprintln!("💡 Tip: Supported formats are:");
-            eprintln!("   • go        - Go language structs with JSON tags");
-            eprintln!("   • rust      - Rust structs with serde annotations");
-            eprintln!("   • typescript - TypeScript interfaces");
-            eprintln!("   • python    - Python dataclasses with type hints");
-            eprintln!("   • schema    - JSON Schema (default)");
-            eprintln!("📖 Example: j2s data.json --format go --struct-name User");
+            output::report_error(
+                &format!("Error creating code generator: {e}"),
+                &[
+                    "Supported formats: schema, go, rust, typescript, python, kotlin, swift, java, csharp, dart, zod, proto, graphql, sql, avro, openapi, jtd",
+                    "Example: j2s data.json --format go --struct-name User",
+                ],
+            );
             return Err(e);
         }
     };
 
     // Prepare generation options
-    let struct_name = args.get_struct_name();
-    let options = GenerationOptions::new()
-        .with_struct_name(struct_name)
-        .with_comments(true)
-        .with_optional_fields(true);
+    let to_stdout = writes_to_stdout(args, input_path);
+    let options = build_generation_options(args, (!to_stdout).then_some(output_path.as_str()));
 
     // Validate options with the generator
     if let Err(e) = generator.validate_options(&options) {
-        eprintln!("❌ Invalid generation options: {e}");
-        eprintln!("💡 Tip: Check your --struct-name parameter and other options");
+        output::report_error(
+            &format!("Invalid generation options: {e}"),
+            &["Check your --struct-name and --lang-option parameters"],
+        );
         return Err(e);
     }
 
+    // Write one file per type when requested
+    if args.split_files {
+        return generate_split_code_output(json_value, input_path, args, format, generator.as_ref(), &options, performance_monitor);
+    }
+
     // Generate code with progress indication for large files
     let file_size = json_value.to_string().len();
     if file_size > 100_000 {
-        println!("⚙️  Generating {} code for large file ({:.1} KB)...", 
+        status!("⚙️  Generating {} code for large file ({:.1} KB)...", 
                  generator.language_name(), file_size as f64 / 1000.0);
-        println!("📊 Processing complex JSON structure...");
+        status!("📊 Processing complex JSON structure...");
     } else {
-        println!("⚙️  Generating {} code...", generator.language_name());
+        status!("⚙️  Generating {} code...", generator.language_name());
     }
     
     let generation_start = performance_monitor.start_operation();
     
-    // Check if we should use parallel processing for multiple formats
-    // For now, we generate one format at a time, but the infrastructure is ready
+    // Multi-format runs go through generate_multi_format_output; this path handles one
     let formats = vec![format.to_string()];
     let should_use_parallel = ParallelCodeGenerator::should_use_parallel(&formats, file_size);
     
     if should_use_parallel && file_size > 1_000_000 {
-        println!("🚀 Using optimized processing for large file...");
+        status!("🚀 Using optimized processing for large file...");
     }
     
     let generated_code = match generator.generate(json_value, &options) {
         Ok(code) => {
             performance_monitor.record_generation_time(generation_start.elapsed());
             if file_size > 100_000 {
-                println!("✨ Code generation completed successfully!");
+                status!("✨ Code generation completed successfully!");
             }
             code
         },
         Err(e) => {
-            eprintln!("❌ Error generating {} code: {e}", generator.language_name());
-            eprintln!("💡 Troubleshooting tips:");
-            eprintln!("   • Check that your JSON structure is valid");
-            eprintln!("   • Ensure the JSON is not too deeply nested (max 10 levels)");
-            eprintln!("   • Try with a simpler JSON structure first");
-            eprintln!("   • Check that field names don't contain special characters");
+            let mut hints = vec![
+                "Check that your JSON structure is valid",
+                "Ensure the JSON is not too deeply nested (max 10 levels)",
+                "Try with a simpler JSON structure first",
+                "Check that field names don't contain special characters",
+            ];
             if args.struct_name.is_some() {
-                eprintln!("   • Verify your --struct-name parameter is valid");
+                hints.push("Verify your --struct-name parameter is valid");
             }
+            output::report_error(
+                &format!("Error generating {} code: {e}", generator.language_name()),
+                &hints,
+            );
             return Err(e);
         }
     };
 
+    if to_stdout {
+        let io_start = performance_monitor.start_operation();
+        if let Err(e) = write_code_to_stdout(&generated_code, format) {
+            output::report_error(
+                &format!("Error writing {} code to stdout: {e}", generator.language_name()),
+                &[],
+            );
+            return Err(e);
+        }
+        performance_monitor.record_output_size(generated_code.len());
+        performance_monitor.record_write_time(io_start.elapsed());
+        return Ok(());
+    }
+
     // Write code file
-    println!("💾 Writing {} code to: {output_path}", generator.language_name());
+    status!(
+        "💾 Writing {} code to: {output_path}",
+        generator.language_name()
+    );
     let io_start = performance_monitor.start_operation();
     match write_code_file(&output_path, &generated_code, format) {
         Ok(()) => {
             performance_monitor.record_output_size(generated_code.len());
-            performance_monitor.record_io_time(io_start.elapsed());
-            println!("✅ Successfully generated {} file: {output_path}", generator.language_name());
+            performance_monitor.record_write_time(io_start.elapsed());
+            status!(
+                "✅ Successfully generated {} file: {output_path}",
+                generator.language_name()
+            );
             let code_size = generated_code.len();
-            println!("📈 Code size: {:.1} KB", code_size as f64 / 1000.0);
-            
+            status!("📈 Code size: {:.1} KB", code_size as f64 / 1000.0);
+
             // Provide language-specific usage hints
-            print_usage_hints(format, &output_path);
+            report_usage_hints(format, &output_path);
         }
         Err(e) => {
-            eprintln!("❌ Error writing {} file: {e}", generator.language_name());
-            eprintln!("💡 Troubleshooting tips:");
-            eprintln!("   • Check that you have write permissions to the output directory");
-            eprintln!("   • Ensure the output directory exists");
-            eprintln!("   • Verify there's enough disk space available");
-            eprintln!("   • Check if the file is currently open in another application");
-            if let Some(output) = &args.output {
-                eprintln!("   • Verify the output path is valid: {output}");
-            }
+            let path_hint = args
+                .output
+                .as_ref()
+                .map(|output| format!("Verify the output path is valid: {output}"));
+            let mut hints = vec![
+                "Check that you have write permissions to the output directory",
+                "Ensure the output directory exists",
+                "Verify there's enough disk space available",
+                "Check if the file is currently open in another application",
+            ];
+            hints.extend(path_hint.as_deref());
+            output::report_error(
+                &format!("Error writing {} file: {e}", generator.language_name()),
+                &hints,
+            );
             return Err(e);
         }
     }
@@ -373,33 +680,209 @@ fn generate_code_output(
     Ok(())
 }
 
-/// Print language-specific usage hints for the generated code
-fn print_usage_hints(format: &str, output_path: &str) {
-    match format {
-        "go" => {
-            println!("💡 Usage hints for Go:");
-            println!("   • Add to your Go module: go mod tidy");
-            println!("   • Import in your code: import \"encoding/json\"");
-            println!("   • Use json.Unmarshal() to parse JSON into your struct");
-        }
-        "rust" => {
-            println!("💡 Usage hints for Rust:");
-            println!("   • Add to Cargo.toml: serde = {{ version = \"1.0\", features = [\"derive\"] }}");
-            println!("   • Add to Cargo.toml: serde_json = \"1.0\"");
-            println!("   • Use serde_json::from_str() to parse JSON into your struct");
+/// Generate code split into one file per type and write every file to the output directory
+///
+/// `--output` names the directory; without it the files are written next to the input.
+fn generate_split_code_output(
+    json_value: &serde_json::Value,
+    input_path: &str,
+    args: &cli::CliArgs,
+    format: &str,
+    generator: &dyn CodeGenerator,
+    options: &GenerationOptions,
+    performance_monitor: &mut PerformanceMonitor,
+) -> Result<()> {
+    if writes_to_stdout(args, input_path) {
+        output::report_error(
+            "Cannot write split files to stdout",
+            &["Use --output <directory> to write one file per type"],
+        );
+        return Err(J2sError::argument_error(
+            "--split-files cannot be written to stdout",
+        ));
+    }
+
+    let output_dir = args.output.as_deref();
+    if let Some(dir) = output_dir {
+        if Path::new(dir).is_file() {
+            output::report_error(
+                &format!("Output path is an existing file: {dir}"),
+                &["With --split-files, --output must name a directory"],
+            );
+            return Err(J2sError::argument_error(format!(
+                "Output path '{dir}' must be a directory when splitting files"
+            )));
         }
-        "typescript" => {
-            println!("💡 Usage hints for TypeScript:");
-            println!("   • Import in your code: import {{ YourInterface }} from './{}'", 
-                     std::path::Path::new(output_path).file_stem().unwrap().to_str().unwrap());
-            println!("   • Use JSON.parse() with type assertion: JSON.parse(data) as YourInterface");
+        status!("📁 Writing outputs to directory: {dir}");
+    }
+
+    status!("⚙️  Generating {} code...", generator.language_name());
+    let generation_start = performance_monitor.start_operation();
+    let files = match generator.generate_files(json_value, options) {
+        Ok(files) => files,
+        Err(e) => {
+            output::report_error(
+                &format!("Error generating {} code: {e}", generator.language_name()),
+                &["Check that your JSON structure is valid"],
+            );
+            return Err(e);
         }
-        "python" => {
-            println!("💡 Usage hints for Python:");
-            println!("   • Import in your code: from {} import YourClass", 
-                     std::path::Path::new(output_path).file_stem().unwrap().to_str().unwrap());
-            println!("   • Use json.loads() and create instance: YourClass(**json.loads(data))");
+    };
+    performance_monitor.record_generation_time(generation_start.elapsed());
+
+    let io_start = performance_monitor.start_operation();
+    for (file_name, content) in &files {
+        let output_path = generate_split_output_path(input_path, output_dir, file_name);
+        if let Err(e) = write_code_file(&output_path, content, format) {
+            output::report_error(
+                &format!("Error writing {} file: {e}", generator.language_name()),
+                &["Check that you have write permissions to the output directory"],
+            );
+            return Err(e);
         }
-        _ => {}
+        performance_monitor.record_output_size(content.len());
     }
+    performance_monitor.record_write_time(io_start.elapsed());
+
+    status!(
+        "✅ Successfully generated {} {} files from {input_path}",
+        files.len(),
+        generator.language_name()
+    );
+    Ok(())
+}
+
+/// Report language-specific usage hints for the generated code
+fn report_usage_hints(format: &str, output_path: &str) {
+    let module = Path::new(output_path).file_stem().and_then(|stem| stem.to_str()).unwrap_or_default();
+    let typescript_import = format!("Import in your code: import {{ YourInterface }} from './{module}'");
+    let python_import = format!("Import in your code: from {module} import YourClass");
+    let (topic, hints): (&str, &[&str]) = match format {
+        "go" => (
+            "Go",
+            &[
+                "Add to your Go module: go mod tidy",
+                "Import in your code: import \"encoding/json\"",
+                "Use json.Unmarshal() to parse JSON into your struct",
+            ],
+        ),
+        "rust" => (
+            "Rust",
+            &[
+                "Add to Cargo.toml: serde = { version = \"1.0\", features = [\"derive\"] }",
+                "Add to Cargo.toml: serde_json = \"1.0\"",
+                "Use serde_json::from_str() to parse JSON into your struct",
+                "Pass --lang-option rust_derives=Default,PartialEq to derive more traits, or rust_deny_unknown_fields=true to reject unknown keys",
+                "Pass --lang-option rust_borrow=cow for zero-copy structs that borrow strings from the input",
+            ],
+        ),
+        "typescript" => (
+            "TypeScript",
+            &[
+                &typescript_import,
+                "Use JSON.parse() with type assertion: JSON.parse(data) as YourInterface",
+                "Pass --lang-option ts_optional=optional under exactOptionalPropertyTypes, or ts_enums=true for literal unions",
+                "Pass --lang-option ts_guards=true to validate parsed data with generated isYourInterface(v) guards",
+            ],
+        ),
+        "python" => (
+            "Python",
+            &[
+                &python_import,
+                "Use json.loads() and create instance: YourClass(**json.loads(data))",
+                "For validated nested models use --lang-option python_style=pydantic and YourClass.model_validate_json(data)",
+            ],
+        ),
+        "kotlin" => (
+            "Kotlin",
+            &[
+                "Apply the plugin: id(\"org.jetbrains.kotlin.plugin.serialization\")",
+                "Add the dependency: org.jetbrains.kotlinx:kotlinx-serialization-json",
+                "Use Json.decodeFromString<YourClass>(data) to parse JSON into your class",
+            ],
+        ),
+        "swift" => (
+            "Swift",
+            &[
+                "Add the file to your target; Codable needs only the standard library",
+                "Use JSONDecoder().decode(YourStruct.self, from: data) to parse JSON into your struct",
+            ],
+        ),
+        "java" => (
+            "Java",
+            &[
+                "Add the dependency: com.fasterxml.jackson.core:jackson-databind",
+                "Use --lang-option java_style=pojo for Java 8 and --split-files for one file per type",
+                "Use new ObjectMapper().readValue(data, YourType.class) to parse JSON into your type",
+            ],
+        ),
+        "csharp" => (
+            "C#",
+            &[
+                "System.Text.Json ships with .NET; `required` members need C# 11 (.NET 7+)",
+                "Use --lang-option csharp_style=class for mutable classes and --lang-option namespace=My.App",
+                "Use JsonSerializer.Deserialize<YourType>(data) to parse JSON into your type",
+            ],
+        ),
+        "dart" => (
+            "Dart",
+            &[
+                "Add dependencies: dart pub add json_annotation dev:build_runner dev:json_serializable",
+                "Generate the part file: dart run build_runner build",
+                "Use YourClass.fromJson(jsonDecode(data)) to parse JSON into your class",
+            ],
+        ),
+        "zod" => (
+            "zod",
+            &[
+                "Add the dependency: npm install zod",
+                "Use YourTypeSchema.parse(JSON.parse(data)) to validate JSON and get a typed value",
+            ],
+        ),
+        "proto" => (
+            "Protocol Buffers",
+            &[
+                "Compile the schema with protoc or buf to generate gRPC code",
+                "Pass --lang-option existing_proto=<file> to keep field numbers from an earlier schema",
+            ],
+        ),
+        "graphql" => (
+            "GraphQL",
+            &[
+                "Register a JSON scalar (e.g. from graphql-scalars) if the schema declares one",
+                "Pass --lang-option graphql_input=true to also get input types for mutations",
+            ],
+        ),
+        "sql" => (
+            "SQL",
+            &[
+                "Pass an array of records so every record contributes to the column types",
+                "Pass --lang-option sql_nested=table to store nested objects in child tables",
+                "SQLite only enforces foreign keys after PRAGMA foreign_keys = ON",
+            ],
+        ),
+        "avro" => (
+            "Avro",
+            &[
+                "Register the schema with your schema registry before producing to the topic",
+                "Pass --lang-option namespace=<name> to set the record namespace",
+            ],
+        ),
+        "openapi" => (
+            "OpenAPI",
+            &[
+                "Pass --lang-option openapi_response=<Name> to add a named response returning the sample",
+                "Pass --lang-option openapi_responses=<dir> to add a response for every captured .json sample",
+            ],
+        ),
+        "jtd" => (
+            "JTD",
+            &[
+                "Pass an array of samples so optional properties, enums and tagged unions can be told apart",
+                "Use --lang-option jtd_enum_max=0 to keep every string a plain string",
+            ],
+        ),
+        _ => return,
+    };
+    output::report(Event::Hints { topic, hints });
 }
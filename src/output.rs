@@ -0,0 +1,460 @@
+//! # Status Output and Reporting
+//!
+//! Everything j2s tells the user while it works — progress lines, warnings, errors,
+//! usage hints, phase timings, the paths of written artifacts and the final result — is
+//! sent as an [`Event`] to the active [`Reporter`] instead of being printed directly.
+//! Three reporters are provided:
+//!
+//! - [`HumanReporter`]: emoji progress lines for a terminal (the default)
+//! - [`SilentReporter`]: reports nothing but errors
+//! - [`JsonLinesReporter`]: one JSON object per event, for CI logs and wrapping tools
+//!
+//! Library code reports through the `status!`, `detail!` and `warning!` macros. Reports
+//! normally go to stdout; when the generated artifact itself is written to stdout
+//! (`-o -`), they are redirected to stderr so that stdout carries only the artifact.
+
+use crate::performance::PerformanceMetrics;
+use serde_json::json;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+/// Runs taking longer than this show the performance summary even without --verbose
+const SLOW_RUN: Duration = Duration::from_secs(1);
+
+/// Whether status messages are currently sent to stderr
+static STATUS_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+/// The reporter receiving all events; `None` means the default human reporter
+static REPORTER: RwLock<Option<Arc<dyn Reporter>>> = RwLock::new(None);
+
+/// Something worth telling the user about while processing
+#[derive(Debug, Clone, Copy)]
+pub enum Event<'a> {
+    /// A progress message
+    Progress { message: &'a str },
+    /// Extra detail that is only shown in verbose mode
+    Detail { message: &'a str },
+    /// A processing phase (read, parse, analyze, generate, write, total) finished
+    Phase { phase: &'a str, duration: Duration },
+    /// A problem that does not stop processing
+    Warning { message: &'a str },
+    /// A problem that stops processing, with suggestions for fixing it
+    Error {
+        message: &'a str,
+        hints: &'a [&'a str],
+    },
+    /// Suggestions for using the generated code of one language
+    Hints {
+        topic: &'a str,
+        hints: &'a [&'a str],
+    },
+    /// An artifact was written (`path` is `-` for stdout)
+    Output {
+        format: &'a str,
+        path: &'a str,
+        bytes: usize,
+    },
+    /// Processing finished, successfully or not
+    Result {
+        success: bool,
+        metrics: &'a PerformanceMetrics,
+    },
+}
+
+impl Event<'_> {
+    /// Name of the event kind, as used in the `event` field of JSON output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Event::Progress { .. } => "progress",
+            Event::Detail { .. } => "detail",
+            Event::Phase { .. } => "phase",
+            Event::Warning { .. } => "warning",
+            Event::Error { .. } => "error",
+            Event::Hints { .. } => "hints",
+            Event::Output { .. } => "output",
+            Event::Result { .. } => "result",
+        }
+    }
+
+    /// Encode the event as a JSON object
+    pub fn to_json(self) -> serde_json::Value {
+        match self {
+            Event::Progress { message }
+            | Event::Detail { message }
+            | Event::Warning { message } => {
+                json!({ "event": self.kind(), "message": message })
+            }
+            Event::Error { message, hints } => json!({
+                "event": self.kind(),
+                "message": message,
+                "hints": hints,
+            }),
+            Event::Hints { topic, hints } => json!({
+                "event": self.kind(),
+                "topic": topic,
+                "hints": hints,
+            }),
+            Event::Phase { phase, duration } => json!({
+                "event": self.kind(),
+                "phase": phase,
+                "duration_ms": duration.as_secs_f64() * 1000.0,
+            }),
+            Event::Output {
+                format,
+                path,
+                bytes,
+            } => json!({
+                "event": self.kind(),
+                "format": format,
+                "path": path,
+                "bytes": bytes,
+            }),
+            Event::Result { success, metrics } => json!({
+                "event": self.kind(),
+                "success": success,
+                "duration_ms": metrics.total_time.as_secs_f64() * 1000.0,
+                "input_bytes": metrics.input_size,
+                "output_bytes": metrics.output_size,
+            }),
+        }
+    }
+}
+
+/// Receiver for the events produced while processing
+pub trait Reporter: Send + Sync {
+    /// Handle a single event
+    fn report(&self, event: &Event<'_>);
+}
+
+/// Reporter that discards every event except errors, which are printed to stderr
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SilentReporter;
+
+impl Reporter for SilentReporter {
+    fn report(&self, event: &Event<'_>) {
+        if let Event::Error { .. } = event {
+            HumanReporter::default().report(event);
+        }
+    }
+}
+
+/// Reporter that prints emoji progress lines for people watching a terminal
+///
+/// Progress, hint and output events are always shown; details and phase timings only
+/// in verbose mode, and the performance summary in verbose mode or after a slow run.
+/// Warnings and errors are printed to stderr.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HumanReporter {
+    verbose: bool,
+}
+
+impl HumanReporter {
+    /// Create a human reporter, optionally showing details and phase timings
+    pub fn new(verbose: bool) -> Self {
+        Self { verbose }
+    }
+
+    /// Render an event as the line to print, or `None` if it is not shown
+    fn render(&self, event: &Event<'_>) -> Option<String> {
+        match event {
+            Event::Progress { message } => Some(message.to_string()),
+            Event::Detail { message } if self.verbose => Some(message.to_string()),
+            Event::Phase { phase, duration } if self.verbose => Some(format!(
+                "   ⏱️  {phase}: {:.2}ms",
+                duration.as_secs_f64() * 1000.0
+            )),
+            Event::Warning { message } => Some(format!("⚠️  Warning: {message}")),
+            Event::Error { message, hints } => {
+                let mut lines = vec![format!("❌ {message}")];
+                match hints {
+                    [] => {}
+                    [hint] => lines.push(format!("💡 Tip: {hint}")),
+                    _ => {
+                        lines.push("💡 Troubleshooting tips:".to_string());
+                        lines.extend(hints.iter().map(|hint| format!("   • {hint}")));
+                    }
+                }
+                Some(lines.join("\n"))
+            }
+            Event::Hints { topic, hints } => {
+                let mut lines = vec![format!("💡 Usage hints for {topic}:")];
+                lines.extend(hints.iter().map(|hint| format!("   • {hint}")));
+                Some(lines.join("\n"))
+            }
+            Event::Output {
+                format: "schema",
+                path,
+                bytes,
+            } => Some(format!("✅ Generated schema: {path} ({bytes} bytes)")),
+            Event::Output {
+                format,
+                path,
+                bytes,
+            } => Some(format!(
+                "✅ Generated {format} code: {path} ({bytes} bytes)"
+            )),
+            Event::Result { metrics, .. }
+                if self.verbose || metrics.total_time > SLOW_RUN =>
+            {
+                Some(metrics.summary())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Reporter for HumanReporter {
+    fn report(&self, event: &Event<'_>) {
+        if let Some(line) = self.render(event) {
+            if matches!(event, Event::Warning { .. } | Event::Error { .. }) {
+                eprintln!("{line}");
+            } else {
+                write_status_line(&line);
+            }
+        }
+    }
+}
+
+/// Reporter that prints every event as one line of JSON
+#[derive(Debug, Default, Clone, Copy)]
+pub struct JsonLinesReporter;
+
+impl Reporter for JsonLinesReporter {
+    fn report(&self, event: &Event<'_>) {
+        write_status_line(&event.to_json().to_string());
+    }
+}
+
+/// Install the reporter that receives all subsequent events
+pub fn set_reporter(reporter: Arc<dyn Reporter>) {
+    let mut current = REPORTER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *current = Some(reporter);
+}
+
+/// Send an event to the active reporter
+pub fn report(event: Event<'_>) {
+    let current = REPORTER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    match current.as_ref() {
+        Some(reporter) => reporter.report(&event),
+        None => HumanReporter::default().report(&event),
+    }
+}
+
+/// Send status messages to stderr (`true`) or stdout (`false`)
+pub fn set_status_to_stderr(enabled: bool) {
+    STATUS_TO_STDERR.store(enabled, Ordering::Relaxed);
+}
+
+/// Check whether status messages are currently sent to stderr
+pub fn status_to_stderr() -> bool {
+    STATUS_TO_STDERR.load(Ordering::Relaxed)
+}
+
+/// Write one line to the current status stream (stdout, or stderr when stdout carries an artifact)
+pub fn write_status_line(line: &str) {
+    if status_to_stderr() {
+        eprintln!("{line}");
+    } else {
+        println!("{line}");
+    }
+}
+
+/// Report an error that stops processing, with suggestions for fixing it
+pub fn report_error(message: &str, hints: &[&str]) {
+    report(Event::Error { message, hints });
+}
+
+/// Report a formatted event of the given kind; used by the reporting macros
+pub fn report_fmt(kind: fn(&str) -> Event<'_>, args: fmt::Arguments<'_>) {
+    let message = args.to_string();
+    report(kind(&message));
+}
+
+/// Report a progress message, formatted like `println!`
+macro_rules! status {
+    () => {
+        $crate::output::report_fmt(|message| $crate::output::Event::Progress { message }, format_args!(""))
+    };
+    ($($arg:tt)*) => {
+        $crate::output::report_fmt(|message| $crate::output::Event::Progress { message }, format_args!($($arg)*))
+    };
+}
+
+/// Report a detail that is only shown in verbose mode, formatted like `println!`
+macro_rules! detail {
+    ($($arg:tt)*) => {
+        $crate::output::report_fmt(|message| $crate::output::Event::Detail { message }, format_args!($($arg)*))
+    };
+}
+
+/// Report a warning, formatted like `println!`
+macro_rules! warning {
+    ($($arg:tt)*) => {
+        $crate::output::report_fmt(|message| $crate::output::Event::Warning { message }, format_args!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status_stream_toggle() {
+        let original = status_to_stderr();
+
+        set_status_to_stderr(true);
+        assert!(status_to_stderr());
+
+        set_status_to_stderr(false);
+        assert!(!status_to_stderr());
+
+        set_status_to_stderr(original);
+    }
+
+    #[test]
+    fn test_event_to_json() {
+        let event = Event::Phase {
+            phase: "parse",
+            duration: Duration::from_millis(12),
+        };
+        let value = event.to_json();
+        assert_eq!(value["event"], "phase");
+        assert_eq!(value["phase"], "parse");
+        assert_eq!(value["duration_ms"], 12.0);
+
+        let event = Event::Output {
+            format: "go",
+            path: "out/data.go",
+            bytes: 120,
+        };
+        assert_eq!(
+            event.to_json().to_string(),
+            r#"{"bytes":120,"event":"output","format":"go","path":"out/data.go"}"#
+        );
+
+        let event = Event::Warning { message: "careful" };
+        assert_eq!(event.to_json()["event"], "warning");
+        assert_eq!(event.to_json()["message"], "careful");
+
+        let event = Event::Error {
+            message: "Error parsing JSON",
+            hints: &["Check the syntax"],
+        };
+        assert_eq!(
+            event.to_json().to_string(),
+            r#"{"event":"error","hints":["Check the syntax"],"message":"Error parsing JSON"}"#
+        );
+
+        let event = Event::Hints {
+            topic: "Go",
+            hints: &["go mod tidy"],
+        };
+        assert_eq!(
+            event.to_json().to_string(),
+            r#"{"event":"hints","hints":["go mod tidy"],"topic":"Go"}"#
+        );
+
+        let metrics = PerformanceMetrics {
+            total_time: Duration::from_millis(3),
+            input_size: 10,
+            output_size: 20,
+            ..PerformanceMetrics::default()
+        };
+        let value = Event::Result {
+            success: false,
+            metrics: &metrics,
+        }
+        .to_json();
+        assert_eq!(value["event"], "result");
+        assert_eq!(value["success"], false);
+        assert_eq!(value["duration_ms"], 3.0);
+        assert_eq!(value["input_bytes"], 10);
+        assert_eq!(value["output_bytes"], 20);
+    }
+
+    #[test]
+    fn test_human_reporter_errors_and_hints() {
+        let reporter = HumanReporter::new(false);
+        assert_eq!(
+            reporter.render(&Event::Error {
+                message: "Error reading stdin",
+                hints: &["Pipe JSON into j2s"],
+            }),
+            Some("❌ Error reading stdin\n💡 Tip: Pipe JSON into j2s".to_string())
+        );
+        assert_eq!(
+            reporter.render(&Event::Error {
+                message: "Error generating code",
+                hints: &["Check the JSON", "Try a smaller file"],
+            }),
+            Some(
+                "❌ Error generating code\n💡 Troubleshooting tips:\n   • Check the JSON\n   • Try a smaller file"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            reporter.render(&Event::Hints {
+                topic: "Go",
+                hints: &["go mod tidy"],
+            }),
+            Some("💡 Usage hints for Go:\n   • go mod tidy".to_string())
+        );
+
+        // The performance summary is only shown for verbose or slow runs
+        let fast = PerformanceMetrics::default();
+        let slow = PerformanceMetrics {
+            total_time: Duration::from_secs(2),
+            ..PerformanceMetrics::default()
+        };
+        let result = |metrics| Event::Result {
+            success: true,
+            metrics,
+        };
+        assert_eq!(reporter.render(&result(&fast)), None);
+        assert!(reporter.render(&result(&slow)).unwrap().starts_with("📊 Performance Summary:"));
+        assert!(HumanReporter::new(true).render(&result(&fast)).is_some());
+    }
+
+    #[test]
+    fn test_human_reporter_verbosity() {
+        let quiet = HumanReporter::new(false);
+        let verbose = HumanReporter::new(true);
+        let detail = Event::Detail {
+            message: "depth: 3",
+        };
+        let phase = Event::Phase {
+            phase: "generate",
+            duration: Duration::from_millis(5),
+        };
+
+        assert_eq!(quiet.render(&detail), None);
+        assert_eq!(quiet.render(&phase), None);
+        assert_eq!(verbose.render(&detail), Some("depth: 3".to_string()));
+        assert_eq!(
+            verbose.render(&phase),
+            Some("   ⏱️  generate: 5.00ms".to_string())
+        );
+
+        let output = Event::Output {
+            format: "rust",
+            path: "data.rs",
+            bytes: 42,
+        };
+        assert_eq!(
+            quiet.render(&output),
+            Some("✅ Generated rust code: data.rs (42 bytes)".to_string())
+        );
+        assert_eq!(
+            quiet.render(&Event::Warning {
+                message: "large file"
+            }),
+            Some("⚠️  Warning: large file".to_string())
+        );
+    }
+}
@@ -5,6 +5,7 @@
 //! monitoring capabilities.
 
 use crate::error::{J2sError, Result};
+use crate::output::{self, Event};
 use rayon::prelude::*;
 use serde_json::Value;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -56,23 +57,26 @@ impl PerformanceMetrics {
         Self::default()
     }
 
-    /// Print a performance summary to stdout
-    pub fn print_summary(&self) {
-        println!("📊 Performance Summary:");
-        println!("   ⏱️  Total time: {:.2}ms", self.total_time.as_millis());
-        println!("   📖 Parse time: {:.2}ms", self.parse_time.as_millis());
-        println!("   ⚙️  Generation time: {:.2}ms", self.generation_time.as_millis());
-        println!("   💾 I/O time: {:.2}ms", self.io_time.as_millis());
-        println!("   🧠 Peak memory: {:.1} MB", self.peak_memory_usage as f64 / 1_000_000.0);
-        println!("   📦 Objects processed: {}", self.objects_processed);
-        println!("   🏷️  Fields processed: {}", self.fields_processed);
-        println!("   📏 Input size: {:.1} KB", self.input_size as f64 / 1000.0);
-        println!("   📄 Output size: {:.1} KB", self.output_size as f64 / 1000.0);
+    /// Render the performance summary shown at the end of a slow or verbose run
+    pub fn summary(&self) -> String {
+        let mut lines = vec![
+            "📊 Performance Summary:".to_string(),
+            format!("   ⏱️  Total time: {:.2}ms", self.total_time.as_millis()),
+            format!("   📖 Parse time: {:.2}ms", self.parse_time.as_millis()),
+            format!("   ⚙️  Generation time: {:.2}ms", self.generation_time.as_millis()),
+            format!("   💾 I/O time: {:.2}ms", self.io_time.as_millis()),
+            format!("   🧠 Peak memory: {:.1} MB", self.peak_memory_usage as f64 / 1_000_000.0),
+            format!("   📦 Objects processed: {}", self.objects_processed),
+            format!("   🏷️  Fields processed: {}", self.fields_processed),
+            format!("   📏 Input size: {:.1} KB", self.input_size as f64 / 1000.0),
+            format!("   📄 Output size: {:.1} KB", self.output_size as f64 / 1000.0),
+        ];
         
         if self.total_time.as_millis() > 0 {
             let throughput = (self.input_size as f64 / 1000.0) / (self.total_time.as_secs_f64());
-            println!("   🚀 Throughput: {throughput:.1} KB/s");
+            lines.push(format!("   🚀 Throughput: {throughput:.1} KB/s"));
         }
+        lines.join("\n")
     }
 
     /// Check if performance is within acceptable bounds
@@ -114,19 +118,40 @@ impl PerformanceMonitor {
         Instant::now()
     }
 
-    /// Record parse time
+    /// Record parse time and report the finished "parse" phase
     pub fn record_parse_time(&mut self, duration: Duration) {
         self.metrics.parse_time = duration;
+        output::report(Event::Phase {
+            phase: "parse",
+            duration,
+        });
     }
 
-    /// Record generation time
+    /// Record generation time and report the finished "generate" phase
     pub fn record_generation_time(&mut self, duration: Duration) {
         self.metrics.generation_time = duration;
+        output::report(Event::Phase {
+            phase: "generate",
+            duration,
+        });
+    }
+
+    /// Add input reading to the I/O time and report the finished "read" phase
+    pub fn record_read_time(&mut self, duration: Duration) {
+        self.metrics.io_time += duration;
+        output::report(Event::Phase {
+            phase: "read",
+            duration,
+        });
     }
 
-    /// Record I/O time
-    pub fn record_io_time(&mut self, duration: Duration) {
-        self.metrics.io_time = duration;
+    /// Add output writing to the I/O time and report the finished "write" phase
+    pub fn record_write_time(&mut self, duration: Duration) {
+        self.metrics.io_time += duration;
+        output::report(Event::Phase {
+            phase: "write",
+            duration,
+        });
     }
 
     /// Update memory usage tracking
@@ -211,7 +236,7 @@ impl StreamingJsonProcessor {
 
         // Parse JSON with progress indication for large files
         let json_value = if content.len() > 10_000_000 {
-            println!("📊 Processing large JSON file ({:.1} MB)...", content.len() as f64 / 1_000_000.0);
+            status!("📊 Processing large JSON file ({:.1} MB)...", content.len() as f64 / 1_000_000.0);
             self.parse_with_progress(content)?
         } else {
             serde_json::from_str(content).map_err(|e| {
@@ -227,13 +252,13 @@ impl StreamingJsonProcessor {
     fn parse_with_progress(&self, content: &str) -> Result<Value> {
         // For now, we use the standard parser but with progress indication
         // In a more advanced implementation, we could use a streaming JSON parser
-        println!("   📊 Progress: 25% - Starting JSON parsing...");
+        status!("   📊 Progress: 25% - Starting JSON parsing...");
         
         let result = serde_json::from_str(content).map_err(|e| {
             J2sError::json_error(format!("Failed to parse large JSON: {e}"))
         });
 
-        println!("   📊 Progress: 100% - JSON parsing complete");
+        status!("   📊 Progress: 100% - JSON parsing complete");
         result
     }
 }
@@ -255,14 +280,17 @@ impl ParallelCodeGenerator {
             return Ok(Vec::new());
         }
 
-        println!("🔧 Generating code for {} formats in parallel...", formats.len());
+        status!("🔧 Generating code for {} formats in parallel...", formats.len());
+
+        // Infer the intermediate representation once and share it between the formats
+        let ir = crate::codegen::types::SharedIr::new(json_value, options.sampling.clone());
 
         // Use rayon for parallel processing
         let results: Result<Vec<_>> = formats
             .par_iter()
             .map(|format| {
                 let generator = crate::codegen::factory::GeneratorFactory::create_generator(format)?;
-                let code = generator.generate(json_value, options)?;
+                let code = generator.generate_with_ir(&ir, options)?;
                 Ok((format.clone(), code))
             })
             .collect();
@@ -270,7 +298,7 @@ impl ParallelCodeGenerator {
         let generation_results = results?;
         monitor.record_generation_time(start_time.elapsed());
 
-        println!("✅ Parallel code generation completed for {} formats", formats.len());
+        status!("✅ Parallel code generation completed for {} formats", formats.len());
         Ok(generation_results)
     }
 
@@ -283,6 +311,224 @@ impl ParallelCodeGenerator {
     }
 }
 
+/// Arrays with more elements than this are sampled instead of fully scanned
+pub const LARGE_ARRAY_THRESHOLD: usize = 10_000;
+
+/// Default number of elements inspected when sampling a large array
+pub const DEFAULT_SAMPLE_SIZE: usize = 1000;
+
+/// Arrays with at least this many inspected elements are analyzed across threads
+pub const PARALLEL_ARRAY_THRESHOLD: usize = 2_000;
+
+/// Strategy used to pick which elements of a large array are inspected during inference
+///
+/// Sampling only applies to arrays with more than `LARGE_ARRAY_THRESHOLD` elements;
+/// smaller arrays are always scanned in full. The same strategy is shared by the schema
+/// generator and `JsonToIrConverter` so both outputs are inferred from the same elements.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SamplingStrategy {
+    /// Inspect every element, regardless of array size
+    Full,
+    /// Inspect the first `size` elements
+    Head { size: usize },
+    /// Inspect `size` elements spread evenly across the whole array
+    Stride { size: usize },
+    /// Inspect `size` elements chosen uniformly at random, reproducible through `seed`
+    Reservoir { size: usize, seed: u64 },
+}
+
+impl Default for SamplingStrategy {
+    fn default() -> Self {
+        SamplingStrategy::Head {
+            size: DEFAULT_SAMPLE_SIZE,
+        }
+    }
+}
+
+impl SamplingStrategy {
+    /// Parse a strategy from its command-line form
+    ///
+    /// Accepted forms are `full`, `head[:SIZE]`, `stride[:SIZE]` and
+    /// `reservoir[:SIZE[:SEED]]`. The size defaults to `DEFAULT_SAMPLE_SIZE` and the
+    /// seed defaults to 0.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut parts = spec.trim().split(':');
+        let name = parts.next().unwrap_or_default().to_lowercase();
+        let size = match parts.next() {
+            Some(raw) => raw.parse::<usize>().map_err(|_| {
+                J2sError::argument_error(format!("Invalid sample size '{raw}' in '{spec}'"))
+            })?,
+            None => DEFAULT_SAMPLE_SIZE,
+        };
+        if size == 0 {
+            return Err(J2sError::argument_error(format!(
+                "Sample size must be greater than zero in '{spec}'"
+            )));
+        }
+
+        let strategy = match name.as_str() {
+            "full" => SamplingStrategy::Full,
+            "head" => SamplingStrategy::Head { size },
+            "stride" => SamplingStrategy::Stride { size },
+            "reservoir" => {
+                let seed = match parts.next() {
+                    Some(raw) => raw.parse::<u64>().map_err(|_| {
+                        J2sError::argument_error(format!("Invalid sampling seed '{raw}' in '{spec}'"))
+                    })?,
+                    None => 0,
+                };
+                SamplingStrategy::Reservoir { size, seed }
+            }
+            _ => {
+                return Err(J2sError::argument_error(format!(
+                    "Unknown sampling strategy '{spec}'. Supported strategies: full, head[:SIZE], stride[:SIZE], reservoir[:SIZE[:SEED]]"
+                )));
+            }
+        };
+
+        if parts.next().is_some() || (strategy == SamplingStrategy::Full && spec.contains(':')) {
+            return Err(J2sError::argument_error(format!(
+                "Too many parameters for sampling strategy '{spec}'"
+            )));
+        }
+
+        Ok(strategy)
+    }
+
+    /// Check whether an array of `len` elements will be sampled rather than fully scanned
+    pub fn should_sample(&self, len: usize) -> bool {
+        len > LARGE_ARRAY_THRESHOLD && self.sample_size(len) < len
+    }
+
+    /// Get the number of elements inspected for an array of `len` elements
+    pub fn sample_size(&self, len: usize) -> usize {
+        match self {
+            SamplingStrategy::Full => len,
+            _ if len <= LARGE_ARRAY_THRESHOLD => len,
+            SamplingStrategy::Head { size }
+            | SamplingStrategy::Stride { size }
+            | SamplingStrategy::Reservoir { size, .. } => (*size).min(len),
+        }
+    }
+
+    /// Select the indices to inspect for an array of `len` elements
+    ///
+    /// Indices are returned in ascending order so that inference visits elements in the
+    /// same order as a full scan would.
+    pub fn select_indices(&self, len: usize) -> Vec<usize> {
+        if !self.should_sample(len) {
+            return (0..len).collect();
+        }
+
+        let size = self.sample_size(len);
+        match self {
+            SamplingStrategy::Full | SamplingStrategy::Head { .. } => (0..size).collect(),
+            SamplingStrategy::Stride { .. } => (0..size)
+                .map(|i| (i as u128 * len as u128 / size as u128) as usize)
+                .collect(),
+            SamplingStrategy::Reservoir { seed, .. } => {
+                // Algorithm R with a seeded SplitMix64 generator for reproducible samples
+                let mut state = *seed;
+                let mut next = move || {
+                    state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+                    let mut z = state;
+                    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+                    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+                    z ^ (z >> 31)
+                };
+
+                let mut reservoir: Vec<usize> = (0..size).collect();
+                for index in size..len {
+                    let slot = (next() % (index as u64 + 1)) as usize;
+                    if slot < size {
+                        reservoir[slot] = index;
+                    }
+                }
+                reservoir.sort_unstable();
+                reservoir
+            }
+        }
+    }
+
+    /// Select the elements to inspect from a slice
+    pub fn sample<'a, T>(&self, items: &'a [T]) -> Vec<&'a T> {
+        if !self.should_sample(items.len()) {
+            return items.iter().collect();
+        }
+        self.select_indices(items.len())
+            .into_iter()
+            .map(|index| &items[index])
+            .collect()
+    }
+
+    /// Describe how an array of `len` elements was sampled, for use in generated output
+    pub fn describe_sample(&self, len: usize) -> String {
+        format!(
+            "Inferred from {} of {len} items ({self} sampling)",
+            self.sample_size(len)
+        )
+    }
+}
+
+impl std::fmt::Display for SamplingStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SamplingStrategy::Full => write!(f, "full"),
+            SamplingStrategy::Head { size } => write!(f, "head:{size}"),
+            SamplingStrategy::Stride { size } => write!(f, "stride:{size}"),
+            SamplingStrategy::Reservoir { size, seed } => write!(f, "reservoir:{size}:{seed}"),
+        }
+    }
+}
+
+/// Ordered set of the distinct shapes found in a run of array elements
+///
+/// Each shape is identified by a key; only the first occurrence of a key is kept, and
+/// at most `limit` shapes are collected. Summaries of consecutive chunks of an array can
+/// be combined with `merge`, which is associative, so a parallel reduction produces the
+/// same shapes in the same order as a sequential scan.
+#[derive(Debug, Clone)]
+pub struct ShapeSummary<T> {
+    shapes: Vec<(String, T)>,
+    limit: usize,
+}
+
+impl<T> ShapeSummary<T> {
+    /// Create an empty summary that keeps at most `limit` distinct shapes
+    pub fn new(limit: usize) -> Self {
+        Self {
+            shapes: Vec::new(),
+            limit,
+        }
+    }
+
+    /// Record a shape unless its key was already seen or the summary is full
+    pub fn insert(&mut self, key: String, shape: T) {
+        if self.is_full() || self.shapes.iter().any(|(existing, _)| *existing == key) {
+            return;
+        }
+        self.shapes.push((key, shape));
+    }
+
+    /// Combine with the summary of the elements that follow this one
+    pub fn merge(mut self, other: Self) -> Self {
+        for (key, shape) in other.shapes {
+            self.insert(key, shape);
+        }
+        self
+    }
+
+    /// Whether no further shapes will be recorded
+    pub fn is_full(&self) -> bool {
+        self.shapes.len() >= self.limit
+    }
+
+    /// Consume the summary, returning the shapes in first-seen order
+    pub fn into_shapes(self) -> Vec<T> {
+        self.shapes.into_iter().map(|(_, shape)| shape).collect()
+    }
+}
+
 /// Memory-efficient JSON analyzer for large structures
 pub struct MemoryEfficientAnalyzer {
     max_depth: usize,
@@ -416,25 +662,25 @@ impl StructureAnalysis {
         self.total_elements() > 10000
     }
 
-    /// Print analysis summary
+    /// Report the analysis summary as details, shown in verbose mode
     pub fn print_summary(&self) {
-        println!("📊 JSON Structure Analysis:");
-        println!("   📦 Objects: {}", self.object_count);
-        println!("   📋 Arrays: {}", self.array_count);
-        println!("   📝 Strings: {}", self.string_count);
-        println!("   🔢 Numbers: {}", self.number_count);
-        println!("   ✅ Booleans: {}", self.bool_count);
-        println!("   ❌ Nulls: {}", self.null_count);
-        println!("   📏 Max depth: {}", self.max_depth);
-        println!("   📊 Max array size: {}", self.max_array_size);
-        println!("   📄 Max string length: {}", self.max_string_length);
+        detail!("📊 JSON Structure Analysis:");
+        detail!("   📦 Objects: {}", self.object_count);
+        detail!("   📋 Arrays: {}", self.array_count);
+        detail!("   📝 Strings: {}", self.string_count);
+        detail!("   🔢 Numbers: {}", self.number_count);
+        detail!("   ✅ Booleans: {}", self.bool_count);
+        detail!("   ❌ Nulls: {}", self.null_count);
+        detail!("   📏 Max depth: {}", self.max_depth);
+        detail!("   📊 Max array size: {}", self.max_array_size);
+        detail!("   📄 Max string length: {}", self.max_string_length);
         
         if self.large_objects > 0 || self.large_arrays > 0 {
-            println!("   ⚠️  Large structures: {} objects, {} arrays", self.large_objects, self.large_arrays);
+            detail!("   ⚠️  Large structures: {} objects, {} arrays", self.large_objects, self.large_arrays);
         }
         
         if self.max_depth_exceeded {
-            println!("   ⚠️  Maximum analysis depth exceeded");
+            detail!("   ⚠️  Maximum analysis depth exceeded");
         }
     }
 }
@@ -517,6 +763,71 @@ mod tests {
         assert!(!simple_analysis.is_complex());
     }
 
+    #[test]
+    fn test_sampling_strategy_parse() {
+        assert_eq!(SamplingStrategy::parse("full").unwrap(), SamplingStrategy::Full);
+        assert_eq!(
+            SamplingStrategy::parse("head").unwrap(),
+            SamplingStrategy::Head { size: DEFAULT_SAMPLE_SIZE }
+        );
+        assert_eq!(
+            SamplingStrategy::parse("stride:500").unwrap(),
+            SamplingStrategy::Stride { size: 500 }
+        );
+        assert_eq!(
+            SamplingStrategy::parse("reservoir:200:42").unwrap(),
+            SamplingStrategy::Reservoir { size: 200, seed: 42 }
+        );
+
+        assert!(SamplingStrategy::parse("random").is_err());
+        assert!(SamplingStrategy::parse("head:0").is_err());
+        assert!(SamplingStrategy::parse("head:abc").is_err());
+        assert!(SamplingStrategy::parse("full:10").is_err());
+        assert!(SamplingStrategy::parse("stride:10:1").is_err());
+    }
+
+    #[test]
+    fn test_sampling_strategy_small_arrays_are_fully_scanned() {
+        let strategy = SamplingStrategy::Head { size: 10 };
+        assert!(!strategy.should_sample(LARGE_ARRAY_THRESHOLD));
+        assert_eq!(strategy.select_indices(LARGE_ARRAY_THRESHOLD).len(), LARGE_ARRAY_THRESHOLD);
+        assert!(strategy.should_sample(LARGE_ARRAY_THRESHOLD + 1));
+        assert!(!SamplingStrategy::Full.should_sample(1_000_000));
+    }
+
+    #[test]
+    fn test_sampling_strategy_select_indices() {
+        let len = 20_000;
+
+        let head = SamplingStrategy::Head { size: 100 }.select_indices(len);
+        assert_eq!(head, (0..100).collect::<Vec<_>>());
+
+        let stride = SamplingStrategy::Stride { size: 100 }.select_indices(len);
+        assert_eq!(stride.len(), 100);
+        assert_eq!(stride[0], 0);
+        assert_eq!(stride[1], 200);
+        assert!(*stride.last().unwrap() >= len - 200);
+
+        let reservoir = SamplingStrategy::Reservoir { size: 100, seed: 7 };
+        let first = reservoir.select_indices(len);
+        assert_eq!(first.len(), 100);
+        assert!(first.windows(2).all(|w| w[0] < w[1]));
+        assert!(first.iter().any(|&index| index >= 100));
+        assert_eq!(first, reservoir.select_indices(len));
+
+        let other_seed = SamplingStrategy::Reservoir { size: 100, seed: 8 }.select_indices(len);
+        assert_ne!(first, other_seed);
+    }
+
+    #[test]
+    fn test_sampling_strategy_describe_sample() {
+        let strategy = SamplingStrategy::Reservoir { size: 1000, seed: 42 };
+        assert_eq!(
+            strategy.describe_sample(50_000),
+            "Inferred from 1000 of 50000 items (reservoir:1000:42 sampling)"
+        );
+    }
+
     #[test]
     fn test_parallel_should_use_parallel() {
         let multiple_formats = vec!["go".to_string(), "rust".to_string()];
@@ -526,4 +837,32 @@ mod tests {
         assert!(ParallelCodeGenerator::should_use_parallel(&single_format, 2_000_000));
         assert!(!ParallelCodeGenerator::should_use_parallel(&single_format, 100));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_shape_summary_merge_matches_sequential_scan() {
+        let keys = ["a", "b", "a", "c", "d", "b", "e", "f", "g", "h"];
+        let summarize = |chunk: &[&str]| {
+            let mut summary = ShapeSummary::new(5);
+            for key in chunk {
+                summary.insert(key.to_string(), key.to_uppercase());
+            }
+            summary
+        };
+
+        let sequential = summarize(&keys).into_shapes();
+        assert_eq!(sequential, vec!["A", "B", "C", "D", "E"]);
+
+        // Every way of splitting the run into chunks must merge to the same result
+        for split in 0..=keys.len() {
+            let (left, right) = keys.split_at(split);
+            let merged = summarize(left).merge(summarize(right));
+            assert_eq!(merged.into_shapes(), sequential);
+        }
+
+        let (a, rest) = keys.split_at(3);
+        let (b, c) = rest.split_at(4);
+        let left_first = summarize(a).merge(summarize(b)).merge(summarize(c));
+        let right_first = summarize(a).merge(summarize(b).merge(summarize(c)));
+        assert_eq!(left_first.into_shapes(), right_first.into_shapes());
+    }
+}
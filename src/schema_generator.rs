@@ -1,6 +1,11 @@
+use crate::performance::{PARALLEL_ARRAY_THRESHOLD, SamplingStrategy, ShapeSummary};
+use rayon::prelude::*;
 use serde::Serialize;
 use std::collections::HashMap;
 
+/// Number of distinct item schemas collected before a mixed array stops being scanned
+const MAX_ARRAY_ITEM_SHAPES: usize = 6;
+
 /// Represents a JSON Schema structure according to JSON Schema Draft 2020-12
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct JsonSchema {
@@ -175,41 +180,69 @@ pub fn generate_schema(json_value: &serde_json::Value) -> JsonSchema {
     generate_schema_with_depth(json_value, 0, true)
 }
 
-/// Generates a JSON Schema with progress indication for large structures
+/// Generates a JSON Schema using a specific sampling strategy for large arrays
 ///
-/// This function provides the same functionality as `generate_schema` but includes
-/// progress callbacks for processing large JSON structures.
+/// Arrays with more than `LARGE_ARRAY_THRESHOLD` elements are inferred from the elements
+/// selected by `sampling`. Sampled arrays carry a description recording the strategy and
+/// the number of elements that were inspected.
 ///
 /// # Arguments
 /// * `json_value` - The JSON value to analyze
+/// * `sampling` - The strategy used to select elements of large arrays
 /// * `show_progress` - Whether to show progress messages
 ///
 /// # Returns
 /// A `JsonSchema` struct representing the schema for the input JSON
-pub fn generate_schema_with_progress(
+pub fn generate_schema_with_sampling(
     json_value: &serde_json::Value,
+    sampling: &SamplingStrategy,
     show_progress: bool,
 ) -> JsonSchema {
     if show_progress {
-        println!("   📊 Progress: 0% - Starting schema generation...");
+        status!("   📊 Progress: 0% - Starting schema generation...");
     }
 
-    let schema = generate_schema_with_depth_and_progress(json_value, 0, true, show_progress);
+    let schema =
+        generate_schema_with_depth_and_progress(json_value, 0, true, show_progress, sampling);
 
     if show_progress {
-        println!("   📊 Progress: 100% - Schema generation complete");
+        status!("   📊 Progress: 100% - Schema generation complete");
     }
 
     schema
 }
 
+/// Generates a JSON Schema with progress indication for large structures
+///
+/// This function provides the same functionality as `generate_schema` but includes
+/// progress callbacks for processing large JSON structures.
+///
+/// # Arguments
+/// * `json_value` - The JSON value to analyze
+/// * `show_progress` - Whether to show progress messages
+///
+/// # Returns
+/// A `JsonSchema` struct representing the schema for the input JSON
+pub fn generate_schema_with_progress(
+    json_value: &serde_json::Value,
+    show_progress: bool,
+) -> JsonSchema {
+    generate_schema_with_sampling(json_value, &SamplingStrategy::default(), show_progress)
+}
+
 /// Internal function that generates a JSON Schema with recursion depth tracking
 fn generate_schema_with_depth(
     json_value: &serde_json::Value,
     depth: usize,
     is_root: bool,
 ) -> JsonSchema {
-    generate_schema_with_depth_and_progress(json_value, depth, is_root, false)
+    generate_schema_with_depth_and_progress(
+        json_value,
+        depth,
+        is_root,
+        false,
+        &SamplingStrategy::default(),
+    )
 }
 
 /// Internal function that generates a JSON Schema with recursion depth tracking and progress indication
@@ -218,11 +251,12 @@ fn generate_schema_with_depth_and_progress(
     depth: usize,
     is_root: bool,
     show_progress: bool,
+    sampling: &SamplingStrategy,
 ) -> JsonSchema {
     // Check recursion depth to prevent stack overflow
     if depth > MAX_RECURSION_DEPTH {
         if show_progress {
-            println!(
+            status!(
                 "   📊 Progress: 90% - Maximum recursion depth reached - creating fallback schema"
             );
         }
@@ -241,7 +275,7 @@ fn generate_schema_with_depth_and_progress(
         serde_json::Value::Object(obj) => {
             if show_progress && depth < 3 {
                 let progress = ((depth as f32 / MAX_RECURSION_DEPTH as f32) * 80.0) as usize;
-                println!(
+                status!(
                     "   📊 Progress: {}% - Processing object at depth {depth} with {} properties",
                     progress,
                     obj.len()
@@ -249,7 +283,7 @@ fn generate_schema_with_depth_and_progress(
             }
 
             let processed_schema =
-                process_object_with_depth_and_progress(obj, depth + 1, show_progress);
+                process_object_with_depth_and_progress(obj, depth + 1, show_progress, sampling);
             if is_root {
                 // For root schema, add the $schema field
                 JsonSchema {
@@ -268,7 +302,7 @@ fn generate_schema_with_depth_and_progress(
         serde_json::Value::Array(arr) => {
             if show_progress && depth < 3 {
                 let progress = ((depth as f32 / MAX_RECURSION_DEPTH as f32) * 80.0) as usize;
-                println!(
+                status!(
                     "   📊 Progress: {}% - Processing array at depth {depth} with {} elements",
                     progress,
                     arr.len()
@@ -276,7 +310,7 @@ fn generate_schema_with_depth_and_progress(
             }
 
             let processed_schema =
-                process_array_with_depth_and_progress(arr, depth + 1, show_progress);
+                process_array_with_depth_and_progress(arr, depth + 1, show_progress, sampling);
             if is_root {
                 // For root schema, add the $schema field
                 JsonSchema {
@@ -326,7 +360,7 @@ fn infer_type(value: &serde_json::Value) -> SchemaType {
 /// Processes a JSON object and generates its schema (for testing)
 #[cfg(test)]
 fn process_object(obj: &serde_json::Map<String, serde_json::Value>) -> JsonSchema {
-    process_object_with_depth_and_progress(obj, 0, false)
+    process_object_with_depth_and_progress(obj, 0, false, &SamplingStrategy::default())
 }
 
 /// Processes a JSON object and generates its schema with depth tracking and progress indication
@@ -334,6 +368,7 @@ fn process_object_with_depth_and_progress(
     obj: &serde_json::Map<String, serde_json::Value>,
     depth: usize,
     show_progress: bool,
+    sampling: &SamplingStrategy,
 ) -> JsonSchema {
     let mut properties = HashMap::new();
     let mut required = Vec::new();
@@ -344,7 +379,7 @@ fn process_object_with_depth_and_progress(
         // Update progress for large objects
         if show_progress && total_props > 100 && index % 20 == 0 {
             let progress = (index * 80 / total_props) + 10;
-            println!(
+            status!(
                 "   📊 Progress: {}% - Processing property '{key}' ({}/{total_props})",
                 progress,
                 index + 1
@@ -353,7 +388,7 @@ fn process_object_with_depth_and_progress(
 
         // Recursively generate schema for each property using depth-aware function
         let property_schema =
-            generate_schema_with_depth_and_progress(value, depth, false, show_progress);
+            generate_schema_with_depth_and_progress(value, depth, false, show_progress, sampling);
 
         properties.insert(key.clone(), property_schema);
 
@@ -369,7 +404,7 @@ fn process_object_with_depth_and_progress(
 /// Processes a JSON array and generates its schema (for testing)
 #[cfg(test)]
 fn process_array(arr: &[serde_json::Value]) -> JsonSchema {
-    process_array_with_depth_and_progress(arr, 0, false)
+    process_array_with_depth_and_progress(arr, 0, false, &SamplingStrategy::default())
 }
 
 /// Processes a JSON array and generates its schema with depth tracking and progress indication
@@ -377,6 +412,7 @@ fn process_array_with_depth_and_progress(
     arr: &[serde_json::Value],
     depth: usize,
     show_progress: bool,
+    sampling: &SamplingStrategy,
 ) -> JsonSchema {
     // Handle empty array case
     if arr.is_empty() {
@@ -396,74 +432,99 @@ fn process_array_with_depth_and_progress(
     let total_items = arr.len();
 
     // For very large arrays, sample items instead of processing all
-    let sample_size = if total_items > 10000 {
-        if show_progress {
-            println!(
-                "   📊 Progress: 20% - Large array detected ({total_items} items) - using sampling for performance"
-            );
-        }
-        1000 // Sample first 1000 items for very large arrays
-    } else {
-        total_items
-    };
+    let is_sampled = sampling.should_sample(total_items);
+    if is_sampled && show_progress {
+        status!(
+            "   📊 Progress: 20% - Large array detected ({total_items} items) - using {sampling} sampling for performance"
+        );
+    }
+    let sample = sampling.sample(arr);
+    let sample_size = sample.len();
 
     // Collect all unique types in the array (or sample)
-    let mut type_schemas: Vec<JsonSchema> = Vec::new();
-    let mut seen_types: Vec<String> = Vec::new();
-
-    for (index, value) in arr.iter().take(sample_size).enumerate() {
-        // Update progress for large arrays
-        if show_progress && sample_size > 100 && index % 100 == 0 {
-            let progress = (index * 60 / sample_size) + 20;
-            println!(
-                "   📊 Progress: {}% - Analyzing array item {}/{sample_size}",
-                progress,
-                index + 1
-            );
+    let summary = if sample_size >= PARALLEL_ARRAY_THRESHOLD {
+        // Fan out across threads; merging chunk summaries in order keeps the result
+        // identical to the sequential scan below
+        if show_progress {
+            status!("   📊 Progress: 30% - Analyzing {sample_size} array items in parallel");
         }
+        sample
+            .par_iter()
+            .fold(
+                || ShapeSummary::new(MAX_ARRAY_ITEM_SHAPES),
+                |mut summary, value| {
+                    if !summary.is_full() {
+                        let item_schema = generate_schema_with_depth_and_progress(
+                            value, depth, false, false, sampling,
+                        );
+                        summary.insert(format!("{item_schema:?}"), item_schema);
+                    }
+                    summary
+                },
+            )
+            .reduce(|| ShapeSummary::new(MAX_ARRAY_ITEM_SHAPES), ShapeSummary::merge)
+    } else {
+        let mut summary = ShapeSummary::new(MAX_ARRAY_ITEM_SHAPES);
+
+        for (index, value) in sample.into_iter().enumerate() {
+            // Update progress for large arrays
+            if show_progress && sample_size > 100 && index % 100 == 0 {
+                let progress = (index * 60 / sample_size) + 20;
+                status!(
+                    "   📊 Progress: {}% - Analyzing array item {}/{sample_size}",
+                    progress,
+                    index + 1
+                );
+            }
 
-        // Use depth-aware schema generation
-        let item_schema =
-            generate_schema_with_depth_and_progress(value, depth, false, show_progress);
-
-        // Create a unique key for this schema to avoid duplicates
-        let schema_key = format!("{item_schema:?}");
+            // Use depth-aware schema generation
+            let item_schema =
+                generate_schema_with_depth_and_progress(value, depth, false, show_progress, sampling);
 
-        if !seen_types.contains(&schema_key) {
-            seen_types.push(schema_key);
-            type_schemas.push(item_schema);
-        }
+            // Use a unique key for this schema to avoid duplicates
+            summary.insert(format!("{item_schema:?}"), item_schema);
 
-        // Early exit if we've found enough unique types for mixed arrays
-        if type_schemas.len() > 5 {
-            if show_progress {
-                println!(
-                    "   📊 Progress: 80% - Multiple types detected - using first type for schema"
-                );
+            // Early exit if we've found enough unique types for mixed arrays
+            if summary.is_full() {
+                break;
             }
-            break;
         }
+
+        summary
+    };
+
+    if summary.is_full() && show_progress {
+        status!("   📊 Progress: 80% - Multiple types detected - using first type for schema");
     }
+    let type_schemas = summary.into_shapes();
+    let unique_types = type_schemas.len();
 
     // If all items have the same type, use that type directly
-    if type_schemas.len() == 1 {
+    let array_schema = if unique_types == 1 {
         JsonSchema::new_nested_array(type_schemas.into_iter().next().unwrap())
     } else {
         // For mixed types, we need to use anyOf
         // For now, we'll use the first type as a fallback
         // TODO: Implement proper anyOf support in a future enhancement
         if show_progress {
-            println!(
-                "   📊 Progress: 85% - Mixed array types detected ({} unique types) - using primary type",
-                type_schemas.len()
+            status!(
+                "   📊 Progress: 85% - Mixed array types detected ({unique_types} unique types) - using primary type"
             );
         }
         JsonSchema::new_nested_array(type_schemas.into_iter().next().unwrap())
+    };
+
+    // Record how the item schema was inferred when only part of the array was inspected
+    if is_sampled {
+        array_schema.with_description(sampling.describe_sample(total_items))
+    } else {
+        array_schema
     }
 }
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::performance::LARGE_ARRAY_THRESHOLD;
     use core::f64;
 
     #[test]
@@ -1979,4 +2040,50 @@ mod tests {
         assert_eq!(items.type_name, SchemaType::String); // First type encountered
         assert!(items.schema.is_empty());
     }
+
+    #[test]
+    fn test_generate_schema_with_sampling_describes_sampled_arrays() {
+        let len = LARGE_ARRAY_THRESHOLD + 2_000;
+        let large_array = serde_json::Value::Array((0..len).map(|i| serde_json::json!(i)).collect());
+
+        let schema = generate_schema_with_sampling(
+            &large_array,
+            &SamplingStrategy::Stride { size: 50 },
+            false,
+        );
+        assert_eq!(schema.type_name, SchemaType::Array);
+        assert_eq!(schema.items.as_ref().unwrap().type_name, SchemaType::Integer);
+        assert_eq!(
+            schema.description.as_deref(),
+            Some(format!("Inferred from 50 of {len} items (stride:50 sampling)").as_str())
+        );
+
+        let schema = generate_schema_with_sampling(&large_array, &SamplingStrategy::Full, false);
+        assert_eq!(schema.items.as_ref().unwrap().type_name, SchemaType::Integer);
+        assert!(schema.description.is_none());
+
+        // Arrays under the threshold are never sampled
+        let small_array = serde_json::json!([1, 2, 3]);
+        let schema =
+            generate_schema_with_sampling(&small_array, &SamplingStrategy::default(), false);
+        assert!(schema.description.is_none());
+    }
+
+    #[test]
+    fn test_large_array_parallel_inference_keeps_first_item_type() {
+        let mut items: Vec<serde_json::Value> = (0..PARALLEL_ARRAY_THRESHOLD * 2)
+            .map(|i| serde_json::json!({"id": i}))
+            .collect();
+        items[0] = serde_json::json!({"id": 0, "name": "first"});
+        items[PARALLEL_ARRAY_THRESHOLD + 1] = serde_json::json!("not an object");
+
+        let schema = generate_schema(&serde_json::Value::Array(items.clone()));
+        let expected_items = generate_schema_with_depth(&items[0], 1, false);
+        assert_eq!(schema.items.as_deref(), Some(&expected_items));
+        assert_eq!(
+            expected_items.properties.as_ref().unwrap().len(),
+            2,
+            "item schema should come from the first element"
+        );
+    }
 }
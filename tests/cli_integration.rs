@@ -660,3 +660,649 @@ fn test_string_only_json() {
 
     assert_eq!(schema["type"], "string");
 }
+
+/// Test generating several formats into an output directory in one invocation
+#[test]
+fn test_multiple_formats_into_output_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("user.json");
+    let output_dir = temp_dir.path().join("generated");
+
+    fs::write(&input_path, r#"{"id": 1, "name": "Alice", "tags": ["a", "b"]}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(&input_path)
+        .arg("--format")
+        .arg("go,rust,typescript,schema")
+        .arg("--output")
+        .arg(&output_dir)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Successfully generated 4 files"));
+
+    assert!(output_dir.join("user.go").exists());
+    assert!(output_dir.join("user.rs").exists());
+    assert!(output_dir.join("user.ts").exists());
+
+    let schema_content = fs::read_to_string(output_dir.join("user.schema.json")).unwrap();
+    let schema: serde_json::Value = serde_json::from_str(&schema_content).unwrap();
+    assert_eq!(schema["type"], "object");
+
+    let go_content = fs::read_to_string(output_dir.join("user.go")).unwrap();
+    assert!(go_content.contains("type User struct"));
+}
+
+/// Test repeated --format flags default to the input file's directory
+#[test]
+fn test_repeated_format_flags() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("data.json");
+
+    fs::write(&input_path, r#"{"value": 42}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(&input_path)
+        .arg("-f")
+        .arg("python")
+        .arg("-f")
+        .arg("schema")
+        .assert()
+        .success();
+
+    assert!(temp_dir.path().join("data.py").exists());
+    assert!(temp_dir.path().join("data.schema.json").exists());
+}
+
+/// Test that an existing file is rejected as the output directory for several formats
+#[test]
+fn test_multiple_formats_output_must_be_directory() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("data.json");
+    let output_file = temp_dir.path().join("existing.txt");
+
+    fs::write(&input_path, r#"{"value": 42}"#).unwrap();
+    fs::write(&output_file, "not a directory").unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(&input_path)
+        .arg("--format")
+        .arg("go,rust")
+        .arg("--output")
+        .arg(&output_file)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("must name a directory"));
+}
+
+/// Test piping JSON through stdin writes only the schema to stdout
+#[test]
+fn test_stdin_to_stdout_schema() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    let output = cmd
+        .write_stdin(r#"{"name": "piped", "count": 3}"#)
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Reading JSON from stdin"))
+        .get_output()
+        .stdout
+        .clone();
+
+    // stdout must carry nothing but the artifact
+    let schema: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(schema["type"], "object");
+    assert_eq!(schema["properties"]["count"]["type"], "integer");
+}
+
+/// Test explicit `-` input and `-o -` output with a code format
+#[test]
+fn test_stdin_dash_to_stdout_code() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "typescript", "--struct-name", "User", "-o", "-"])
+        .write_stdin(r#"{"id": 1}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("export interface User"))
+        .stdout(predicate::str::contains("🔍").not());
+}
+
+/// Test reading from stdin while writing to a file
+#[test]
+fn test_stdin_to_output_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("piped.go");
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["--input", "-", "--format", "go", "--output"])
+        .arg(&output_path)
+        .write_stdin(r#"{"ok": true}"#)
+        .assert()
+        .success();
+
+    let go_content = fs::read_to_string(&output_path).unwrap();
+    assert!(go_content.contains("type Data struct"));
+}
+
+/// Test `-o -` sends a file's schema to stdout without creating a file
+#[test]
+fn test_file_input_to_stdout() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("input.json");
+
+    fs::write(&input_path, r#"[1, 2, 3]"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    let output = cmd
+        .arg(&input_path)
+        .args(["-o", "-"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let schema: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(schema["type"], "array");
+    assert!(!temp_dir.path().join("input.schema.json").exists());
+}
+
+/// Test an explicit `-` input with nothing on stdin
+#[test]
+fn test_stdin_empty_input_error() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg("-")
+        .write_stdin("")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("No JSON data received on stdin"));
+}
+
+/// Test that read errors on implicit stdin are reported instead of the usage message
+#[test]
+fn test_implicit_stdin_invalid_utf8_error() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.write_stdin(vec![0xff, 0xfe, b'{', b'}'])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Invalid encoding on stdin"))
+        .stderr(predicate::str::contains("No input file specified").not());
+}
+
+/// Test --quiet suppresses all progress output
+#[test]
+fn test_quiet_flag() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("quiet.json");
+    let output_path = temp_dir.path().join("quiet.schema.json");
+
+    fs::write(&input_path, r#"{"silent": true}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(&input_path)
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::is_empty());
+
+    assert!(output_path.exists());
+}
+
+/// Test --message-format json emits one JSON event per line
+#[test]
+fn test_message_format_json() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("events.json");
+    let output_path = temp_dir.path().join("events.go");
+
+    fs::write(&input_path, r#"{"id": 7, "name": "events"}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    let stdout = cmd
+        .arg(&input_path)
+        .args(["--format", "go", "--message-format", "json", "--output"])
+        .arg(&output_path)
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let events: Vec<serde_json::Value> = String::from_utf8(stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    let phases: Vec<&str> = events
+        .iter()
+        .filter(|event| event["event"] == "phase")
+        .map(|event| event["phase"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        phases,
+        vec!["read", "parse", "analyze", "generate", "write", "total"]
+    );
+
+    let output_event = events
+        .iter()
+        .find(|event| event["event"] == "output")
+        .unwrap();
+    assert_eq!(output_event["format"], "go");
+    assert_eq!(output_event["path"], output_path.to_str().unwrap());
+
+    let hints_event = events
+        .iter()
+        .find(|event| event["event"] == "hints")
+        .unwrap();
+    assert_eq!(hints_event["topic"], "Go");
+    assert!(!events
+        .iter()
+        .any(|event| event["event"] == "progress"
+            && event["message"].as_str().unwrap().contains("Usage hints")));
+
+    let result_event = events.last().unwrap();
+    assert_eq!(result_event["event"], "result");
+    assert_eq!(result_event["success"], true);
+}
+
+/// Test --message-format json reports failures as error and result events
+#[test]
+fn test_message_format_json_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("broken.json");
+
+    fs::write(&input_path, r#"{"id": 7,"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    let stdout = cmd
+        .arg(&input_path)
+        .args(["--message-format", "json"])
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let events: Vec<serde_json::Value> = String::from_utf8(stdout)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    let error_event = events
+        .iter()
+        .find(|event| event["event"] == "error")
+        .unwrap();
+    assert!(error_event["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Error parsing JSON"));
+    assert!(!error_event["hints"].as_array().unwrap().is_empty());
+
+    let result_event = events.last().unwrap();
+    assert_eq!(result_event["event"], "result");
+    assert_eq!(result_event["success"], false);
+}
+
+/// Test --verbose shows the performance summary
+#[test]
+fn test_verbose_flag() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("verbose.json");
+
+    fs::write(&input_path, r#"{"detail": "shown"}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(&input_path)
+        .arg("--verbose")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Performance Summary"))
+        .stdout(predicate::str::contains("⏱️  parse:"));
+}
+
+/// Test Kotlin data class generation written next to the input
+#[test]
+fn test_kotlin_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("account.json");
+    let output_path = temp_dir.path().join("account.kt");
+
+    fs::write(&input_path, r#"{"account_id": 7, "nickname": null}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(&input_path)
+        .args(["--format", "kotlin", "--struct-name", "Account"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Kotlin data classes"));
+
+    let code = fs::read_to_string(&output_path).unwrap();
+    assert!(code.contains("@Serializable\ndata class Account("));
+    assert!(code.contains("@SerialName(\"account_id\")\n    val accountId: Long,"));
+    assert!(code.contains("val nickname: JsonElement? = null,"));
+}
+
+/// Test Swift Codable generation with CodingKeys and escaped keywords
+#[test]
+fn test_swift_format() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "swift", "--struct-name", "Settings", "-o", "-"])
+        .write_stdin(r#"{"default": true, "refresh_rate": 60}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("struct Settings: Codable {"))
+        .stdout(predicate::str::contains("    let `default`: Bool"))
+        .stdout(predicate::str::contains("        case refreshRate = \"refresh_rate\""));
+}
+
+#[test]
+fn test_java_format() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "java", "--struct-name", "User", "-o", "-"])
+        .args(["--lang-option", "package=com.example.model"])
+        .write_stdin(r#"{"user_id": 7, "nickname": null}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("package com.example.model;"))
+        .stdout(predicate::str::contains("@JsonIgnoreProperties(ignoreUnknown = true)\nrecord User("))
+        .stdout(predicate::str::contains("public record").not())
+        .stdout(predicate::str::contains("@JsonProperty(\"user_id\") long userId"));
+}
+
+#[test]
+fn test_java_public_type_matches_file_name() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("s.json");
+    fs::write(&input_file, r#"{"id": 1, "owner": {"name": "Ada"}}"#).unwrap();
+
+    // The file is named after the input, not the type, so nothing may be public
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(input_file.to_str().unwrap())
+        .args(["--format", "java", "--struct-name", "Root"])
+        .assert()
+        .success();
+    let code = fs::read_to_string(temp_dir.path().join("s.java")).unwrap();
+    assert!(!code.contains("public record"));
+
+    let output_file = temp_dir.path().join("Root.java");
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(input_file.to_str().unwrap())
+        .args(["--format", "java", "--struct-name", "Root", "--output"])
+        .arg(output_file.to_str().unwrap())
+        .assert()
+        .success();
+    let code = fs::read_to_string(&output_file).unwrap();
+    assert_eq!(code.matches("public record").count(), 1);
+    assert!(code.contains("public record Root("));
+}
+
+#[test]
+fn test_java_split_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("user.json");
+    let output_dir = temp_dir.path().join("model");
+    fs::write(&input_file, r#"{"name": "Alice", "address": {"city": "Paris"}}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(input_file.to_str().unwrap())
+        .args(["--format", "java", "--struct-name", "User", "--split-files"])
+        .args(["--lang-option", "java_style=pojo"])
+        .arg("--output")
+        .arg(output_dir.to_str().unwrap())
+        .assert()
+        .success();
+
+    let user = fs::read_to_string(output_dir.join("User.java")).unwrap();
+    assert!(user.contains("public class User {"));
+    assert!(user.contains("    public String getName() {"));
+
+    // The nested type is written to its own file next to the root type
+    let files: Vec<_> = fs::read_dir(&output_dir).unwrap().collect();
+    assert_eq!(files.len(), 2);
+}
+
+#[test]
+fn test_invalid_lang_option() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "java", "--lang-option", "java_style"])
+        .write_stdin("{}")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Expected KEY=VALUE"));
+}
+
+#[test]
+fn test_csharp_format() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "csharp", "--struct-name", "Event", "-o", "-"])
+        .args(["--lang-option", "namespace=Example.Models"])
+        .write_stdin(r#"{"event": "click", "count": 3}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("namespace Example.Models;"))
+        .stdout(predicate::str::contains("public sealed record Event\n{"))
+        .stdout(predicate::str::contains("    [JsonPropertyName(\"event\")]\n    public required string EventValue { get; init; }"));
+}
+
+#[test]
+fn test_dart_format_part_directive() {
+    let temp_dir = TempDir::new().unwrap();
+    let input_file = temp_dir.path().join("data.json");
+    let output_file = temp_dir.path().join("user_model.dart");
+    fs::write(&input_file, r#"{"user_id": 1, "name": "Alice"}"#).unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.arg(input_file.to_str().unwrap())
+        .args(["--format", "dart", "--struct-name", "User", "--output"])
+        .arg(output_file.to_str().unwrap())
+        .assert()
+        .success();
+
+    // The part directive follows the output file name, not the class name
+    let content = fs::read_to_string(&output_file).unwrap();
+    assert!(content.contains("part 'user_model.g.dart';"));
+    assert!(content.contains("  @JsonKey(name: 'user_id')\n  final int userId;"));
+    assert!(content.contains("factory User.fromJson(Map<String, dynamic> json)"));
+}
+
+#[test]
+fn test_zod_format() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "zod", "--struct-name", "User", "-o", "-"])
+        .write_stdin(r#"{"first-name": "Alice", "age": 30, "scores": [1.5]}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("import { z } from \"zod\";"))
+        .stdout(predicate::str::contains("  \"first-name\": z.string(),"))
+        .stdout(predicate::str::contains("  scores: z.array(z.number()),"))
+        .stdout(predicate::str::contains("export type User = z.infer<typeof UserSchema>;"));
+}
+
+#[test]
+fn test_python_pydantic_style() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "python", "--struct-name", "Order", "-o", "-"])
+        .args(["--lang-option", "python_style=pydantic"])
+        .write_stdin(r#"{"order-id": 7, "note": null}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("from pydantic import BaseModel, ConfigDict, Field"))
+        .stdout(predicate::str::contains("class Order(BaseModel):"))
+        .stdout(predicate::str::contains("    order_id: int = Field(alias=\"order-id\")"));
+}
+
+#[test]
+fn test_python_typeddict_style() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "python", "--struct-name", "Headers", "-o", "-"])
+        .args(["--lang-option", "python_style=typeddict", "--lang-option", "typeddict_total=false"])
+        .write_stdin(r#"{"content-type": "text/plain", "length": 12}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Headers = TypedDict("))
+        .stdout(predicate::str::contains("        \"content-type\": Required[str],"))
+        .stdout(predicate::str::contains("    total=False,"));
+}
+
+#[test]
+fn test_proto_format_keeps_existing_field_numbers() {
+    let temp_dir = TempDir::new().unwrap();
+    let existing_path = temp_dir.path().join("user.proto");
+    fs::write(
+        &existing_path,
+        "syntax = \"proto3\";\n\nmessage User {\n  string name = 1;\n  int64 legacy_id = 2;\n  int64 age = 3;\n}\n",
+    )
+    .unwrap();
+
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "proto", "--struct-name", "User", "-o", "-"])
+        .args(["--lang-option", &format!("existing_proto={}", existing_path.display())])
+        .write_stdin(r#"{"age": 30, "email": "a@example.com", "name": "Alice"}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("syntax = \"proto3\";"))
+        .stdout(predicate::str::contains("  reserved 2;\n  reserved \"legacy_id\";"))
+        .stdout(predicate::str::contains("  int64 age = 3;"))
+        .stdout(predicate::str::contains("  string email = 4;"))
+        .stdout(predicate::str::contains("  string name = 1;"));
+}
+
+#[test]
+fn test_graphql_format() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "graphql", "--struct-name", "User", "-o", "-"])
+        .args(["--lang-option", "graphql_input=true"])
+        .write_stdin(r#"{"user_name": "Alice", "tags": ["a"], "extra": null}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("scalar JSON"))
+        .stdout(predicate::str::contains("type User {"))
+        .stdout(predicate::str::contains("  userName: String!"))
+        .stdout(predicate::str::contains("  tags: [String!]!"))
+        .stdout(predicate::str::contains("  extra: JSON\n"))
+        .stdout(predicate::str::contains("input UserInput {"));
+}
+
+#[test]
+fn test_sql_format() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "sql", "--struct-name", "products", "-o", "-"])
+        .args(["--lang-option", "sql_nested=table", "--lang-option", "sql_dialect=sqlite"])
+        .write_stdin(r#"[{"id": 1, "name": "Laptop", "specs": {"cpu": "i7"}}, {"id": 2, "specs": {"cpu": "M2"}}]"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("CREATE TABLE products ("))
+        .stdout(predicate::str::contains("    id INTEGER PRIMARY KEY"))
+        .stdout(predicate::str::contains("    name TEXT\n"))
+        .stdout(predicate::str::contains("CREATE TABLE products_specs ("))
+        .stdout(predicate::str::contains(
+            "    products_id INTEGER NOT NULL UNIQUE REFERENCES products (id) ON DELETE CASCADE,",
+        ));
+}
+
+#[test]
+fn test_avro_format() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "avro", "--struct-name", "UserCreated", "-o", "-"])
+        .args(["--lang-option", "namespace=com.example.events"])
+        .write_stdin(r#"{"user_id": 7, "email": null, "tags": ["new"]}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"name\": \"UserCreated\""))
+        .stdout(predicate::str::contains("\"namespace\": \"com.example.events\""))
+        .stdout(predicate::str::contains("\"default\": null"))
+        .stdout(predicate::str::contains("\"items\": \"string\""));
+}
+
+#[test]
+fn test_openapi_format() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "openapi", "--struct-name", "User", "-o", "-"])
+        .args(["--lang-option", "openapi_response=GetUser"])
+        .write_stdin(r#"{"id": 1, "address": {"city": "Paris"}}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("openapi: \"3.1.0\""))
+        .stdout(predicate::str::contains("    UserAddress:\n      type: object"))
+        .stdout(predicate::str::contains("$ref: \"#/components/schemas/UserAddress\""))
+        .stdout(predicate::str::contains("  responses:\n    GetUser:"));
+}
+
+#[test]
+fn test_jtd_format() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "jtd", "-o", "-"])
+        .write_stdin(r#"[{"type": "click", "x": 1, "note": null}, {"type": "click", "x": 2, "note": "hi"}, {"type": "key", "code": "A"}]"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("\"discriminator\": \"type\""))
+        .stdout(predicate::str::contains("\"click\": {"))
+        .stdout(predicate::str::contains("\"nullable\": true"));
+}
+
+#[test]
+fn test_rust_container_attributes() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "rust", "--struct-name", "User", "-o", "-"])
+        .args(["--lang-option", "rust_derives=Default,Eq"])
+        .args(["--lang-option", "rust_deny_unknown_fields=true"])
+        .write_stdin(r#"[{"userId": 1, "displayName": "Ada"}, {"userId": 2}]"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]"))
+        .stdout(predicate::str::contains("#[serde(rename_all = \"camelCase\", deny_unknown_fields)]"))
+        .stdout(predicate::str::contains("    #[serde(default)]\n    pub display_name: String,"));
+}
+
+#[test]
+fn test_rust_borrowed_structs() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "rust", "--struct-name", "Event", "-o", "-"])
+        .args(["--lang-option", "rust_borrow=str"])
+        .args(["--lang-option", "rust_borrow_fallback=true"])
+        .write_stdin(r#"{"kind": "click", "note": "a \"quoted\" word", "target": {"id": "btn"}}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("pub struct Event<'a> {"))
+        .stdout(predicate::str::contains("    #[serde(borrow)]\n    pub kind: &'a str,"))
+        .stdout(predicate::str::contains("    pub note: String,"));
+}
+
+#[test]
+fn test_typescript_declaration_options() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "typescript", "--struct-name", "Order", "-o", "-"])
+        .args(["--lang-option", "ts_declaration=type"])
+        .args(["--lang-option", "ts_readonly=true"])
+        .args(["--lang-option", "ts_optional=optional"])
+        .args(["--lang-option", "ts_any=unknown"])
+        .args(["--lang-option", "ts_enums=true"])
+        .write_stdin(
+            r#"[{"id": 1, "state": "open", "tags": ["a"], "note": null},
+                {"id": 2, "state": "closed", "tags": [], "note": null},
+                {"id": 3, "state": "open", "tags": ["b"], "note": null},
+                {"id": 4, "state": "closed", "tags": [], "note": null}]"#,
+        )
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("export type Order = {"))
+        .stdout(predicate::str::contains("  readonly state: \"closed\" | \"open\";"))
+        .stdout(predicate::str::contains("  readonly tags: ReadonlyArray<string>;"))
+        .stdout(predicate::str::contains("  readonly note?: unknown;"))
+        .stdout(predicate::str::contains("};"));
+}
+
+#[test]
+fn test_typescript_type_guards() {
+    let mut cmd = Command::cargo_bin("j2s").unwrap();
+    cmd.args(["-", "--format", "typescript", "--struct-name", "User", "-o", "-"])
+        .args(["--lang-option", "ts_guards=true"])
+        .write_stdin(r#"{"id": 7, "scores": [1.5, 2.0], "profile": {"bio": null}}"#)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("export function isUser(v: unknown): v is User {"))
+        .stdout(predicate::str::contains("    Number.isInteger(o[\"id\"]) &&"))
+        .stdout(predicate::str::contains("    Array.isArray(o[\"scores\"]) && o[\"scores\"].every((item) => typeof item === \"number\")\n  );"))
+        .stdout(predicate::str::contains("export function isProfile"));
+}
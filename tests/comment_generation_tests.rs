@@ -199,11 +199,12 @@ fn test_comment_generator_factory() {
 #[test]
 fn test_supported_languages() {
     let languages = CommentGeneratorFactory::supported_languages();
-    assert_eq!(languages.len(), 4);
+    assert_eq!(languages.len(), 5);
     assert!(languages.contains(&"go"));
     assert!(languages.contains(&"rust"));
     assert!(languages.contains(&"typescript"));
     assert!(languages.contains(&"python"));
+    assert!(languages.contains(&"kotlin"));
 }
 
 #[test]
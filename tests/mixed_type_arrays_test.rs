@@ -93,8 +93,8 @@ fn test_mixed_primitive_array_go() {
     let code = result.unwrap();
     println!("Go mixed primitives code:\n{}", code);
     
-    // Should use interface{} for mixed array
-    assert!(code.contains("[]interface{}"));
+    // Should use the any alias for mixed array
+    assert!(code.contains("[]any"));
     assert!(code.contains("MixedArray"));
 }
 